@@ -275,6 +275,22 @@ impl CheckContext {
         }
         Ok(windows.remove(0))
     }
+
+    /// Waits for a window of the app that is not one of `known_windows` (e.g. a popup).
+    pub fn wait_for_new_window_by_pid(&self, known_windows: &[&Window]) -> anyhow::Result<Window> {
+        let started = Instant::now();
+        while started.elapsed() < MAX_DURATION {
+            let new_window = self
+                .wait_for_windows_by_pid()?
+                .into_iter()
+                .find(|window| known_windows.iter().all(|known| known.id() != window.id()));
+            if let Some(window) = new_window {
+                return Ok(window);
+            }
+            sleep(CAPTURE_INTERVAL);
+        }
+        bail!("couldn't find a new window after {:?}", MAX_DURATION);
+    }
 }
 
 fn record_fail(fails: &mut Vec<String>, fail: impl Display) {
@@ -350,6 +366,13 @@ impl Context {
         self.as_check().wait_for_window_by_pid()
     }
 
+    pub fn wait_for_new_window_by_pid(
+        &mut self,
+        known_windows: &[&Window],
+    ) -> anyhow::Result<Window> {
+        self.as_check().wait_for_new_window_by_pid(known_windows)
+    }
+
     pub fn connection(&mut self) -> &mut Connection {
         &mut self.as_check().connection
     }
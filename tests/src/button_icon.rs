@@ -0,0 +1,78 @@
+use {
+    widgem::{
+        icon::Icon,
+        impl_widget_base,
+        widgets::{
+            button::{Button, IconPosition},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+const CIRCLE_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+    <circle cx="8" cy="8" r="6" fill="#2a7ab0"/>
+</svg>"##;
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("Warning")
+            .set_icon(Some(Icon::named("message_warning")));
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(1)
+            .set_row(0)
+            .set_text("Circle")
+            .set_icon(Some(Icon::from_svg_data(CIRCLE_SVG.as_bytes()).unwrap()))
+            .set_icon_position(IconPosition::Top);
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("Down")
+            .set_icon(Some(Icon::named("scroll_down")))
+            .set_text_visible(false);
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(1)
+            .set_row(1)
+            .set_text("Disabled")
+            .set_icon(Some(Icon::named("scroll_down")))
+            .set_enabled(false);
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn button_icon(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "buttons with icons")?;
+
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "icon on top focused")?;
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "icon only focused")?;
+
+    window.close()?;
+    Ok(())
+}
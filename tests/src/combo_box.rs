@@ -0,0 +1,87 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{combo_box::ComboBox, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let items = ["Apple", "Banana", "Cherry", "Date"];
+        window
+            .base_mut()
+            .add_child::<ComboBox>()
+            .set_column(0)
+            .set_row(0)
+            .set_items(items)
+            .set_current_index(Some(0));
+        window
+            .base_mut()
+            .add_child::<ComboBox>()
+            .set_column(0)
+            .set_row(1)
+            .set_editable(true)
+            .set_items(items);
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "combo boxes")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "second item selected")?;
+    ctx.connection().key("End")?;
+    ctx.snapshot(&mut window, "last item selected")?;
+    ctx.connection().type_text("c")?;
+    ctx.snapshot(&mut window, "item selected by typing")?;
+
+    ctx.connection().key("space")?;
+    let mut popup = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut popup, "popup opened")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut popup, "next item highlighted")?;
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "item chosen from popup")?;
+
+    window.close()?;
+    Ok(())
+}
+
+#[widgem_test_kit::test]
+pub fn editable(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.connection().key("Tab")?;
+    ctx.set_blinking_expected(true);
+    ctx.snapshot(&mut window, "editable combo box focused")?;
+    ctx.connection().type_text("b")?;
+    ctx.set_blinking_expected(false);
+    ctx.snapshot(&mut window, "completed item")?;
+    ctx.connection().key("End")?;
+    ctx.set_blinking_expected(true);
+    ctx.snapshot(&mut window, "completion accepted")?;
+    ctx.connection().key("BackSpace")?;
+    ctx.snapshot(&mut window, "edited text doesnt match any item")?;
+
+    window.close()?;
+    Ok(())
+}
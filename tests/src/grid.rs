@@ -0,0 +1,74 @@
+use {
+    widgem::{
+        impl_widget_base,
+        shortcut::{KeyCombinations, Shortcut, ShortcutScope},
+        widgets::{label::Label, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("First row");
+        let hidden_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("Second row")
+            .id();
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(2)
+            .set_text("Third row");
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(1)
+            .set_row(0)
+            .set_text("Second column");
+
+        base.add_shortcut(Shortcut::new(
+            KeyCombinations::from_str_portable("F2").unwrap(),
+            ShortcutScope::Application,
+            hidden_id.callback(|label, _| {
+                let visible = !label.base().is_self_visible();
+                label.set_visible(visible);
+                Ok(())
+            }),
+        ));
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn hidden_children(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "all children visible")?;
+
+    ctx.connection().key("F2")?;
+    ctx.snapshot(&mut window, "second row hidden")?;
+    ctx.connection().key("F2")?;
+    ctx.snapshot(&mut window, "second row shown again")?;
+
+    window.close()?;
+    Ok(())
+}
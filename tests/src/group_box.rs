@@ -0,0 +1,107 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            check_box::CheckBox,
+            frame::{Frame, FrameStyle},
+            group_box::GroupBox,
+            label::Label,
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let options = window
+            .base_mut()
+            .add_child::<GroupBox>()
+            .set_column(0)
+            .set_row(0)
+            .set_title("Options")
+            .set_checkable(true);
+        options
+            .content_mut()
+            .base_mut()
+            .add_child::<CheckBox>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("Autosave");
+        options
+            .content_mut()
+            .base_mut()
+            .add_child::<CheckBox>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("Backups");
+
+        let info = window
+            .base_mut()
+            .add_child::<GroupBox>()
+            .set_column(1)
+            .set_row(0)
+            .set_title("Info");
+        info.content_mut()
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("Not checkable");
+
+        for (index, (frame_style, text)) in [
+            (FrameStyle::Box, "Box"),
+            (FrameStyle::Panel, "Panel"),
+            (FrameStyle::Rounded, "Rounded"),
+            (FrameStyle::NoFrame, "No frame"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            window
+                .base_mut()
+                .add_child::<Frame>()
+                .set_column(index as i32 % 2)
+                .set_row(1 + index as i32 / 2)
+                .set_frame_style(frame_style)
+                .base_mut()
+                .add_child::<Label>()
+                .set_column(0)
+                .set_row(0)
+                .set_text(text);
+        }
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn group_box(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "group boxes and frames")?;
+
+    ctx.connection().key("space")?;
+    ctx.snapshot(&mut window, "group box unchecked and content disabled")?;
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "disabled content skipped by tab")?;
+    ctx.connection().key("Shift+Tab")?;
+    ctx.connection().key("space")?;
+    ctx.snapshot(&mut window, "group box checked and content enabled")?;
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "content check box focused")?;
+
+    window.close()?;
+    Ok(())
+}
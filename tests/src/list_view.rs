@@ -0,0 +1,86 @@
+use {
+    std::rc::Rc,
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            list_view::{ListView, SelectionMode},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let rows = (1..=1000).map(|i| format!("Item {i}")).collect::<Vec<_>>();
+        window
+            .base_mut()
+            .add_child::<ListView>()
+            .set_column(0)
+            .set_row(0)
+            .set_selection_mode(SelectionMode::Multi)
+            .set_model(Rc::new(rows));
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    window.resize(200, 150)?;
+    ctx.snapshot(&mut window, "list view")?;
+
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "first row selected")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "second row selected")?;
+    ctx.connection().key("Shift+Down")?;
+    ctx.connection().key("Shift+Down")?;
+    ctx.snapshot(&mut window, "range selected")?;
+
+    ctx.connection().key("Page_Down")?;
+    ctx.snapshot(&mut window, "page down")?;
+    ctx.connection().key("End")?;
+    ctx.snapshot(&mut window, "scrolled to last row")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.snapshot(&mut window, "page up")?;
+    ctx.connection().key("Home")?;
+    ctx.snapshot(&mut window, "scrolled to first row")?;
+
+    window.close()?;
+    Ok(())
+}
+
+#[widgem_test_kit::test]
+pub fn mouse(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    window.resize(200, 150)?;
+    window.mouse_move(50, 30)?;
+    ctx.connection().mouse_click(1)?;
+    ctx.snapshot(&mut window, "row clicked")?;
+    ctx.connection().mouse_scroll_down()?;
+    ctx.snapshot(&mut window, "scrolled down")?;
+    ctx.connection().mouse_scroll_up()?;
+    ctx.snapshot(&mut window, "scrolled up")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -1,8 +1,26 @@
 mod button;
+mod button_icon;
+mod combo_box;
+mod grid;
+mod group_box;
 mod label;
+mod list_view;
 mod menu;
+mod message_box;
+mod mnemonic;
+mod progress_bar;
+mod rich_label;
 mod scroll_bar;
+mod slider;
+mod spin_box;
+mod splitter;
+mod tab_widget;
+mod table_view;
+mod text_area;
 mod text_input;
+mod tool_bar;
+mod tooltip;
+mod tree_view;
 
 use std::{
     env,
@@ -12,7 +12,11 @@ pub struct RootWidget {
 
 impl RootWidget {
     fn on_triggered(&mut self, _event: ()) -> anyhow::Result<()> {
-        self.base.add_child::<Menu>();
+        let menu = self.base.add_child::<Menu>();
+        menu.add_item().set_text("Open");
+        menu.add_item().set_text("Save");
+        menu.add_separator();
+        menu.add_item().set_text("Quit");
         Ok(())
     }
 }
@@ -42,7 +46,19 @@ fn menu(ctx: &mut Context) -> anyhow::Result<()> {
         r.base_mut().add_child::<RootWidget>();
         Ok(())
     })?;
-    let window = ctx.wait_for_window_by_pid()?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "button")?;
+    window.mouse_move(20, 15)?;
+    ctx.connection().mouse_click(1)?;
+    let mut menu = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut menu, "menu opened")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut menu, "first item highlighted")?;
+    ctx.connection().key("Down")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut menu, "separator skipped")?;
+    ctx.connection().key("Escape")?;
+    ctx.snapshot(&mut window, "menu closed")?;
     window.close()?;
     Ok(())
 }
@@ -0,0 +1,138 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            button::Button,
+            dialog::{Dialog, DialogResult},
+            label::Label,
+            message_box::{MessageBox, MessageBoxIcon, StandardButton, StandardButtons},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt, WidgetId,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+    status_id: WidgetId<Label>,
+}
+
+impl RootWidget {
+    fn open_message_box(&mut self, _event: ()) -> anyhow::Result<()> {
+        let status_id = self.status_id;
+        self.base
+            .add_child::<MessageBox>()
+            .set_title("Confirm")
+            .set_icon(MessageBoxIcon::Question)
+            .set_text("Delete R&D notes?")
+            .set_buttons(StandardButtons::YesNoCancel)
+            .on_finished(status_id.callback(|label, button: StandardButton| {
+                label.set_text(format!("Message box: {}", button.text()));
+                Ok(())
+            }));
+        Ok(())
+    }
+
+    fn open_dialog(&mut self, _event: ()) -> anyhow::Result<()> {
+        let status_id = self.status_id;
+        let dialog = self
+            .base
+            .add_child::<Dialog>()
+            .set_title("Dialog")
+            .on_finished(status_id.callback(|label, result: DialogResult| {
+                label.set_text(format!("Dialog: {result:?}"));
+                Ok(())
+            }));
+        dialog
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("Press Enter to accept or Escape to reject.");
+        Ok(())
+    }
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let id = base.id();
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("Message box")
+            .on_triggered(id.callback(Self::open_message_box));
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(1)
+            .set_row(0)
+            .set_text("Dialog")
+            .on_triggered(id.callback(Self::open_dialog));
+        let status_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("No result")
+            .id();
+
+        Self { base, status_id }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn message_box(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "buttons")?;
+
+    ctx.connection().key("space")?;
+    let mut message_box = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut message_box, "message box with default button")?;
+    ctx.connection().key("Escape")?;
+    ctx.snapshot(&mut window, "message box cancelled")?;
+
+    ctx.connection().key("space")?;
+    let mut message_box = ctx.wait_for_new_window_by_pid(&[&window, &message_box])?;
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut message_box, "second button focused")?;
+    ctx.connection().key("space")?;
+    ctx.snapshot(&mut window, "second button pressed")?;
+
+    window.close()?;
+    Ok(())
+}
+
+#[widgem_test_kit::test]
+pub fn dialog(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.connection().key("Tab")?;
+    ctx.connection().key("space")?;
+    let mut dialog = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut dialog, "dialog opened")?;
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "dialog accepted")?;
+
+    ctx.connection().key("space")?;
+    let mut dialog = ctx.wait_for_new_window_by_pid(&[&window, &dialog])?;
+    ctx.snapshot(&mut dialog, "dialog opened again")?;
+    ctx.connection().key("Escape")?;
+    ctx.snapshot(&mut window, "dialog rejected")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            button::Button, label::Label, text_input::TextInput, window::Window, Widget,
+            WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let status_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(1)
+            .set_row(0)
+            .set_text("Not saved")
+            .id();
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_column(0)
+            .set_row(0)
+            .set_text("&Save")
+            .on_triggered(status_id.callback(|label, ()| {
+                label.set_text("Saved");
+                Ok(())
+            }));
+
+        let input_id = window
+            .base_mut()
+            .add_child::<TextInput>()
+            .set_column(1)
+            .set_row(1)
+            .id();
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("&Name:")
+            .set_buddy(Some(input_id.raw()));
+
+        // Ampersands in plain text are displayed as is and don't create mnemonics.
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(2)
+            .set_text("Tom & Jerry");
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(3)
+            .set_text("R&&D");
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn mnemonic(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "labels and button")?;
+    ctx.connection().key("Alt+D")?;
+    ctx.snapshot(&mut window, "no mnemonic for escaped ampersand")?;
+    ctx.connection().key("Alt+S")?;
+    ctx.snapshot(&mut window, "button triggered")?;
+    ctx.set_blinking_expected(true);
+    ctx.connection().key("Alt+N")?;
+    ctx.snapshot(&mut window, "buddy focused")?;
+    ctx.set_blinking_expected(false);
+    window.close()?;
+    Ok(())
+}
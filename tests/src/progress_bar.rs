@@ -0,0 +1,104 @@
+use {
+    widgem::{
+        impl_widget_base,
+        shortcut::{KeyCombinations, Shortcut, ShortcutScope},
+        types::Axis,
+        widgets::{progress_bar::ProgressBar, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+    value: i32,
+    is_text_visible: bool,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let on_up = base.callback(|this, _| {
+            this.value = (this.value + 25).min(100);
+            this.base.update();
+            Ok(())
+        });
+        let on_t = base.callback(|this, _| {
+            this.is_text_visible = !this.is_text_visible;
+            this.base.update();
+            Ok(())
+        });
+        base.add_shortcut(Shortcut::new(
+            KeyCombinations::from_str_portable("Up").unwrap(),
+            ShortcutScope::Application,
+            on_up,
+        ));
+        base.add_shortcut(Shortcut::new(
+            KeyCombinations::from_str_portable("T").unwrap(),
+            ShortcutScope::Application,
+            on_t,
+        ));
+
+        Self {
+            base,
+            value: 0,
+            is_text_visible: true,
+        }
+    }
+
+    fn handle_declare_children_request(&mut self) -> anyhow::Result<()> {
+        let window = self
+            .base
+            .declare_child::<Window>()
+            .set_title(module_path!());
+
+        window
+            .base_mut()
+            .declare_child::<ProgressBar>()
+            .set_column(0)
+            .set_row(0)
+            .set_value(self.value)
+            .set_text_visible(self.is_text_visible);
+        window
+            .base_mut()
+            .declare_child::<ProgressBar>()
+            .set_column(0)
+            .set_row(1)
+            .set_value_range(0..=20)
+            .set_value(self.value / 5)
+            .set_format("{value} of {max}")
+            .set_text_visible(self.is_text_visible);
+        window
+            .base_mut()
+            .declare_child::<ProgressBar>()
+            .set_column(1)
+            .set_row(0)
+            .set_axis(Axis::Y)
+            .set_value(self.value)
+            .set_text_visible(self.is_text_visible);
+
+        Ok(())
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn progress_bar(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "empty progress bars")?;
+    ctx.connection().key("Up")?;
+    ctx.snapshot(&mut window, "quarter")?;
+    ctx.connection().key("Up")?;
+    ctx.connection().key("Up")?;
+    ctx.snapshot(&mut window, "three quarters")?;
+    ctx.connection().key("Up")?;
+    ctx.snapshot(&mut window, "full")?;
+    ctx.connection().key("t")?;
+    ctx.snapshot(&mut window, "text hidden")?;
+
+    window.close()?;
+    Ok(())
+}
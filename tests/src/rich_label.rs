@@ -0,0 +1,93 @@
+use {
+    widgem::{
+        impl_widget_base,
+        rich_text::{RichText, SpanStyle},
+        tiny_skia::Color,
+        widgets::{label::Label, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        let status_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(3)
+            .set_text("No link activated")
+            .id();
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(0)
+            .set_rich_text(RichText::from_markup(
+                "Open [the docs](docs) or [the source](source) to learn more.",
+            ))
+            .on_link_activated(status_id.callback(|label, link: String| {
+                label.set_text(format!("Activated link: {link}"));
+                Ok(())
+            }));
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_rich_text(
+                RichText::from_markup("**Bold**, *italic* and `monospace` text. ")
+                    .with_span(
+                        "Red text. ",
+                        SpanStyle::new().with_color(Color::from_rgba8(200, 0, 0, 255)),
+                    )
+                    .with_span("Large text.", SpanStyle::new().with_font_scale(1.5)),
+            );
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(2)
+            .set_word_wrap(true)
+            .set_text(
+                "This is a long paragraph that is wrapped to multiple lines \
+                 when the window is not wide enough to fit it on a single line.",
+            );
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn rich_label(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "first link focused")?;
+
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "second link focused")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "focus wrapped to first link")?;
+    ctx.connection().key("Left")?;
+    ctx.snapshot(&mut window, "focus wrapped to last link")?;
+
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "second link activated")?;
+    ctx.connection().key("Left")?;
+    ctx.connection().key("space")?;
+    ctx.snapshot(&mut window, "first link activated")?;
+
+    window.resize(200, 300)?;
+    ctx.snapshot(&mut window, "text wrapped")?;
+
+    window.close()?;
+    Ok(())
+}
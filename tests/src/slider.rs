@@ -0,0 +1,86 @@
+use {
+    widgem::{
+        impl_widget_base,
+        types::Axis,
+        widgets::{
+            label::Label,
+            slider::{Slider, TickPosition},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let label_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("0")
+            .id();
+        window
+            .base_mut()
+            .add_child::<Slider>()
+            .set_column(0)
+            .set_row(0)
+            .set_value_range(0..=100)
+            .set_tick_position(TickPosition::Both)
+            .set_tick_interval(10)
+            .on_value_changed(label_id.callback(|label, value| {
+                label.set_text(value);
+                Ok(())
+            }));
+        window
+            .base_mut()
+            .add_child::<Slider>()
+            .set_column(1)
+            .set_row(0)
+            .set_axis(Axis::Y)
+            .set_value_range(0..=10)
+            .set_tick_position(TickPosition::After)
+            .set_tick_interval(1);
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "horizontal and vertical sliders")?;
+
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "step up")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.snapshot(&mut window, "page up")?;
+    ctx.connection().key("Left")?;
+    ctx.snapshot(&mut window, "step down")?;
+    ctx.connection().key("End")?;
+    ctx.snapshot(&mut window, "maximum")?;
+    ctx.connection().key("Home")?;
+    ctx.snapshot(&mut window, "minimum")?;
+
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "vertical slider focused")?;
+    ctx.connection().key("Up")?;
+    ctx.connection().key("Up")?;
+    ctx.snapshot(&mut window, "vertical slider moved up")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{spin_box::SpinBox, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        window
+            .base_mut()
+            .add_child::<SpinBox>()
+            .set_column(0)
+            .set_row(0)
+            .set_range(0.0, 10.0)
+            .set_step(0.5)
+            .set_decimals(2)
+            .set_prefix("$");
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    ctx.set_blinking_expected(true);
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "spin box with prefix")?;
+    ctx.connection().key("Up")?;
+    ctx.snapshot(&mut window, "step up")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.snapshot(&mut window, "page up")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "step down")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.snapshot(&mut window, "clamped to maximum")?;
+
+    ctx.connection().key("BackSpace")?;
+    ctx.connection().type_text("x")?;
+    ctx.snapshot(&mut window, "invalid text")?;
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "invalid text reverted")?;
+
+    ctx.connection().key("BackSpace")?;
+    ctx.connection().key("BackSpace")?;
+    ctx.connection().key("BackSpace")?;
+    ctx.snapshot(&mut window, "valid text edited")?;
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "edited value committed")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            label::Label, splitter::Splitter, window::Window, Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let splitter = window
+            .base_mut()
+            .add_child::<Splitter>()
+            .set_column(0)
+            .set_row(0);
+        for name in ["Left", "Middle", "Right"] {
+            splitter.add_pane::<Label>(name).set_text(name);
+        }
+        splitter.set_collapsible(0, true);
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    window.resize(300, 100)?;
+    ctx.snapshot(&mut window, "splitter with three panes")?;
+
+    ctx.connection().key("Right")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "first handle moved right")?;
+    ctx.connection().key("Home")?;
+    ctx.snapshot(&mut window, "first pane collapsed")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "first pane restored")?;
+
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "second handle focused")?;
+    ctx.connection().key("Left")?;
+    ctx.connection().key("Left")?;
+    ctx.snapshot(&mut window, "second handle moved left")?;
+
+    window.resize(400, 100)?;
+    ctx.snapshot(&mut window, "resized")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            label::Label, tab_widget::TabWidget, window::Window, Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        for (column, prefix) in ["Left", "Right"].into_iter().enumerate() {
+            let tabs = window
+                .base_mut()
+                .add_child::<TabWidget>()
+                .set_column(column as i32)
+                .set_row(0);
+            for title in ["One", "Two", "Three"] {
+                tabs.add_page::<Label>(title, title)
+                    .set_text(format!("{prefix} page {title}"));
+            }
+        }
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn tab_widget(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "two tab widgets")?;
+
+    // Only the tab widget that contains the focused widget reacts to the shortcuts.
+    ctx.connection().key("Ctrl+Tab")?;
+    ctx.snapshot(&mut window, "left widget switched to next page")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "left widget switched to last page")?;
+    ctx.connection().key("Home")?;
+    ctx.snapshot(&mut window, "left widget switched to first page")?;
+
+    ctx.connection().key("Tab")?;
+    ctx.snapshot(&mut window, "right widget focused")?;
+    ctx.connection().key("Ctrl+Shift+Tab")?;
+    ctx.snapshot(&mut window, "right widget switched to last page")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use {
+    std::rc::Rc,
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            table_view::{TableModel, TableView},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+struct Fruits(Vec<(&'static str, u32)>);
+
+impl TableModel for Fruits {
+    fn row_count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn column_count(&self) -> usize {
+        2
+    }
+
+    fn data(&self, row: usize, column: usize) -> String {
+        let (name, count) = self.0[row];
+        if column == 0 {
+            name.into()
+        } else {
+            count.to_string()
+        }
+    }
+
+    fn header_data(&self, column: usize) -> String {
+        if column == 0 { "Name" } else { "Count" }.into()
+    }
+}
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let model = Fruits(vec![
+            ("Cherry", 30),
+            ("Apple", 5),
+            ("Date", 12),
+            ("Banana", 7),
+        ]);
+        window
+            .base_mut()
+            .add_child::<TableView>()
+            .set_column(0)
+            .set_row(0)
+            .set_sorting_enabled(true)
+            .set_model(Rc::new(model));
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "table view")?;
+
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "first cell selected")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "second column")?;
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "second row")?;
+    ctx.connection().key("Home")?;
+    ctx.snapshot(&mut window, "first column")?;
+    ctx.connection().key("Ctrl+End")?;
+    ctx.snapshot(&mut window, "last row")?;
+
+    window.close()?;
+    Ok(())
+}
+
+#[widgem_test_kit::test]
+pub fn sorting(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    window.mouse_move(20, 10)?;
+    ctx.connection().mouse_click(1)?;
+    ctx.snapshot(&mut window, "sorted by name ascending")?;
+    ctx.connection().mouse_click(1)?;
+    ctx.snapshot(&mut window, "sorted by name descending")?;
+
+    window.close()?;
+    Ok(())
+}
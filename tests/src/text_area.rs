@@ -0,0 +1,121 @@
+use {
+    itertools::Itertools,
+    widgem::{
+        impl_widget_base,
+        shortcut::{KeyCombinations, Shortcut, ShortcutScope},
+        widgets::{
+            label::Label, text_area::TextArea, window::Window, Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        let status_id = window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("Not edited")
+            .id();
+        let text = (1..=30)
+            .map(|i| format!("Line {i}"))
+            .chain([
+                "A long line that is wrapped when word wrap is enabled and scrolled otherwise"
+                    .into(),
+            ])
+            .join("\n");
+        let text_area = window
+            .base_mut()
+            .add_child::<TextArea>()
+            .set_column(0)
+            .set_row(0);
+        text_area.set_text(text);
+        text_area.on_text_edited(status_id.callback(|label, text: String| {
+            label.set_text(format!("Edited: {} lines", text.lines().count()));
+            Ok(())
+        }));
+        let text_area_id = text_area.id();
+
+        base.add_shortcut(Shortcut::new(
+            KeyCombinations::from_str_portable("F2").unwrap(),
+            ShortcutScope::Application,
+            text_area_id.callback(|text_area, _| {
+                let enabled = !text_area.is_word_wrap_enabled();
+                text_area.set_word_wrap(enabled);
+                Ok(())
+            }),
+        ));
+        base.add_shortcut(Shortcut::new(
+            KeyCombinations::from_str_portable("F3").unwrap(),
+            ShortcutScope::Application,
+            text_area_id.callback(|text_area, _| {
+                text_area.undo();
+                Ok(())
+            }),
+        ));
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keys(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    ctx.set_blinking_expected(true);
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "text area")?;
+
+    ctx.connection().key("Page_Down")?;
+    ctx.snapshot(&mut window, "cursor moved one page down")?;
+    ctx.connection().key("Page_Down")?;
+    ctx.snapshot(&mut window, "cursor moved two pages down and scrolled")?;
+    ctx.connection().key("Page_Up")?;
+    ctx.snapshot(&mut window, "cursor moved one page up")?;
+    ctx.connection().key("Ctrl+End")?;
+    ctx.snapshot(&mut window, "cursor moved to the end and scrolled")?;
+    ctx.connection().key("Ctrl+Home")?;
+    ctx.snapshot(&mut window, "cursor moved to the start and scrolled")?;
+
+    window.close()?;
+    Ok(())
+}
+
+#[widgem_test_kit::test]
+pub fn edit(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    ctx.set_blinking_expected(true);
+    let mut window = ctx.wait_for_window_by_pid()?;
+
+    ctx.connection().key("Ctrl+End")?;
+    ctx.connection().key("Return")?;
+    ctx.connection().type_text("New line")?;
+    ctx.snapshot(&mut window, "typed at the end")?;
+    // Undo from an application shortcut, like an Edit menu would.
+    ctx.connection().key("F3")?;
+    ctx.snapshot(&mut window, "typing undone")?;
+
+    ctx.connection().key("Ctrl+Home")?;
+    ctx.connection().key("F2")?;
+    ctx.snapshot(&mut window, "word wrap disabled")?;
+    ctx.connection().key("Ctrl+End")?;
+    ctx.snapshot(&mut window, "scrolled horizontally to the cursor")?;
+    ctx.connection().key("F2")?;
+    ctx.snapshot(&mut window, "word wrap enabled")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use {
+    std::time::Duration,
+    widgem::{
+        impl_widget_base,
+        shortcut::KeyCombinations,
+        widgets::{
+            label::Label, status_bar::StatusBar, tool_bar::ToolBar, window::Window, Widget,
+            WidgetBaseOf, WidgetExt, WidgetId,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+fn add_tool_button(
+    tool_bar: &mut ToolBar,
+    status_id: WidgetId<StatusBar>,
+    text: &'static str,
+    shortcut: &str,
+) {
+    tool_bar
+        .add_button()
+        .set_text(text)
+        .set_shortcut(Some(KeyCombinations::from_str_portable(shortcut).unwrap()))
+        .on_triggered(status_id.callback(move |status_bar, ()| {
+            status_bar.show_message(format!("{text} triggered"), None);
+            Ok(())
+        }));
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        let status_bar = window
+            .base_mut()
+            .add_child::<StatusBar>()
+            .set_column(0)
+            .set_row(2);
+        status_bar.add_permanent_widget::<Label>().set_text("UTF-8");
+        status_bar.add_permanent_widget::<Label>().set_text("Ln 1");
+        let status_id = status_bar.id();
+
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_column(0)
+            .set_row(1)
+            .set_text("Document content");
+
+        let tool_bar = window
+            .base_mut()
+            .add_child::<ToolBar>()
+            .set_column(0)
+            .set_row(0);
+        add_tool_button(tool_bar, status_id, "New", "Ctrl+N");
+        add_tool_button(tool_bar, status_id, "Open", "Ctrl+O");
+        tool_bar.add_separator();
+        add_tool_button(tool_bar, status_id, "Bold", "Ctrl+B");
+        tool_bar.button_mut(3).unwrap().set_checkable(true);
+        add_tool_button(tool_bar, status_id, "Italic", "Ctrl+I");
+        tool_bar
+            .add_button()
+            .set_text("Save")
+            .set_shortcut(Some(KeyCombinations::from_str_portable("Ctrl+S").unwrap()))
+            .on_triggered(status_id.callback(|status_bar, ()| {
+                status_bar.show_message("Saved", Some(Duration::from_secs(2)));
+                Ok(())
+            }));
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn tool_bar(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "tool bar and status bar")?;
+
+    ctx.connection().key("Ctrl+N")?;
+    ctx.snapshot(&mut window, "status message shown")?;
+    ctx.connection().key("Ctrl+B")?;
+    ctx.snapshot(&mut window, "checkable button checked")?;
+
+    ctx.connection().key("Ctrl+S")?;
+    ctx.snapshot(&mut window, "temporary message shown")?;
+    std::thread::sleep(Duration::from_secs(3));
+    ctx.snapshot(&mut window, "temporary message cleared")?;
+
+    window.resize(160, 120)?;
+    ctx.snapshot(&mut window, "items moved to overflow menu")?;
+    window.mouse_move(150, 15)?;
+    ctx.connection().mouse_click(1)?;
+    let mut menu = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut menu, "overflow menu")?;
+    ctx.connection().key("Up")?;
+    ctx.connection().key("Return")?;
+    ctx.snapshot(&mut window, "overflow menu item triggered")?;
+
+    window.close()?;
+    Ok(())
+}
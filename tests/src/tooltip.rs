@@ -0,0 +1,60 @@
+use {
+    widgem::{
+        impl_widget_base,
+        widgets::{button::Button, label::Label, window::Window, Widget, WidgetBaseOf, WidgetExt},
+    },
+    widgem_test_kit::context::Context,
+};
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+        window
+            .base_mut()
+            .add_child::<Button>()
+            .set_text("Save")
+            .set_tooltip("Saves the document")
+            .set_column(0)
+            .set_row(0);
+        window
+            .base_mut()
+            .add_child::<Label>()
+            .set_text("No tooltip here")
+            .set_column(0)
+            .set_row(1);
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn tooltip(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "window")?;
+
+    window.mouse_move(20, 15)?;
+    let mut tooltip = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut tooltip, "tooltip shown")?;
+
+    // Any key press hides the tooltip.
+    ctx.connection().key("Shift_L")?;
+    ctx.snapshot(&mut window, "tooltip hidden")?;
+
+    // The tooltip is shown again after the pointer leaves and re-enters the button.
+    window.mouse_move(0, 0)?;
+    window.mouse_move(20, 15)?;
+    let mut tooltip = ctx.wait_for_new_window_by_pid(&[&window])?;
+    ctx.snapshot(&mut tooltip, "tooltip shown again")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use {
+    std::rc::Rc,
+    widgem::{
+        impl_widget_base,
+        widgets::{
+            tree_view::{TreeModel, TreeView},
+            window::Window,
+            Widget, WidgetBaseOf, WidgetExt,
+        },
+    },
+    widgem_test_kit::context::Context,
+};
+
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(name: &'static str, children: Vec<Node>) -> Self {
+        Self { name, children }
+    }
+
+    fn get(&self, path: &[usize]) -> &Node {
+        path.iter().fold(self, |node, &index| &node.children[index])
+    }
+}
+
+impl TreeModel for Node {
+    fn child_count(&self, path: &[usize]) -> usize {
+        self.get(path).children.len()
+    }
+
+    fn data(&self, path: &[usize]) -> String {
+        self.get(path).name.into()
+    }
+}
+
+pub struct RootWidget {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for RootWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.add_child::<Window>().set_title(module_path!());
+
+        let model = Node::new(
+            "",
+            vec![
+                Node::new(
+                    "Fruits",
+                    vec![Node::new("Apple", vec![]), Node::new("Banana", vec![])],
+                ),
+                Node::new(
+                    "Vegetables",
+                    vec![
+                        Node::new("Carrot", vec![]),
+                        Node::new(
+                            "Cabbage",
+                            vec![Node::new("Red", vec![]), Node::new("White", vec![])],
+                        ),
+                    ],
+                ),
+                Node::new("Other", vec![]),
+            ],
+        );
+        window
+            .base_mut()
+            .add_child::<TreeView>()
+            .set_column(0)
+            .set_row(0)
+            .set_model(Rc::new(model))
+            .expand(&[1]);
+
+        Self { base }
+    }
+}
+
+#[widgem_test_kit::test]
+pub fn keyboard(ctx: &mut Context) -> anyhow::Result<()> {
+    ctx.run(|r| {
+        r.base_mut().add_child::<RootWidget>();
+        Ok(())
+    })?;
+    let mut window = ctx.wait_for_window_by_pid()?;
+    ctx.snapshot(&mut window, "tree with expanded node")?;
+
+    ctx.connection().key("Down")?;
+    ctx.snapshot(&mut window, "first node selected")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "first node expanded")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "moved to first child")?;
+    ctx.connection().key("Left")?;
+    ctx.snapshot(&mut window, "moved to parent")?;
+    ctx.connection().key("Left")?;
+    ctx.snapshot(&mut window, "first node collapsed")?;
+
+    ctx.connection().key("Down")?;
+    ctx.connection().key("Down")?;
+    ctx.connection().key("Down")?;
+    ctx.connection().key("Right")?;
+    ctx.snapshot(&mut window, "nested node expanded")?;
+    ctx.connection().key("End")?;
+    ctx.snapshot(&mut window, "last node selected")?;
+
+    window.close()?;
+    Ok(())
+}
@@ -16,6 +16,8 @@ pub struct AccessibleNodes {
     // TODO: BTreeMap? sort by visible row+column?
     direct_children: HashMap<NodeId, Vec<(Key, NodeId)>>,
     direct_parents: HashMap<NodeId, NodeId>,
+    // Nodes that have `labelled_by` relations.
+    labelled_nodes: HashSet<NodeId>,
 
     pending_updates: HashSet<NodeId>,
     root: NodeId,
@@ -30,6 +32,7 @@ impl AccessibleNodes {
             nodes: Default::default(),
             direct_children: Default::default(),
             direct_parents: Default::default(),
+            labelled_nodes: Default::default(),
             pending_updates: Default::default(),
             root,
             focus: root,
@@ -44,6 +47,7 @@ impl AccessibleNodes {
 
     pub fn clear(&mut self) {
         self.nodes.clear();
+        self.labelled_nodes.clear();
         self.pending_updates.clear();
 
         let root_node = Node::new(Role::Group);
@@ -105,13 +109,28 @@ impl AccessibleNodes {
     pub fn update(&mut self, id: NodeId, node: Option<Node>) {
         let added_or_removed;
         if let Some(node) = node {
+            if node.labelled_by().is_empty() {
+                self.labelled_nodes.remove(&id);
+            } else {
+                self.labelled_nodes.insert(id);
+            }
             let r = self.nodes.insert(id, node);
             added_or_removed = r.is_none();
         } else {
+            self.labelled_nodes.remove(&id);
             let r = self.nodes.remove(&id);
             added_or_removed = r.is_some();
         }
         self.pending_updates.insert(id);
+        if added_or_removed {
+            // Relations are only sent for existing nodes, so the nodes that refer to this node
+            // have to be updated.
+            for labelled in &self.labelled_nodes {
+                if self.nodes[labelled].labelled_by().contains(&id) {
+                    self.pending_updates.insert(*labelled);
+                }
+            }
+        }
         if added_or_removed && id != self.root {
             if let Some(parent) = self.direct_parents.get(&id) {
                 self.mark_parent_as_pending(*parent);
@@ -134,6 +153,15 @@ impl AccessibleNodes {
                 find_children(id, &self.direct_children, &self.nodes, &mut children);
                 let mut node = node.clone();
                 node.set_children(children);
+                if !node.labelled_by().is_empty() {
+                    let labelled_by = node
+                        .labelled_by()
+                        .iter()
+                        .copied()
+                        .filter(|id| self.nodes.contains_key(id))
+                        .collect::<Vec<_>>();
+                    node.set_labelled_by(labelled_by);
+                }
                 nodes.push((id, node));
             }
         }
@@ -151,14 +151,25 @@ where
     W: Widget,
     F: Fn(&mut W, E) -> Result<()> + 'static,
     E: 'static,
+{
+    raw_widget_callback(widget_id.raw(), move |widget, event| {
+        let widget = widget
+            .downcast_mut::<W>()
+            .context("widget downcast failed")?;
+        func(widget, event)
+    })
+}
+
+// Creates a callback that receives the widget without downcasting it.
+pub(crate) fn raw_widget_callback<E, F>(widget_id: RawWidgetId, func: F) -> Callback<E>
+where
+    F: Fn(&mut dyn Widget, E) -> Result<()> + 'static,
+    E: 'static,
 {
     let callback_id = CallbackId::new();
     let data = WidgetCallbackData {
-        widget_id: widget_id.raw(),
+        widget_id,
         func: Rc::new(move |widget, any_event| {
-            let widget = widget
-                .downcast_mut::<W>()
-                .context("widget downcast failed")?;
             let event = any_event
                 .downcast::<E>()
                 .map_err(|_| anyhow!("event downcast failed"))?;
@@ -169,7 +180,7 @@ where
     Callback::new(
         with_system(|s| s.event_loop_proxy.clone()),
         callback_id,
-        widget_id.raw(),
+        widget_id,
     )
 }
 
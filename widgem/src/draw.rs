@@ -4,7 +4,7 @@ use {
             common::{ComputedBackground, ComputedBorderStyle},
             RelativeOffset,
         },
-        types::{Point, PpxSuffix, Rect},
+        types::{Corners, Point, PpxSuffix, Rect, Sides},
     },
     log::warn,
     std::{cell::RefCell, rc::Rc},
@@ -113,57 +113,48 @@ impl DrawEvent {
         );
     }
 
-    fn rounded_rect_path(&self, rect: Rect, mut radius: f32, width: f32) -> Path {
-        if radius > (rect.size_x().to_i32() as f32 / 2.0)
-            || radius > (rect.size_y().to_i32() as f32 / 2.0)
-        {
-            //TODO do something here, log some error
-            warn!("radius is bigger than fits in rectangle");
-            radius = 0.0;
+    // `insets` are the distances from the edges of `rect` to the path.
+    fn rounded_rect_path(&self, rect: Rect, mut radius: Corners<f32>, insets: Sides<f32>) -> Path {
+        let max_radius =
+            (rect.size_x().to_i32() as f32 / 2.0).min(rect.size_y().to_i32() as f32 / 2.0);
+        for radius in [
+            &mut radius.top_left,
+            &mut radius.top_right,
+            &mut radius.bottom_right,
+            &mut radius.bottom_left,
+        ] {
+            if *radius > max_radius {
+                //TODO do something here, log some error
+                warn!("radius is bigger than fits in rectangle");
+                *radius = 0.0;
+            }
         }
-        let top_left_point = rect.top_left();
-        let top_left = tiny_skia::Point {
-            x: top_left_point.x().to_i32() as f32 + width / 2.0,
-            y: top_left_point.y().to_i32() as f32 + width / 2.0,
-        };
-        let size = tiny_skia::Point {
-            x: rect.size_x().to_i32() as f32 - width,
-            y: rect.size_y().to_i32() as f32 - width,
-        };
+        let left = rect.left().to_i32() as f32 + insets.left;
+        let top = rect.top().to_i32() as f32 + insets.top;
+        let right = rect.right().to_i32() as f32 - insets.right;
+        let bottom = rect.bottom().to_i32() as f32 - insets.bottom;
         let mut path_builder = PathBuilder::new();
-        path_builder.move_to(top_left.x + radius, top_left.y);
-        path_builder.line_to(top_left.x + size.x - radius, top_left.y);
-        rounded_line_in_square_corner(
-            &mut path_builder,
-            top_left.x + size.x,
-            top_left.y,
-            top_left.x + size.x,
-            top_left.y + radius,
-        );
-        path_builder.line_to(top_left.x + size.x, top_left.y + size.y - radius);
-        rounded_line_in_square_corner(
-            &mut path_builder,
-            top_left.x + size.x,
-            top_left.y + size.y,
-            top_left.x + size.x - radius,
-            top_left.y + size.y,
-        );
-        path_builder.line_to(top_left.x + radius, top_left.y + size.y);
+        path_builder.move_to(left + radius.top_left, top);
+        path_builder.line_to(right - radius.top_right, top);
+        rounded_line_in_square_corner(&mut path_builder, right, top, right, top + radius.top_right);
+        path_builder.line_to(right, bottom - radius.bottom_right);
         rounded_line_in_square_corner(
             &mut path_builder,
-            top_left.x,
-            top_left.y + size.y,
-            top_left.x,
-            top_left.y + size.y - radius,
+            right,
+            bottom,
+            right - radius.bottom_right,
+            bottom,
         );
-        path_builder.line_to(top_left.x, top_left.y + radius);
+        path_builder.line_to(left + radius.bottom_left, bottom);
         rounded_line_in_square_corner(
             &mut path_builder,
-            top_left.x,
-            top_left.y,
-            top_left.x + radius,
-            top_left.y,
+            left,
+            bottom,
+            left,
+            bottom - radius.bottom_left,
         );
+        path_builder.line_to(left, top + radius.top_left);
+        rounded_line_in_square_corner(&mut path_builder, left, top, left + radius.top_left, top);
         path_builder.finish().unwrap()
     }
 
@@ -204,11 +195,10 @@ impl DrawEvent {
         border: &ComputedBorderStyle,
         background: Option<&ComputedBackground>,
     ) {
-        let path = self.rounded_rect_path(
-            rect,
-            border.radius.to_i32() as f32,
-            border.width.to_i32() as f32,
-        );
+        let radius = border.radius.map(|radius| radius.to_i32() as f32);
+        let width = border.width.map(|width| width.to_i32() as f32);
+        // The path goes through the middle of the border.
+        let path = self.rounded_rect_path(rect, radius, width.map(|width| width / 2.0));
         if let Some(background) = background {
             let shader = match background {
                 ComputedBackground::Solid { color } => Shader::SolidColor(*color),
@@ -226,13 +216,123 @@ impl DrawEvent {
             };
             self.fill_path(&path, shader);
         }
-        if border.width > 0.ppx() {
-            self.stroke_path(&path, border.color, border.width.to_i32() as f32);
+        if width.is_uniform() && border.color.is_uniform() {
+            if width.left > 0.0 {
+                self.stroke_path(&path, border.color.left, width.left);
+            }
+        } else {
+            self.fill_border_sides(rect, radius, width, &border.color);
+        }
+    }
+
+    // Draws a border with different widths or colors of the sides.
+    fn fill_border_sides(
+        &self,
+        rect: Rect,
+        radius: Corners<f32>,
+        width: Sides<f32>,
+        color: &Sides<Color>,
+    ) {
+        let (size_x, size_y) = (rect.size_x().to_i32() as f32, rect.size_y().to_i32() as f32);
+        let mut path_builder = PathBuilder::new();
+        path_builder.push_path(&self.rounded_rect_path(rect, radius, Sides::all(0.0)));
+        if width.left + width.right < size_x && width.top + width.bottom < size_y {
+            let inner_radius = Corners::new(
+                radius.top_left - width.left.max(width.top),
+                radius.top_right - width.right.max(width.top),
+                radius.bottom_right - width.right.max(width.bottom),
+                radius.bottom_left - width.left.max(width.bottom),
+            )
+            .map(|radius| radius.max(0.0));
+            path_builder.push_path(&self.rounded_rect_path(rect, inner_radius, width));
+        }
+        let Some(border_path) = path_builder.finish() else {
+            return;
+        };
+
+        // Each side is drawn in its own region. The regions are separated by the lines
+        // going from the outer corners of the border through its inner corners.
+        let max_x = width.left.max(width.right);
+        let max_y = width.top.max(width.bottom);
+        let extent = match (max_x > 0.0, max_y > 0.0) {
+            (true, true) => (size_x / 2.0 / max_x).min(size_y / 2.0 / max_y),
+            (true, false) => size_x / 2.0 / max_x,
+            (false, true) => size_y / 2.0 / max_y,
+            (false, false) => return,
+        };
+        let (left, top) = (rect.left().to_i32() as f32, rect.top().to_i32() as f32);
+        let (right, bottom) = (left + size_x, top + size_y);
+        let inner_top_left = (left + width.left * extent, top + width.top * extent);
+        let inner_top_right = (right - width.right * extent, top + width.top * extent);
+        let inner_bottom_right = (right - width.right * extent, bottom - width.bottom * extent);
+        let inner_bottom_left = (left + width.left * extent, bottom - width.bottom * extent);
+        let regions = [
+            (
+                width.left,
+                color.left,
+                [
+                    (left, bottom),
+                    (left, top),
+                    inner_top_left,
+                    inner_bottom_left,
+                ],
+            ),
+            (
+                width.top,
+                color.top,
+                [(left, top), (right, top), inner_top_right, inner_top_left],
+            ),
+            (
+                width.right,
+                color.right,
+                [
+                    (right, top),
+                    (right, bottom),
+                    inner_bottom_right,
+                    inner_top_right,
+                ],
+            ),
+            (
+                width.bottom,
+                color.bottom,
+                [
+                    (right, bottom),
+                    (left, bottom),
+                    inner_bottom_left,
+                    inner_bottom_right,
+                ],
+            ),
+        ];
+        for (width, color, points) in regions {
+            if width <= 0.0 {
+                continue;
+            }
+            let mut region = PathBuilder::new();
+            region.move_to(points[0].0, points[0].1);
+            for (x, y) in &points[1..] {
+                region.line_to(*x, *y);
+            }
+            region.close();
+            let Some(region) = region.finish() else {
+                continue;
+            };
+            let mut mask = self.mask.clone();
+            mask.intersect_path(&region, FillRule::Winding, false, self.transform);
+            self.pixmap.borrow_mut().fill_path(
+                &border_path,
+                &Paint {
+                    shader: Shader::SolidColor(color),
+                    ..Paint::default()
+                },
+                FillRule::EvenOdd,
+                self.transform,
+                Some(&mask),
+            );
         }
     }
 
     pub fn fill_rounded_rect(&self, rect: Rect, radius: f32, width: f32, shader: Shader) {
-        let path = self.rounded_rect_path(rect, radius, width);
+        let path = self.rounded_rect_path(rect, Corners::all(radius), Sides::all(width / 2.0));
         self.fill_path(&path, shader);
     }
 
@@ -241,7 +341,7 @@ impl DrawEvent {
         if width == 0.0 {
             return;
         }
-        let path = self.rounded_rect_path(rect, radius, width);
+        let path = self.rounded_rect_path(rect, Corners::all(radius), Sides::all(width / 2.0));
         self.stroke_path(&path, color, width);
     }
 
@@ -272,6 +372,22 @@ impl DrawEvent {
         );
     }
 
+    /// Returns a draw event that doesn't draw anything inside `rect`.
+    pub fn excluding_rect(&self, rect: Rect) -> Self {
+        let mut output = self.clone();
+        let mut path_builder = PathBuilder::new();
+        path_builder.push_rect(tiny_skia::Rect::try_from(self.mask_rect).expect("invalid rect"));
+        if let Ok(rect) = tiny_skia::Rect::try_from(rect.translate(self.top_left)) {
+            path_builder.push_rect(rect);
+        }
+        if let Some(path) = path_builder.finish() {
+            output
+                .mask
+                .intersect_path(&path, FillRule::EvenOdd, false, Transform::default());
+        }
+        output
+    }
+
     pub fn map_to_child(&self, rect_in_parent: Rect) -> Option<Self> {
         let rect = rect_in_parent.translate(self.top_left);
         let mask_rect = self.mask_rect.intersect(rect);
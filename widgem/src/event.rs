@@ -161,6 +161,8 @@ pub enum FocusReason {
     Tab,
     /// A widget was automatically focused because there was no focused widget previously.
     Auto,
+    /// A widget was focused by a mnemonic.
+    Mnemonic,
 }
 
 #[derive(Debug, Clone)]
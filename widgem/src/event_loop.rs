@@ -1,8 +1,9 @@
 use {
     crate::{
         callback::{CallbackId, InvokeCallbackEvent},
+        event::StyleChangeEvent,
         shared_window::{WindowId, WindowRequest},
-        style::defaults::default_style,
+        style::{defaults::default_style, watcher::StyleWatcher, Style},
         system::{
             address, take_pending_children_updates, with_system, ReportError,
             SharedSystemDataInner, SystemConfig, SYSTEM,
@@ -14,6 +15,7 @@ use {
         },
         window_handler::WindowHandler,
     },
+    anyhow::Context,
     arboard::Clipboard,
     cosmic_text::{fontdb, FontSystem, SwashCache},
     derive_more::From,
@@ -87,6 +89,10 @@ pub struct App {
     fixed_scale: Option<f32>,
     auto_repeat_delay: Option<Duration>,
     auto_repeat_interval: Option<Duration>,
+    tooltip_delay: Option<Duration>,
+    mnemonics_on_alt: bool,
+    style_file: Option<PathBuf>,
+    style_watch: bool,
 }
 
 impl Default for App {
@@ -103,6 +109,10 @@ impl App {
             fixed_scale: None,
             auto_repeat_delay: None,
             auto_repeat_interval: None,
+            tooltip_delay: None,
+            mnemonics_on_alt: false,
+            style_file: None,
+            style_watch: false,
         }
     }
 
@@ -131,6 +141,39 @@ impl App {
         self
     }
 
+    /// Sets the time the mouse pointer has to rest over a widget before its tooltip is shown.
+    pub fn with_tooltip_delay(mut self, delay: Duration) -> App {
+        self.tooltip_delay = Some(delay);
+        self
+    }
+
+    /// If enabled, mnemonics (e.g. "S" in "&Save") are only underlined while Alt is held.
+    ///
+    /// Mnemonics are always underlined by default.
+    pub fn with_mnemonics_on_alt(mut self, enable: bool) -> App {
+        self.mnemonics_on_alt = enable;
+        self
+    }
+
+    /// Loads the style from a CSS file instead of the default theme.
+    ///
+    /// Images referenced in the file are loaded relative to its directory.
+    /// If the file fails to load, the default theme is used.
+    pub fn with_style_file(mut self, path: PathBuf) -> App {
+        self.style_file = Some(path);
+        self
+    }
+
+    /// If enabled, the style is reloaded when the file set with
+    /// [with_style_file](Self::with_style_file) or any file referenced in it is modified.
+    ///
+    /// This is intended for theme development. If the modified file fails to load,
+    /// the error is logged and the previous style stays active.
+    pub fn with_style_watch(mut self, enable: bool) -> App {
+        self.style_watch = enable;
+        self
+    }
+
     pub fn run(
         self,
         init: impl FnOnce(&mut RootWidget) -> anyhow::Result<()> + 'static,
@@ -161,6 +204,7 @@ struct Handler {
     init: Option<BoxInitFn>,
     root_widget: Option<Box<dyn Widget>>,
     event_loop_proxy: Option<EventLoopProxy<UserEvent>>,
+    style_watcher: Option<StyleWatcher>,
 }
 
 impl Handler {
@@ -175,6 +219,7 @@ impl Handler {
             is_initialized: false,
             root_widget: None,
             event_loop_proxy: Some(event_loop.create_proxy()),
+            style_watcher: None,
         }
     }
 
@@ -183,6 +228,40 @@ impl Handler {
             while let Some(timer) = with_system(|system| system.timers.pop()) {
                 timer.callback.invoke(Instant::now());
             }
+            self.reload_style_if_changed();
+        }
+    }
+
+    fn load_style(&mut self) -> Style {
+        let Some(path) = &self.app.style_file else {
+            if self.app.style_watch {
+                warn!("style watch is enabled, but style file is not set");
+            }
+            return default_style();
+        };
+        let style = Style::load_from_file(path)
+            .with_context(|| format!("failed to load style from {path:?}"))
+            .or_report_err()
+            .unwrap_or_else(default_style);
+        if self.app.style_watch {
+            self.style_watcher = Some(StyleWatcher::new(path, &style));
+        }
+        style
+    }
+
+    fn reload_style_if_changed(&mut self) {
+        let Some(watcher) = &mut self.style_watcher else {
+            return;
+        };
+        // The files are read and parsed before borrowing the system data.
+        // If the new style is invalid, the previous style stays active.
+        let Some(style) = watcher.check().or_report_err().flatten() else {
+            return;
+        };
+        // Replacing the style also clears the cache of computed styles.
+        with_system(|system| system.style = style);
+        if let Some(root_widget) = &mut self.root_widget {
+            root_widget.dispatch(StyleChangeEvent {}.into());
         }
     }
 
@@ -229,6 +308,7 @@ impl Handler {
 
 const DEFAULT_AUTO_REPEAT_DELAY: Duration = Duration::from_millis(500);
 const DEFAULT_AUTO_REPEAT_INTERVAL: Duration = Duration::from_millis(50);
+const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(700);
 
 impl ApplicationHandler<UserEvent> for Handler {
     fn window_event(
@@ -285,6 +365,7 @@ impl ApplicationHandler<UserEvent> for Handler {
             }
             let font_system =
                 FontSystem::new_with_locale_and_db(FontSystem::new().locale().to_string(), db);
+            let style = self.load_style();
             let scale = match self.app.fixed_scale {
                 None => default_scale(event_loop),
                 Some(fixed_scale) => fixed_scale,
@@ -302,13 +383,15 @@ impl ApplicationHandler<UserEvent> for Handler {
                         .app
                         .auto_repeat_interval
                         .unwrap_or(DEFAULT_AUTO_REPEAT_INTERVAL),
+                    tooltip_delay: self.app.tooltip_delay.unwrap_or(DEFAULT_TOOLTIP_DELAY),
+                    mnemonics_on_alt: self.app.mnemonics_on_alt,
                 },
                 address_book: HashMap::new(),
                 font_system,
                 swash_cache: SwashCache::new(),
                 event_loop_proxy: self.event_loop_proxy.take().expect("only happens once"),
                 // TODO: how to detect monitor scale change?
-                style: default_style(),
+                style,
                 timers: Timers::new(),
                 clipboard: Clipboard::new().expect("failed to initialize clipboard"),
                 had_any_windows: false,
@@ -332,6 +415,7 @@ impl ApplicationHandler<UserEvent> for Handler {
                 parent_scale: scale,
                 is_parent_enabled: true,
                 is_window_root: false,
+                parent_style_element: None,
             };
             let mut root_widget = RootWidget::new(WidgetBase::new(ctx));
             self.init.take().expect("double init")(&mut root_widget).or_report_err();
@@ -434,8 +518,9 @@ impl ApplicationHandler<UserEvent> for Handler {
         ACTIVE_EVENT_LOOP.set(event_loop, || {
             self.before_handler();
             let next_timer = with_system(|system| system.timers.next_instant());
-            if let Some(next_timer) = next_timer {
-                event_loop.set_control_flow(ControlFlow::WaitUntil(next_timer));
+            let next_style_check = self.style_watcher.as_ref().map(|w| w.next_check());
+            if let Some(next_wakeup) = next_timer.into_iter().chain(next_style_check).min() {
+                event_loop.set_control_flow(ControlFlow::WaitUntil(next_wakeup));
             } else {
                 event_loop.set_control_flow(ControlFlow::Wait);
             }
@@ -0,0 +1,171 @@
+use {
+    crate::style::Style,
+    anyhow::{bail, Context, Result},
+    std::{fmt, path::Path, rc::Rc},
+    tiny_skia::{FilterQuality, Pixmap, PixmapPaint, Transform},
+};
+
+/// An image displayed in a widget, e.g. in a [Button](crate::widgets::button::Button).
+///
+/// Icons are rendered at the scale of the widget. In disabled widgets, a grayed out variant
+/// of the icon is displayed.
+#[derive(Clone)]
+pub enum Icon {
+    /// A raster image. One pixel of the image corresponds to one logical pixel.
+    Pixmap(Rc<Pixmap>),
+    /// A vector image.
+    Svg(Rc<usvg::Tree>),
+    /// An icon provided by the current theme.
+    ///
+    /// The name is the file name of the icon in the theme without the extension
+    /// (e.g. `"message_warning"`). If the theme contains an icon with the `_disabled` suffix,
+    /// it's used in disabled widgets.
+    Named(String),
+}
+
+impl Icon {
+    pub fn named(name: impl Into<String>) -> Self {
+        Icon::Named(name.into())
+    }
+
+    pub fn from_png_data(data: &[u8]) -> Result<Self> {
+        Ok(Icon::Pixmap(Rc::new(Pixmap::decode_png(data)?)))
+    }
+
+    pub fn from_svg_data(data: &[u8]) -> Result<Self> {
+        Ok(Icon::Svg(Rc::new(usvg::Tree::from_data(
+            data,
+            &Default::default(),
+        )?)))
+    }
+
+    /// Loads an SVG or PNG file, depending on the extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = fs_err::read(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => Self::from_svg_data(&data),
+            Some("png") => Self::from_png_data(&data),
+            _ => bail!("unsupported icon format: {path:?}"),
+        }
+    }
+
+    /// Renders the icon at the specified scale.
+    pub(crate) fn render(
+        &self,
+        style: &Style,
+        scale: f32,
+        is_disabled: bool,
+    ) -> Result<Rc<Pixmap>> {
+        let pixmap = match self {
+            Icon::Pixmap(pixmap) => scale_pixmap(pixmap, scale),
+            Icon::Svg(tree) => render_svg(tree, scale),
+            Icon::Named(name) => {
+                if is_disabled {
+                    if let Some(path) = find_theme_icon(style, &format!("{name}_disabled")) {
+                        return style.load_pixmap(&path, scale);
+                    }
+                }
+                let path = find_theme_icon(style, name)
+                    .with_context(|| format!("icon not found in theme: {name:?}"))?;
+                let pixmap = style.load_pixmap(&path, scale)?;
+                if !is_disabled {
+                    return Ok(pixmap);
+                }
+                (*pixmap).clone()
+            }
+        };
+        let pixmap = if is_disabled {
+            disabled_pixmap(pixmap)
+        } else {
+            pixmap
+        };
+        Ok(Rc::new(pixmap))
+    }
+}
+
+impl fmt::Debug for Icon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Icon::Pixmap(pixmap) => f
+                .debug_tuple("Pixmap")
+                .field(&(pixmap.width(), pixmap.height()))
+                .finish(),
+            Icon::Svg(tree) => f.debug_tuple("Svg").field(&tree.size()).finish(),
+            Icon::Named(name) => f.debug_tuple("Named").field(name).finish(),
+        }
+    }
+}
+
+impl From<Rc<Pixmap>> for Icon {
+    fn from(pixmap: Rc<Pixmap>) -> Self {
+        Icon::Pixmap(pixmap)
+    }
+}
+
+impl From<Pixmap> for Icon {
+    fn from(pixmap: Pixmap) -> Self {
+        Icon::Pixmap(Rc::new(pixmap))
+    }
+}
+
+// Returns the path of the theme file that contains the icon.
+fn find_theme_icon(style: &Style, name: &str) -> Option<String> {
+    ["svg", "png"]
+        .into_iter()
+        .map(|ext| format!("{name}.{ext}"))
+        .find(|path| style.has_resource(path))
+}
+
+pub(crate) fn render_svg(tree: &usvg::Tree, scale: f32) -> Pixmap {
+    let size_x = (tree.size().width() * scale).ceil().max(1.0) as u32;
+    let size_y = (tree.size().height() * scale).ceil().max(1.0) as u32;
+    let mut pixmap = Pixmap::new(size_x, size_y).unwrap();
+    resvg::render(
+        tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+    pixmap
+}
+
+pub(crate) fn scale_pixmap(pixmap: &Pixmap, scale: f32) -> Pixmap {
+    if scale == 1.0 {
+        return pixmap.clone();
+    }
+    let size_x = (pixmap.width() as f32 * scale).round().max(1.0) as u32;
+    let size_y = (pixmap.height() as f32 * scale).round().max(1.0) as u32;
+    let mut output = Pixmap::new(size_x, size_y).unwrap();
+    output.draw_pixmap(
+        0,
+        0,
+        pixmap.as_ref(),
+        &PixmapPaint {
+            quality: FilterQuality::Bicubic,
+            ..PixmapPaint::default()
+        },
+        Transform::from_scale(
+            size_x as f32 / pixmap.width() as f32,
+            size_y as f32 / pixmap.height() as f32,
+        ),
+        None,
+    );
+    output
+}
+
+// Opacity of disabled icons.
+const DISABLED_OPACITY: f32 = 0.5;
+
+/// Converts the pixmap to a translucent grayscale image.
+fn disabled_pixmap(mut pixmap: Pixmap) -> Pixmap {
+    for pixel in pixmap.data_mut().chunks_exact_mut(4) {
+        // The colors are premultiplied, so the gray value stays premultiplied as well.
+        let gray = 0.299 * pixel[0] as f32 + 0.587 * pixel[1] as f32 + 0.114 * pixel[2] as f32;
+        let gray = (gray * DISABLED_OPACITY).round() as u8;
+        pixel[0] = gray;
+        pixel[1] = gray;
+        pixel[2] = gray;
+        pixel[3] = (pixel[3] as f32 * DISABLED_OPACITY).round() as u8;
+    }
+    pixmap
+}
@@ -76,6 +76,30 @@ impl LayoutItemAxisOptions {
     }
 }
 
+/// Padding at the start and at the end of a layout axis
+/// (left and right for the X axis, top and bottom for the Y axis).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AxisPadding {
+    pub start: PhysicalPixels,
+    pub end: PhysicalPixels,
+}
+
+impl AxisPadding {
+    pub const ZERO: Self = Self::all(PhysicalPixels::ZERO);
+
+    pub const fn new(start: PhysicalPixels, end: PhysicalPixels) -> Self {
+        Self { start, end }
+    }
+
+    pub const fn all(value: PhysicalPixels) -> Self {
+        Self::new(value, value)
+    }
+
+    pub fn sum(self) -> PhysicalPixels {
+        self.start + self.end
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Alignment {
     Start,
@@ -107,7 +131,7 @@ pub(crate) struct LayoutItem {
 #[derive(Debug)]
 pub(crate) struct SolveLayoutOutput {
     pub(crate) sizes: Vec<PhysicalPixels>,
-    pub(crate) padding: PhysicalPixels,
+    pub(crate) padding: AxisPadding,
     pub(crate) spacing: PhysicalPixels,
 }
 
@@ -130,7 +154,7 @@ pub(crate) fn solve_layout(
         .iter()
         .map(|item| item.size_hints.preferred)
         .sum::<PhysicalPixels>()
-        + 2 * options.preferred_padding
+        + options.preferred_padding.sum()
         + items.len().saturating_sub(1) as i32
             * (options.preferred_spacing - options.border_collapse);
     if total_preferred == total {
@@ -143,7 +167,7 @@ pub(crate) fn solve_layout(
             .iter()
             .map(|item| item.size_hints.min)
             .sum::<PhysicalPixels>()
-            + 2 * options.min_padding
+            + options.min_padding.sum()
             + items.len().saturating_sub(1) as i32
                 * max(0.ppx(), options.min_spacing - options.border_collapse);
         let factor = if total_preferred == total_min {
@@ -151,19 +175,20 @@ pub(crate) fn solve_layout(
         } else {
             (total - total_min).to_i32() as f32 / (total_preferred - total_min).to_i32() as f32
         };
-        output.padding = options.min_padding
-            + PhysicalPixels::from_i32(
-                // TODO: add PhysicalPixels::mul_f32_round method
-                ((options.preferred_padding - options.min_padding).to_i32() as f32 * factor).round()
-                    as i32,
-            );
+        let scale_padding = |min: PhysicalPixels, preferred: PhysicalPixels| {
+            min + (preferred - min).mul_f32_round(factor)
+        };
+        output.padding = AxisPadding::new(
+            scale_padding(options.min_padding.start, options.preferred_padding.start),
+            scale_padding(options.min_padding.end, options.preferred_padding.end),
+        );
         output.spacing = options.min_spacing
             + PhysicalPixels::from_i32(
                 ((options.preferred_spacing - options.min_spacing).to_i32() as f32 * factor).round()
                     as i32,
             );
         let mut remaining =
-            total - output.padding * 2 - output.spacing * items.len().saturating_sub(1) as i32;
+            total - output.padding.sum() - output.spacing * items.len().saturating_sub(1) as i32;
         for item in items {
             let item_size = item.size_hints.min
                 + PhysicalPixels::from_i32(
@@ -183,7 +208,7 @@ pub(crate) fn solve_layout(
             .filter(|item| !item.size_hints.is_fixed)
             .count() as i32;
         let mut remaining =
-            total - output.padding * 2 - output.spacing * items.len().saturating_sub(1) as i32;
+            total - output.padding.sum() - output.spacing * items.len().saturating_sub(1) as i32;
         let mut extras = fair_split(num_flexible, max(0.ppx(), total - total_preferred));
         for item in items {
             let item_size = if item.size_hints.is_fixed {
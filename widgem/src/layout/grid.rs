@@ -1,5 +1,5 @@
 use {
-    super::{Alignment, SizeHintMode, SizeHints},
+    super::{Alignment, AxisPadding, SizeHintMode, SizeHints},
     crate::{
         key::Key,
         layout::{fair_split, solve_layout},
@@ -24,17 +24,17 @@ pub struct GridOptions {
 impl GridOptions {
     pub const ZERO: Self = GridOptions {
         x: GridAxisOptions {
-            min_padding: PhysicalPixels::ZERO,
+            min_padding: AxisPadding::ZERO,
             min_spacing: PhysicalPixels::ZERO,
-            preferred_padding: PhysicalPixels::ZERO,
+            preferred_padding: AxisPadding::ZERO,
             preferred_spacing: PhysicalPixels::ZERO,
             border_collapse: PhysicalPixels::ZERO,
             alignment: Alignment::Start,
         },
         y: GridAxisOptions {
-            min_padding: PhysicalPixels::ZERO,
+            min_padding: AxisPadding::ZERO,
             min_spacing: PhysicalPixels::ZERO,
-            preferred_padding: PhysicalPixels::ZERO,
+            preferred_padding: AxisPadding::ZERO,
             preferred_spacing: PhysicalPixels::ZERO,
             border_collapse: PhysicalPixels::ZERO,
             alignment: Alignment::Start,
@@ -44,9 +44,9 @@ impl GridOptions {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GridAxisOptions {
-    pub min_padding: PhysicalPixels,
+    pub min_padding: AxisPadding,
     pub min_spacing: PhysicalPixels,
-    pub preferred_padding: PhysicalPixels,
+    pub preferred_padding: AxisPadding,
     pub preferred_spacing: PhysicalPixels,
     pub border_collapse: PhysicalPixels,
     pub alignment: Alignment,
@@ -87,10 +87,32 @@ fn size_hint(
         SizeHintMode::Preferred => (options.preferred_padding, options.preferred_spacing),
     };
     max_per_column.values().sum::<PhysicalPixels>()
-        + 2 * padding
+        + padding.sum()
         + max_per_column.len().saturating_sub(1) as i32 * (spacing - options.border_collapse)
 }
 
+// Returns the size hints of the item, including the margins of the item.
+fn size_hint_x_with_margin(item: &mut dyn Widget) -> SizeHints {
+    let margin = item.base().common_style.margin;
+    with_margin(item.size_hint_x(), margin.sum_x())
+}
+
+// Returns the size hints of the item, including the margins of the item.
+// `size_x` also includes the margins.
+fn size_hint_y_with_margin(item: &mut dyn Widget, size_x: PhysicalPixels) -> SizeHints {
+    let margin = item.base().common_style.margin;
+    let size_x = max(0.ppx(), size_x - margin.sum_x());
+    with_margin(item.size_hint_y(size_x), margin.sum_y())
+}
+
+fn with_margin(hints: SizeHints, margin: PhysicalPixels) -> SizeHints {
+    SizeHints {
+        min: hints.min + margin,
+        preferred: hints.preferred + margin,
+        is_fixed: hints.is_fixed,
+    }
+}
+
 pub fn size_hint_x(items: &mut BTreeMap<Key, Box<dyn Widget>>, options: &GridOptions) -> SizeHints {
     let mut min_items = Vec::new();
     let mut preferred_items = Vec::new();
@@ -100,7 +122,7 @@ pub fn size_hint_x(items: &mut BTreeMap<Key, Box<dyn Widget>>, options: &GridOpt
             continue;
         }
 
-        let hints = item.size_hint_x();
+        let hints = size_hint_x_with_margin(item.as_mut());
         let pos_in_grid = item
             .base()
             .layout_item_options
@@ -155,7 +177,7 @@ pub fn size_hint_y(
             .pos_in_grid
             .clone()
             .unwrap();
-        let hints = item.size_hint_y(*item_size_x);
+        let hints = size_hint_y_with_margin(item.as_mut(), *item_size_x);
 
         min_items.push((pos_in_grid.clone(), hints.min));
         preferred_items.push((pos_in_grid, hints.preferred));
@@ -179,7 +201,7 @@ pub fn size_hint_y(
 }
 
 struct XLayout {
-    padding: PhysicalPixels,
+    padding: AxisPadding,
     spacing: PhysicalPixels,
     column_sizes: BTreeMap<i32, PhysicalPixels>,
     child_sizes: HashMap<Key, PhysicalPixels>,
@@ -202,7 +224,7 @@ fn x_layout(
             warn!("spanned items are not supported yet");
         }
         let pos = *pos.start();
-        let mut hints = item.size_hint_x();
+        let mut hints = size_hint_x_with_margin(item.as_mut());
         if let Some(is_fixed) = item.base().layout_item_options.x.is_fixed {
             hints.is_fixed = is_fixed;
         }
@@ -232,15 +254,15 @@ fn x_layout(
             warn!("missing column data for existing child");
             continue;
         };
+        let hints = size_hint_x_with_margin(item.as_mut());
         let child_size = if item
             .base()
             .layout_item_options
             .x
             .is_fixed
-            .unwrap_or_else(|| item.size_hint_x().is_fixed)
+            .unwrap_or(hints.is_fixed)
         {
-            let hint = item.size_hint_x().preferred;
-            min(hint, *column_size)
+            min(hints.preferred, *column_size)
         } else {
             *column_size
         };
@@ -284,7 +306,7 @@ pub fn grid_layout<W: Widget + ?Sized>(widget: &mut W, changed_size_hints: &[Wid
             continue;
         };
         let pos = *pos.start();
-        let mut hints = item.size_hint_y(*item_size_x);
+        let mut hints = size_hint_y_with_margin(item.as_mut(), *item_size_x);
         if let Some(is_fixed) = item.base().layout_item_options.y.is_fixed {
             hints.is_fixed = is_fixed;
         }
@@ -315,9 +337,10 @@ pub fn grid_layout<W: Widget + ?Sized>(widget: &mut W, changed_size_hints: &[Wid
         options.y.alignment,
     );
     for (key, item) in &mut widget.base_mut().children {
-        // if !item.common().is_self_visible {
-        //     continue;
-        // }
+        if !item.base().is_self_visible() && !item.base().is_window_root() {
+            item.set_geometry(None, changed_size_hints);
+            continue;
+        }
         let Some(pos_x) = item.base().layout_item_options.x.pos_in_grid.clone() else {
             continue;
         };
@@ -339,7 +362,7 @@ pub fn grid_layout<W: Widget + ?Sized>(widget: &mut W, changed_size_hints: &[Wid
             warn!("missing item in row_sizes");
             continue;
         };
-        let size_hint_y = item.size_hint_y(*size_x);
+        let size_hint_y = size_hint_y_with_margin(item.as_mut(), *size_x);
         let size_y = if item
             .base()
             .layout_item_options
@@ -351,10 +374,16 @@ pub fn grid_layout<W: Widget + ?Sized>(widget: &mut W, changed_size_hints: &[Wid
         } else {
             *row_size
         };
+        let margin = item.base().common_style.margin;
         item.set_geometry(
             Some(WidgetGeometry::new(
                 &geometry,
-                Rect::from_xywh(*cell_pos_x, *cell_pos_y, *size_x, size_y),
+                Rect::from_xywh(
+                    *cell_pos_x + margin.left,
+                    *cell_pos_y + margin.top,
+                    max(0.ppx(), *size_x - margin.sum_x()),
+                    max(0.ppx(), size_y - margin.sum_y()),
+                ),
             )),
             changed_size_hints,
         );
@@ -363,15 +392,15 @@ pub fn grid_layout<W: Widget + ?Sized>(widget: &mut W, changed_size_hints: &[Wid
 
 fn positions(
     sizes: &BTreeMap<i32, PhysicalPixels>,
-    padding: PhysicalPixels,
+    padding: AxisPadding,
     spacing: PhysicalPixels,
     total_available: PhysicalPixels,
     alignment: Alignment,
 ) -> BTreeMap<i32, PhysicalPixels> {
-    let mut pos = padding;
+    let mut pos = padding.start;
     let total_taken: PhysicalPixels = sizes.values().sum();
     let available_for_items =
-        total_available - 2 * padding - spacing * sizes.len().saturating_sub(1) as i32;
+        total_available - padding.sum() - spacing * sizes.len().saturating_sub(1) as i32;
     match alignment {
         Alignment::Start => {}
         Alignment::Middle => {
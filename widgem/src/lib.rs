@@ -7,8 +7,10 @@ mod callback;
 mod draw;
 pub mod event;
 mod event_loop;
+pub mod icon;
 pub mod key;
 pub mod layout;
+pub mod rich_text;
 pub mod shared_window;
 pub mod shortcut;
 pub mod style;
@@ -0,0 +1,231 @@
+use tiny_skia::Color;
+
+/// Style of a [Span] of [RichText].
+///
+/// Unset properties are inherited from the style of the widget that displays the text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub monospace: bool,
+    pub color: Option<Color>,
+    /// Font size relative to the font size of the widget.
+    pub font_scale: Option<f32>,
+    /// Target of the link. It's reported when the link is activated.
+    pub link: Option<String>,
+}
+
+impl SpanStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub fn with_monospace(mut self, monospace: bool) -> Self {
+        self.monospace = monospace;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_font_scale(mut self, scale: f32) -> Self {
+        self.font_scale = Some(scale);
+        self
+    }
+
+    pub fn with_link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+/// A fragment of [RichText] that has the same style.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Span {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+/// Text that consists of spans with different styles.
+///
+/// Rich text can be built span by span:
+///
+/// ```
+/// # use widgem::rich_text::{RichText, SpanStyle};
+/// let text = RichText::new()
+///     .with_span("Read the ", SpanStyle::new())
+///     .with_span("manual", SpanStyle::new().with_link("https://example.com"));
+/// ```
+///
+/// or parsed from a markup (see [from_markup](Self::from_markup)).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichText {
+    spans: Vec<Span>,
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_span(mut self, text: impl Into<String>, style: SpanStyle) -> Self {
+        self.push(text, style);
+        self
+    }
+
+    pub fn push(&mut self, text: impl Into<String>, style: SpanStyle) -> &mut Self {
+        let text = text.into();
+        if !text.is_empty() {
+            self.spans.push(Span { text, style });
+        }
+        self
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the text without styles.
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns the spans that have a link.
+    pub fn links(&self) -> impl Iterator<Item = &Span> {
+        self.spans.iter().filter(|span| span.style.link.is_some())
+    }
+
+    /// Parses a small subset of Markdown.
+    ///
+    /// The following markup is supported:
+    /// - `**bold**`;
+    /// - `*italic*`;
+    /// - `` `monospace` ``;
+    /// - `[text](link)`.
+    ///
+    /// A character can be escaped with a backslash (e.g. `\*`). Any other text is displayed as is.
+    pub fn from_markup(markup: &str) -> Self {
+        let mut parser = MarkupParser::default();
+        let mut chars = markup.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' if !parser.style.monospace => {
+                    if let Some((_, next)) = chars.next() {
+                        parser.current.push(next);
+                    } else {
+                        parser.current.push(c);
+                    }
+                }
+                '`' => {
+                    let monospace = !parser.style.monospace;
+                    parser.set_style(|style| style.monospace = monospace);
+                }
+                _ if parser.style.monospace => parser.current.push(c),
+                '*' => {
+                    if chars.next_if(|(_, next)| *next == '*').is_some() {
+                        let bold = !parser.style.bold;
+                        parser.set_style(|style| style.bold = bold);
+                    } else {
+                        let italic = !parser.style.italic;
+                        parser.set_style(|style| style.italic = italic);
+                    }
+                }
+                '[' => {
+                    if let Some((text, link, len)) = parse_link(&markup[index..]) {
+                        parser.set_style(|style| style.link = Some(link.into()));
+                        parser.current.push_str(text);
+                        parser.set_style(|style| style.link = None);
+                        // Skip the rest of the link markup.
+                        while chars.next_if(|(next, _)| *next < index + len).is_some() {}
+                    } else {
+                        parser.current.push(c);
+                    }
+                }
+                _ => parser.current.push(c),
+            }
+        }
+        parser.flush();
+        parser.text
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        RichText::new().with_span(text, SpanStyle::new())
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        RichText::new().with_span(text, SpanStyle::new())
+    }
+}
+
+#[derive(Default)]
+struct MarkupParser {
+    text: RichText,
+    style: SpanStyle,
+    current: String,
+}
+
+impl MarkupParser {
+    fn flush(&mut self) {
+        let text = std::mem::take(&mut self.current);
+        self.text.push(text, self.style.clone());
+    }
+
+    fn set_style(&mut self, f: impl FnOnce(&mut SpanStyle)) {
+        self.flush();
+        f(&mut self.style);
+    }
+}
+
+// Parses `[text](link)` at the start of `markup`.
+// Returns the text, the link and the length of the markup.
+fn parse_link(markup: &str) -> Option<(&str, &str, usize)> {
+    let rest = markup.strip_prefix('[')?;
+    let text_end = rest.find("](")?;
+    let text = &rest[..text_end];
+    let after_text = &rest[text_end + 2..];
+    let link_end = after_text.find(')')?;
+    let link = &after_text[..link_end];
+    if text.is_empty() || text.contains('\n') || link.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((text, link, 1 + text_end + 2 + link_end + 1))
+}
+
+#[test]
+fn parse_markup() {
+    let text = RichText::from_markup(
+        r"plain **bold *both*** `a*b` [link](https://example.com) \* [no link]",
+    );
+    let expected = RichText::new()
+        .with_span("plain ", SpanStyle::new())
+        .with_span("bold ", SpanStyle::new().with_bold(true))
+        .with_span("both", SpanStyle::new().with_bold(true).with_italic(true))
+        .with_span(" ", SpanStyle::new())
+        .with_span("a*b", SpanStyle::new().with_monospace(true))
+        .with_span(" ", SpanStyle::new())
+        .with_span("link", SpanStyle::new().with_link("https://example.com"))
+        .with_span(" * [no link]", SpanStyle::new());
+    assert_eq!(text, expected);
+    assert_eq!(text.plain_text(), "plain bold both a*b link * [no link]");
+    assert_eq!(text.links().count(), 1);
+}
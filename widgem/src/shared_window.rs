@@ -1,10 +1,12 @@
 use {
     crate::{
         accessible::AccessibleNodes,
+        callback::Callback,
         draw::DrawEvent,
-        event::FocusReason,
+        event::{FocusReason, KeyboardInputEvent},
         event_loop::with_active_event_loop,
         key::Key,
+        shortcut::{Shortcut, ShortcutId},
         system::with_system,
         types::{PhysicalPixels, Point, Rect, Size},
         widgets::{RawWidgetId, Widget, WidgetAddress, WidgetExt},
@@ -104,10 +106,15 @@ pub struct SharedWindowInner {
 
     pub focused_widget: Option<(Vec<(Key, RawWidgetId)>, RawWidgetId)>,
     pub mouse_grabber_widget: Option<RawWidgetId>,
+    // Widget under the mouse pointer whose tooltip is pending or displayed.
+    pub tooltip_widget: Option<RawWidgetId>,
     pub num_clicks: u32,
     pub last_click_button: Option<MouseButton>,
     pub last_click_instant: Option<Instant>,
     pub is_delete_widget_on_close_enabled: bool,
+    pub shortcuts: Vec<Shortcut>,
+    // Open modal windows that block input to this window.
+    pub modal_blockers: Vec<WindowId>,
 
     pub attributes: Attributes,
 }
@@ -190,10 +197,13 @@ impl SharedWindow {
             focusable_widgets_changed: false,
             focused_widget: None,
             mouse_grabber_widget: None,
+            tooltip_widget: None,
             num_clicks: 0,
             last_click_button: None,
             last_click_instant: None,
             is_delete_widget_on_close_enabled: true,
+            shortcuts: Vec::new(),
+            modal_blockers: Vec::new(),
             // This is updated in `init_window`
             min_inner_size: Size::default(),
             // This is updated in `init_window`
@@ -313,7 +323,7 @@ impl SharedWindow {
         with_system(|system| {
             system.windows_by_winit_id.insert(winit_id, info);
         });
-        self.set_visible(true);
+        self.set_visible(self.is_visible());
         // For some reason it's necessary to request redraw again after initializing accesskit on Windows.
         self.clear_pending_redraw();
         self.request_redraw();
@@ -359,10 +369,29 @@ impl SharedWindow {
         self.0.borrow_mut().mouse_grabber_widget = id;
     }
 
+    pub(crate) fn tooltip_widget(&self) -> Option<RawWidgetId> {
+        self.0.borrow().tooltip_widget
+    }
+
+    pub(crate) fn set_tooltip_widget(&self, id: Option<RawWidgetId>) {
+        self.0.borrow_mut().tooltip_widget = id;
+    }
+
     pub fn focused_widget(&self) -> Option<RawWidgetId> {
         self.0.borrow().focused_widget.as_ref().map(|x| x.1)
     }
 
+    /// Returns true if the focused widget is the specified widget or one of its descendants.
+    pub fn is_focus_within(&self, id: RawWidgetId) -> bool {
+        self.0
+            .borrow()
+            .focused_widget
+            .as_ref()
+            .is_some_and(|(path, focused_id)| {
+                *focused_id == id || path.iter().any(|(_key, path_id)| *path_id == id)
+            })
+    }
+
     pub fn cursor_position(&self) -> Option<Point> {
         self.0.borrow().cursor_position
     }
@@ -383,10 +412,57 @@ impl SharedWindow {
         self.0.borrow().is_delete_widget_on_close_enabled
     }
 
+    pub fn is_visible(&self) -> bool {
+        self.0.borrow().attributes.visible
+    }
+
     pub fn set_visible(&self, visible: bool) {
-        if let Some(w) = self.0.borrow().winit_window.as_ref() {
+        let this = &mut *self.0.borrow_mut();
+        if let Some(w) = this.winit_window.as_ref() {
             w.set_visible(visible);
         }
+        this.attributes.visible = visible;
+    }
+
+    /// Brings the window to the front and sets input focus to it.
+    pub fn focus_window(&self) {
+        if let Some(w) = self.0.borrow().winit_window.as_ref() {
+            w.focus_window();
+        }
+    }
+
+    /// Position of the top left corner of the window's client area on the desktop.
+    ///
+    /// Returns `None` if the window hasn't been created yet or if the platform
+    /// doesn't support querying window position (e.g. Wayland).
+    pub fn inner_position(&self) -> Option<Point> {
+        self.0
+            .borrow()
+            .winit_window
+            .as_ref()?
+            .inner_position()
+            .ok()
+            .map(Into::into)
+    }
+
+    pub fn set_outer_position(&self, position: Point) {
+        let this = &mut *self.0.borrow_mut();
+        if Some(position) == this.attributes.position {
+            return;
+        }
+        if let Some(w) = this.winit_window.as_ref() {
+            w.set_outer_position(PhysicalPosition::from(position));
+        }
+        this.attributes.position = Some(position);
+    }
+
+    /// Desktop area of the monitor that currently contains the window.
+    pub fn monitor_rect(&self) -> Option<Rect> {
+        let monitor = self.0.borrow().winit_window.as_ref()?.current_monitor()?;
+        Some(Rect::from_pos_size(
+            monitor.position().into(),
+            monitor.size().into(),
+        ))
     }
 
     pub(crate) fn set_cursor(&self, icon: CursorIcon) {
@@ -687,6 +763,25 @@ impl SharedWindow {
         }
     }
 
+    pub(crate) fn add_shortcut(&self, shortcut: Shortcut) {
+        self.0.borrow_mut().shortcuts.push(shortcut);
+    }
+
+    pub(crate) fn remove_shortcut(&self, id: ShortcutId) {
+        self.0.borrow_mut().shortcuts.retain(|s| s.id != id);
+    }
+
+    /// Returns callbacks of the window shortcuts triggered by `event`.
+    pub(crate) fn triggered_shortcuts(&self, event: &KeyboardInputEvent) -> Vec<Callback<()>> {
+        self.0
+            .borrow()
+            .shortcuts
+            .iter()
+            .filter(|shortcut| shortcut.key_combinations.matches(event))
+            .map(|shortcut| shortcut.callback.clone())
+            .collect()
+    }
+
     pub(crate) fn move_keyboard_focus(
         &self,
         direction: i32,
@@ -877,6 +972,15 @@ impl SharedWindow {
         this.attributes.window_level = value;
     }
 
+    /// Sets whether the window receives focus when it's shown for the first time.
+    pub fn set_active(&self, value: bool) {
+        let this = &mut *self.0.borrow_mut();
+        if this.winit_window.is_some() {
+            warn!("changing active state after window creation is unsupported");
+        }
+        this.attributes.active = Some(value);
+    }
+
     #[allow(unused_variables)]
     pub fn set_x11_window_type(&self, value: Vec<X11WindowType>) {
         #[cfg(all(unix, not(target_vendor = "apple")))]
@@ -907,6 +1011,27 @@ impl SharedWindow {
         }
     }
 
+    /// Blocks input to this window until [remove_modal_blocker](Self::remove_modal_blocker)
+    /// is called with the same id.
+    pub(crate) fn add_modal_blocker(&self, id: WindowId) {
+        let this = &mut *self.0.borrow_mut();
+        if !this.modal_blockers.contains(&id) {
+            this.modal_blockers.push(id);
+        }
+    }
+
+    pub(crate) fn remove_modal_blocker(&self, id: WindowId) {
+        self.0
+            .borrow_mut()
+            .modal_blockers
+            .retain(|blocker| *blocker != id);
+    }
+
+    /// Returns the most recently opened modal window that blocks input to this window.
+    pub fn modal_blocker(&self) -> Option<WindowId> {
+        self.0.borrow().modal_blockers.last().copied()
+    }
+
     pub fn deregister(&self) {
         let this = self.0.borrow();
         let id = this.id;
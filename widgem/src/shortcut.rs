@@ -11,6 +11,7 @@ use {
     bitflags::bitflags,
     derive_more::From,
     once_cell::sync::OnceCell,
+    std::fmt::{self, Display, Formatter},
     winit::keyboard::{KeyCode, ModifiersState, NamedKey},
 };
 
@@ -46,6 +47,18 @@ impl KeyCombinations {
     }
 }
 
+impl Display for KeyCombinations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, combination) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{combination}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeyCombination {
     pub key: ShortcutKey,
@@ -105,12 +118,59 @@ impl KeyCombination {
     }
 }
 
+impl Display for KeyCombination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        #[cfg(not(target_os = "macos"))]
+        let names = [
+            (Modifiers::CTRL_OR_MAC_CMD, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::META_OR_MAC_CTRL, "Meta"),
+        ];
+        #[cfg(target_os = "macos")]
+        let names = [
+            (Modifiers::META_OR_MAC_CTRL, "Ctrl"),
+            (Modifiers::ALT, "Option"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::CTRL_OR_MAC_CMD, "Cmd"),
+        ];
+        for (modifier, name) in names {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, From)]
 pub enum ShortcutKey {
     Logical(NamedKey),
     Physical(KeyCode),
 }
 
+impl Display for ShortcutKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutKey::Logical(key) => match key {
+                NamedKey::ArrowDown => write!(f, "Down"),
+                NamedKey::ArrowLeft => write!(f, "Left"),
+                NamedKey::ArrowRight => write!(f, "Right"),
+                NamedKey::ArrowUp => write!(f, "Up"),
+                _ => write!(f, "{key:?}"),
+            },
+            ShortcutKey::Physical(code) => {
+                let name = format!("{code:?}");
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name);
+                write!(f, "{name}")
+            }
+        }
+    }
+}
+
 bitflags! {
     #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct Modifiers: u8 {
@@ -191,6 +251,20 @@ pub struct StandardShortcuts {
     pub delete_start_of_word: KeyCombinations,
     pub delete_end_of_word: KeyCombinations,
     pub insert_paragraph_separator: KeyCombinations,
+    pub move_to_previous_line: KeyCombinations,
+    pub move_to_next_line: KeyCombinations,
+    pub select_previous_line: KeyCombinations,
+    pub select_next_line: KeyCombinations,
+    pub move_to_previous_page: KeyCombinations,
+    pub move_to_next_page: KeyCombinations,
+    pub select_previous_page: KeyCombinations,
+    pub select_next_page: KeyCombinations,
+    pub move_to_start_of_document: KeyCombinations,
+    pub move_to_end_of_document: KeyCombinations,
+    pub select_start_of_document: KeyCombinations,
+    pub select_end_of_document: KeyCombinations,
+    pub next_child: KeyCombinations,
+    pub previous_child: KeyCombinations,
 }
 
 impl StandardShortcuts {
@@ -301,6 +375,52 @@ impl StandardShortcuts {
             delete_end_of_word: s("CtrlOrMacCmd+Delete"),
 
             insert_paragraph_separator: s("Enter"),
+
+            move_to_previous_line: s("Up"),
+
+            move_to_next_line: s("Down"),
+
+            select_previous_line: s("Shift+Up"),
+
+            select_next_line: s("Shift+Down"),
+
+            move_to_previous_page: s("PageUp"),
+
+            move_to_next_page: s("PageDown"),
+
+            select_previous_page: s("Shift+PageUp"),
+
+            select_next_page: s("Shift+PageDown"),
+
+            #[cfg(not(target_os = "macos"))]
+            move_to_start_of_document: s("Ctrl+Home"),
+            #[cfg(target_os = "macos")]
+            move_to_start_of_document: s("CtrlOrMacCmd+Up"),
+
+            #[cfg(not(target_os = "macos"))]
+            move_to_end_of_document: s("Ctrl+End"),
+            #[cfg(target_os = "macos")]
+            move_to_end_of_document: s("CtrlOrMacCmd+Down"),
+
+            #[cfg(not(target_os = "macos"))]
+            select_start_of_document: s("Ctrl+Shift+Home"),
+            #[cfg(target_os = "macos")]
+            select_start_of_document: s("CtrlOrMacCmd+Shift+Up"),
+
+            #[cfg(not(target_os = "macos"))]
+            select_end_of_document: s("Ctrl+Shift+End"),
+            #[cfg(target_os = "macos")]
+            select_end_of_document: s("CtrlOrMacCmd+Shift+Down"),
+
+            #[cfg(not(target_os = "macos"))]
+            next_child: s("Ctrl+Tab; Ctrl+PageDown"),
+            #[cfg(target_os = "macos")]
+            next_child: s("MetaOrMacCtrl+Tab"),
+
+            #[cfg(not(target_os = "macos"))]
+            previous_child: s("Ctrl+Shift+Tab; Ctrl+PageUp"),
+            #[cfg(target_os = "macos")]
+            previous_child: s("MetaOrMacCtrl+Shift+Tab"),
         }
     }
 }
@@ -357,6 +477,64 @@ impl Shortcut {
     }
 }
 
+/// Text with an optional mnemonic (access key), parsed from the ampersand syntax.
+///
+/// An ampersand marks the next character as the mnemonic (e.g. `"&Save"`), and `&&`
+/// is displayed as a single ampersand. An ampersand followed by whitespace or at the end
+/// of the text is displayed as is. Only the first marked character is used as the mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MnemonicText {
+    /// The text without mnemonic markers.
+    pub text: String,
+    /// Byte offset of the mnemonic character in `text`.
+    pub mnemonic: Option<usize>,
+}
+
+impl MnemonicText {
+    pub fn parse(text: &str) -> Self {
+        let mut r = Self::default();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                r.text.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('&') => r.text.push('&'),
+                Some(next) if next.is_whitespace() => {
+                    r.text.push('&');
+                    r.text.push(next);
+                }
+                Some(next) => {
+                    if r.mnemonic.is_none() {
+                        r.mnemonic = Some(r.text.len());
+                    }
+                    r.text.push(next);
+                }
+                None => r.text.push('&'),
+            }
+        }
+        r
+    }
+
+    pub fn mnemonic_char(&self) -> Option<char> {
+        self.text[self.mnemonic?..].chars().next()
+    }
+
+    /// Returns the key combination that activates the mnemonic: Alt and the mnemonic key.
+    ///
+    /// Only ASCII letters and digits are supported. The key is matched by its physical position
+    /// in the US layout.
+    pub fn key_combinations(&self) -> Option<KeyCombinations> {
+        let c = self.mnemonic_char()?;
+        if !c.is_ascii_alphanumeric() {
+            return None;
+        }
+        let key = parse_keycode(&c.to_ascii_lowercase().to_string())?;
+        Some(KeyCombinations::new(Modifiers::ALT, key))
+    }
+}
+
 #[test]
 fn test_standard_shortcuts() {
     let shortcuts = StandardShortcuts::new();
@@ -425,3 +603,33 @@ fn test_standard_shortcuts() {
         );
     }
 }
+
+#[test]
+fn test_mnemonic_text() {
+    let text = MnemonicText::parse("Save && &Quit");
+    assert_eq!(text.text, "Save & Quit");
+    assert_eq!(text.mnemonic, Some(7));
+    assert_eq!(text.mnemonic_char(), Some('Q'));
+    assert_eq!(
+        text.key_combinations(),
+        Some(KeyCombinations::new(Modifiers::ALT, KeyCode::KeyQ))
+    );
+
+    let text = MnemonicText::parse("&Ä &1 &");
+    assert_eq!(text.text, "Ä 1 &");
+    assert_eq!(text.mnemonic_char(), Some('Ä'));
+    assert_eq!(text.key_combinations(), None);
+
+    let text = MnemonicText::parse("A & B");
+    assert_eq!(text.text, "A & B");
+    assert_eq!(text.mnemonic, None);
+
+    let text = MnemonicText::parse("R&D");
+    assert_eq!(text.text, "RD");
+    assert_eq!(text.mnemonic_char(), Some('D'));
+
+    let text = MnemonicText::parse("R&&D");
+    assert_eq!(text.text, "R&D");
+    assert_eq!(text.mnemonic, None);
+    assert_eq!(text.key_combinations(), None);
+}
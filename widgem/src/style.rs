@@ -1,18 +1,24 @@
 use {
     crate::{
+        icon::{render_svg, scale_pixmap},
         style::{
             common::ComputedElementStyle,
             css::{
-                convert_background_color, convert_font, convert_main_color, is_root, replace_vars,
-                Element,
+                convert_background_color, convert_font, convert_main_color, depends_on_position,
+                is_root, is_supported_selector, preprocess_css, replace_vars, Element,
             },
         },
         system::with_system,
-        types::{LogicalPixels, Point},
+        types::{LogicalPixels, PhysicalPixels, Sides},
     },
     anyhow::{anyhow, bail, Context, Result},
+    cosmic_text::{fontdb, Family, FamilyOwned},
     lightningcss::{
-        properties::Property, rules::CssRule, selector::Selector, stylesheet::StyleSheet,
+        properties::Property,
+        rules::CssRule,
+        selector::Selector,
+        stylesheet::{ParserOptions, StyleSheet},
+        traits::ToCss,
     },
     log::warn,
     ordered_float::OrderedFloat,
@@ -33,6 +39,7 @@ pub mod common;
 pub mod css;
 pub mod defaults;
 pub mod text_input;
+pub(crate) mod watcher;
 
 pub trait ElementState: Eq + Hash + Sized {
     fn all() -> Vec<Self>;
@@ -53,33 +60,127 @@ impl RelativeOffset {
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Padding {
-    pub x: LogicalPixels,
-    pub y: LogicalPixels,
+    pub left: LogicalPixels,
+    pub top: LogicalPixels,
+    pub right: LogicalPixels,
+    pub bottom: LogicalPixels,
 }
 
 impl Padding {
-    pub fn new(x: LogicalPixels, y: LogicalPixels) -> Self {
-        Self { x, y }
+    pub fn new(
+        left: LogicalPixels,
+        top: LogicalPixels,
+        right: LogicalPixels,
+        bottom: LogicalPixels,
+    ) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
     }
-    pub fn to_physical(self, scale: f32) -> Point {
-        Point::new(self.x.to_physical(scale), self.y.to_physical(scale))
+
+    /// Creates a padding with the same value for the left and right sides
+    /// and the same value for the top and bottom sides.
+    pub fn symmetric(x: LogicalPixels, y: LogicalPixels) -> Self {
+        Self::new(x, y, x, y)
+    }
+
+    pub fn to_physical(self, scale: f32) -> Sides<PhysicalPixels> {
+        Sides::new(
+            self.left.to_physical(scale),
+            self.top.to_physical(scale),
+            self.right.to_physical(scale),
+            self.bottom.to_physical(scale),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FontFamily {
+    Name(String),
+    Serif,
+    SansSerif,
+    Cursive,
+    Fantasy,
+    Monospace,
+}
+
+impl FontFamily {
+    fn to_family_owned(&self) -> FamilyOwned {
+        match self {
+            FontFamily::Name(name) => FamilyOwned::new(Family::Name(name)),
+            FontFamily::Serif => FamilyOwned::Serif,
+            FontFamily::SansSerif => FamilyOwned::SansSerif,
+            FontFamily::Cursive => FamilyOwned::Cursive,
+            FontFamily::Fantasy => FamilyOwned::Fantasy,
+            FontFamily::Monospace => FamilyOwned::Monospace,
+        }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FontSlant {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct TextDecoration {
+    pub underline: bool,
+    pub line_through: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FontStyle {
     pub font_size: LogicalPixels,
     pub line_height: LogicalPixels,
-    // TODO: font family, attributes, etc.
+    /// Font families in the order of preference. The default sans-serif font is used
+    /// if none of them is available.
+    pub font_family: Vec<FontFamily>,
+    /// Font weight from 1 to 1000 (400 is normal, 700 is bold).
+    pub font_weight: u16,
+    pub font_slant: FontSlant,
+    /// Additional space between characters.
+    pub letter_spacing: LogicalPixels,
+    pub text_decoration: TextDecoration,
 }
 
 impl FontStyle {
+    pub const NORMAL_WEIGHT: u16 = 400;
+    pub const BOLD_WEIGHT: u16 = 700;
+
     pub fn to_metrics(&self, scale: f32) -> cosmic_text::Metrics {
         cosmic_text::Metrics {
             font_size: self.font_size.to_physical(scale).to_i32() as f32,
             line_height: self.line_height.to_physical(scale).to_i32() as f32,
         }
     }
+
+    /// Returns the first family from the fallback list that is present in `db`.
+    pub fn resolve_family(&self, db: &fontdb::Database) -> FamilyOwned {
+        self.font_family
+            .iter()
+            .find(|family| match family {
+                FontFamily::Name(name) => db
+                    .faces()
+                    .any(|face| face.families.iter().any(|(face_name, _)| face_name == name)),
+                _ => true,
+            })
+            .map_or(FamilyOwned::SansSerif, FontFamily::to_family_owned)
+    }
+
+    /// Letter spacing relative to the font size.
+    pub fn letter_spacing_em(&self) -> f32 {
+        if self.font_size.to_f32() == 0.0 {
+            0.0
+        } else {
+            self.letter_spacing.to_f32() / self.font_size.to_f32()
+        }
+    }
 }
 
 // TODO: not pub
@@ -97,13 +198,22 @@ pub enum StyleSource {
 pub struct Style {
     pub css: StyleSheet<'static, 'static>,
     pub source: StyleSource,
+    /// Whether any selectors depend on the position of the element among its siblings
+    /// (e.g. `:first-child`). Positions are only tracked if this is `true`.
+    pub uses_child_positions: bool,
 
     cache: HashMap<(Element, OrderedFloat<f32>, TypeId), Box<dyn Any>>,
 }
 
-fn load_css(css: &str) -> Result<StyleSheet<'static, 'static>> {
-    let mut style = StyleSheet::parse(css, Default::default())
-        .map_err(|e| anyhow!("failed to parse css: {e}"))?;
+// `filename` is used in error messages.
+fn load_css(css: &str, filename: &str) -> Result<StyleSheet<'static, 'static>> {
+    let css = preprocess_css(css);
+    let options = ParserOptions {
+        filename: filename.into(),
+        ..Default::default()
+    };
+    let mut style =
+        StyleSheet::parse(&css, options).map_err(|e| anyhow!("failed to parse css: {e}"))?;
     replace_vars(&mut style);
     let code = style.to_css(Default::default())?.code;
     let style = StyleSheet::parse(&code, Default::default())
@@ -112,7 +222,39 @@ fn load_css(css: &str) -> Result<StyleSheet<'static, 'static>> {
     let serialized = serde_value::to_value(&style)?;
 
     // println!("{style:#?}");
-    Ok(serialized.deserialize_into()?)
+    let mut style = serialized.deserialize_into()?;
+    remove_unsupported_selectors(&mut style);
+    Ok(style)
+}
+
+// Removes selectors that can't be matched, and rules that have no selectors left.
+fn remove_unsupported_selectors(css: &mut StyleSheet) {
+    css.rules.0.retain_mut(|rule| {
+        let CssRule::Style(rule) = rule else {
+            return true;
+        };
+        rule.selectors.0.retain(|selector| {
+            let supported = is_supported_selector(selector);
+            if !supported {
+                let text = selector
+                    .to_css_string(Default::default())
+                    .unwrap_or_default();
+                warn!("unsupported selector, ignoring: {text}");
+            }
+            supported
+        });
+        !rule.selectors.0.is_empty()
+    });
+}
+
+fn uses_child_positions(css: &StyleSheet) -> bool {
+    css.rules.0.iter().any(|rule| {
+        if let CssRule::Style(rule) = rule {
+            rule.selectors.0.iter().any(depends_on_position)
+        } else {
+            false
+        }
+    })
 }
 
 impl Style {
@@ -120,8 +262,10 @@ impl Style {
         css: &str,
         files: impl IntoIterator<Item = (&'static str, &'static [u8])>,
     ) -> Result<Self> {
+        let css = load_css(css, "<bundled>")?;
         Ok(Self {
-            css: load_css(css)?,
+            uses_child_positions: uses_child_positions(&css),
+            css,
             source: StyleSource::Bundle {
                 files: files.into_iter().collect(),
             },
@@ -132,8 +276,10 @@ impl Style {
     pub fn load_from_file(css_path: &Path) -> Result<Style> {
         let css = fs_err::read_to_string(css_path)?;
 
+        let css = load_css(&css, &css_path.to_string_lossy())?;
         Ok(Self {
-            css: load_css(&css)?,
+            uses_child_positions: uses_child_positions(&css),
+            css,
             source: StyleSource::File {
                 parent_dir: css_path
                     .parent()
@@ -155,15 +301,20 @@ impl Style {
         let mut results = Vec::new();
         for rule in &self.css.rules.0 {
             if let CssRule::Style(rule) = rule {
-                for selector in &rule.selectors.0 {
-                    if check_selector(selector) {
-                        let specificity = selector.specificity();
-                        results.extend(
-                            rule.declarations
-                                .iter()
-                                .map(|(dec, important)| (important, specificity, dec)),
-                        );
-                    }
+                // If several selectors of the rule match, the most specific one is used.
+                let specificity = rule
+                    .selectors
+                    .0
+                    .iter()
+                    .filter(|selector| check_selector(selector))
+                    .map(|selector| selector.specificity())
+                    .max();
+                if let Some(specificity) = specificity {
+                    results.extend(
+                        rule.declarations
+                            .iter()
+                            .map(|(dec, important)| (important, specificity, dec)),
+                    );
                 }
             }
         }
@@ -206,30 +357,31 @@ impl Style {
         }
     }
 
+    pub fn has_resource(&self, path: &str) -> bool {
+        match &self.source {
+            StyleSource::File { parent_dir } => parent_dir.join(path).is_file(),
+            StyleSource::Bundle { files } => files.contains_key(path),
+        }
+    }
+
+    /// Loads an SVG or PNG image and renders it at the specified scale.
     pub fn load_pixmap(&self, path: &str, scale: f32) -> Result<Rc<Pixmap>> {
         // TODO: cache pixmaps
-        // TODO: support png
-        if !path.ends_with(".svg") {
-            bail!("only svg is currently supported");
-        }
         let data = self.load_resource(path)?;
-
-        let tree = usvg::Tree::from_data(&data, &Default::default())?;
-
-        let pixmap_size_x = (tree.size().width() * scale).ceil() as u32;
-        let pixmap_size_y = (tree.size().height() * scale).ceil() as u32;
-        let mut pixmap = tiny_skia::Pixmap::new(pixmap_size_x, pixmap_size_y).unwrap();
-        resvg::render(
-            &tree,
-            tiny_skia::Transform::from_scale(scale, scale),
-            &mut pixmap.as_mut(),
-        );
+        let pixmap = if path.ends_with(".svg") {
+            let tree = usvg::Tree::from_data(&data, &Default::default())?;
+            render_svg(&tree, scale)
+        } else if path.ends_with(".png") {
+            scale_pixmap(&Pixmap::decode_png(&data)?, scale)
+        } else {
+            bail!("only svg and png are currently supported");
+        };
         Ok(Rc::new(pixmap))
     }
 
     pub fn get<T: ComputedElementStyle>(&mut self, element: &Element, scale: f32) -> Rc<T> {
         let type_id = TypeId::of::<T>();
-        let key = (element.clone(), OrderedFloat(scale), type_id);
+        let key = (element.cache_key(), OrderedFloat(scale), type_id);
         if let Some(data) = self.cache.get(&key) {
             return data
                 .downcast_ref::<Rc<T>>()
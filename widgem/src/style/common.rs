@@ -1,13 +1,16 @@
 use {
     super::{
         css::{
-            convert_background, convert_border, convert_font, convert_main_color, convert_padding,
-            convert_zoom, is_root, Element, PseudoClass,
+            convert_background, convert_border, convert_font, convert_main_color, convert_margin,
+            convert_padding, convert_zoom, is_root, Element, PseudoClass,
         },
         RelativeOffset, Style,
     },
     crate::{
-        layout::grid::{GridAxisOptions, GridOptions},
+        layout::{
+            grid::{GridAxisOptions, GridOptions},
+            AxisPadding,
+        },
         style::{
             css::{
                 convert_spacing, get_border_collapse, get_text_alignment, get_vertical_alignment,
@@ -15,7 +18,7 @@ use {
             },
             defaults,
         },
-        types::{LpxSuffix, PhysicalPixels, Point, PpxSuffix},
+        types::{Corners, LpxSuffix, PhysicalPixels, PpxSuffix, Sides},
     },
     log::warn,
     std::any::Any,
@@ -24,16 +27,16 @@ use {
 
 #[derive(Debug, Clone)]
 pub struct ComputedBorderStyle {
-    pub width: PhysicalPixels,
-    pub color: Color,
-    pub radius: PhysicalPixels,
+    pub width: Sides<PhysicalPixels>,
+    pub color: Sides<Color>,
+    pub radius: Corners<PhysicalPixels>,
 }
 
 impl Default for ComputedBorderStyle {
     fn default() -> Self {
         Self {
-            width: Default::default(),
-            color: Color::TRANSPARENT,
+            width: Sides::ZERO,
+            color: Sides::all(Color::TRANSPARENT),
             radius: Default::default(),
         }
     }
@@ -42,6 +45,8 @@ impl Default for ComputedBorderStyle {
 #[derive(Debug)]
 pub struct CommonComputedStyle {
     pub border: ComputedBorderStyle,
+    /// Space around the widget that is reserved by the grid layout of the parent widget.
+    pub margin: Sides<PhysicalPixels>,
     pub background: Option<ComputedBackground>,
     pub text_color: tiny_skia::Color,
     pub font_metrics: cosmic_text::Metrics,
@@ -66,6 +71,8 @@ impl ComputedElementStyle for CommonComputedStyle {
         let min_padding = convert_padding(&min_rules_with_root, scale, font.font_size);
         let preferred_padding = convert_padding(&rules_with_root, scale, font.font_size);
 
+        let margin = convert_margin(&rules, scale, font.font_size);
+
         let min_spacing = convert_spacing(&min_rules_with_root, scale, font.font_size);
         let preferred_spacing = convert_spacing(&rules_with_root, scale, font.font_size);
 
@@ -82,23 +89,34 @@ impl ComputedElementStyle for CommonComputedStyle {
             0.ppx()
         };
 
-        let min_padding_with_border = min_padding + Point::new(border.width, border.width);
-        let preferred_padding_with_border =
-            preferred_padding + Point::new(border.width, border.width);
+        let min_padding_with_border = min_padding + border.width;
+        let preferred_padding_with_border = preferred_padding + border.width;
 
         let grid = GridOptions {
             x: GridAxisOptions {
-                min_padding: min_padding_with_border.x(),
+                min_padding: AxisPadding::new(
+                    min_padding_with_border.left,
+                    min_padding_with_border.right,
+                ),
                 min_spacing: min_spacing.x(),
-                preferred_padding: preferred_padding_with_border.x(),
+                preferred_padding: AxisPadding::new(
+                    preferred_padding_with_border.left,
+                    preferred_padding_with_border.right,
+                ),
                 preferred_spacing: preferred_spacing.x(),
                 border_collapse,
                 alignment: get_text_alignment(&properties_with_root),
             },
             y: GridAxisOptions {
-                min_padding: min_padding_with_border.y(),
+                min_padding: AxisPadding::new(
+                    min_padding_with_border.top,
+                    min_padding_with_border.bottom,
+                ),
                 min_spacing: min_spacing.y(),
-                preferred_padding: preferred_padding_with_border.y(),
+                preferred_padding: AxisPadding::new(
+                    preferred_padding_with_border.top,
+                    preferred_padding_with_border.bottom,
+                ),
                 preferred_spacing: preferred_spacing.y(),
                 border_collapse,
                 alignment: get_vertical_alignment(&properties_with_root),
@@ -108,6 +126,7 @@ impl ComputedElementStyle for CommonComputedStyle {
         Self {
             font_metrics: font.to_metrics(scale),
             border,
+            margin,
             background,
             text_color,
             grid,
@@ -4,13 +4,13 @@ use {
     super::{
         common::{ComputedBackground, ComputedBorderStyle, ComputedLinearGradient},
         defaults::DEFAULT_LINE_HEIGHT,
-        FontStyle, RelativeOffset,
+        FontFamily, FontSlant, FontStyle, RelativeOffset, TextDecoration,
     },
     crate::{
         layout::Alignment,
         style::defaults,
         system::ReportError,
-        types::{LogicalPixels, LpxSuffix, PhysicalPixels, Point},
+        types::{Corners, LogicalPixels, LpxSuffix, PhysicalPixels, Point, PpxSuffix, Sides},
     },
     anyhow::{bail, Context, Result},
     itertools::Itertools,
@@ -19,13 +19,19 @@ use {
             align::GapValue,
             border::{BorderSideWidth, LineStyle},
             custom::{CustomPropertyName, Token, TokenOrValue},
-            font::{FontSize, LineHeight, VerticalAlign, VerticalAlignKeyword},
+            font::{
+                AbsoluteFontWeight, FamilyName, FontFamily as CssFontFamily, FontSize,
+                FontStyle as CssFontStyle, FontWeight, GenericFontFamily, LineHeight,
+                VerticalAlign, VerticalAlignKeyword,
+            },
             size::Size,
-            text::TextAlign,
+            text::{Spacing, TextAlign, TextDecorationLine},
             Property,
         },
         rules::CssRule,
-        selector::{self, Component, PseudoElement, Selector},
+        selector::{
+            self, Combinator, Component, PseudoElement, Selector, WebKitScrollbarPseudoClass,
+        },
         stylesheet::StyleSheet,
         values::{
             color::CssColor,
@@ -34,11 +40,18 @@ use {
             length::{Length, LengthPercentage, LengthPercentageOrAuto, LengthValue},
             percentage::DimensionPercentage,
             position::{HorizontalPositionKeyword, VerticalPositionKeyword},
+            size::Size2D,
             string::CowArcStr,
         },
     },
     log::warn,
-    std::{borrow::Cow, collections::HashMap},
+    parcel_selectors::parser::{NthSelectorData, NthType},
+    std::{
+        borrow::Cow,
+        collections::HashMap,
+        hash::{DefaultHasher, Hash, Hasher},
+        rc::Rc,
+    },
     tiny_skia::{Color, GradientStop, SpreadMode},
 };
 
@@ -109,21 +122,135 @@ fn convert_line_height(value: &LineHeight, font_size: LogicalPixels) -> Result<L
     }
 }
 
+fn convert_family_name(name: &FamilyName) -> Result<String> {
+    // `FamilyName` doesn't expose its value, but it's serialized as a plain string.
+    match serde_value::to_value(name)? {
+        serde_value::Value::String(name) => Ok(name),
+        value => bail!("unexpected family name: {value:?}"),
+    }
+}
+
+fn convert_font_family(families: &[CssFontFamily]) -> Vec<FontFamily> {
+    families
+        .iter()
+        .filter_map(|family| match family {
+            CssFontFamily::FamilyName(name) => convert_family_name(name)
+                .context("invalid font family")
+                .or_report_err()
+                .map(FontFamily::Name),
+            CssFontFamily::Generic(generic) => match generic {
+                GenericFontFamily::Serif | GenericFontFamily::UISerif => Some(FontFamily::Serif),
+                GenericFontFamily::SansSerif
+                | GenericFontFamily::SystemUI
+                | GenericFontFamily::UISansSerif => Some(FontFamily::SansSerif),
+                GenericFontFamily::Cursive => Some(FontFamily::Cursive),
+                GenericFontFamily::Fantasy => Some(FontFamily::Fantasy),
+                GenericFontFamily::Monospace | GenericFontFamily::UIMonospace => {
+                    Some(FontFamily::Monospace)
+                }
+                _ => {
+                    warn!("unsupported font family: {generic:?}");
+                    None
+                }
+            },
+        })
+        .collect()
+}
+
+// `inherited` is the weight that `bolder` and `lighter` are relative to.
+fn convert_font_weight(value: &FontWeight, inherited: u16) -> Result<u16> {
+    // https://www.w3.org/TR/css-fonts-4/#relative-weights
+    let weight = match value {
+        FontWeight::Absolute(AbsoluteFontWeight::Normal) => FontStyle::NORMAL_WEIGHT,
+        FontWeight::Absolute(AbsoluteFontWeight::Bold) => FontStyle::BOLD_WEIGHT,
+        FontWeight::Absolute(AbsoluteFontWeight::Weight(value)) => {
+            if !(1.0..=1000.0).contains(value) {
+                bail!("font weight must be between 1 and 1000, got {value}");
+            }
+            value.round() as u16
+        }
+        FontWeight::Bolder => match inherited {
+            ..350 => 400,
+            350..550 => 700,
+            _ => 900,
+        },
+        FontWeight::Lighter => match inherited {
+            ..550 => 100,
+            550..750 => 400,
+            _ => 700,
+        },
+    };
+    Ok(weight)
+}
+
+fn convert_font_slant(value: &CssFontStyle) -> FontSlant {
+    match value {
+        CssFontStyle::Normal => FontSlant::Normal,
+        CssFontStyle::Italic => FontSlant::Italic,
+        CssFontStyle::Oblique(_) => FontSlant::Oblique,
+    }
+}
+
+fn convert_text_decoration_line(value: &TextDecorationLine) -> TextDecoration {
+    if value.contains(TextDecorationLine::Overline) || value.contains(TextDecorationLine::Blink) {
+        warn!("unsupported text decoration line: {value:?}");
+    }
+    TextDecoration {
+        underline: value.contains(TextDecorationLine::Underline),
+        line_through: value.contains(TextDecorationLine::LineThrough),
+    }
+}
+
+fn convert_letter_spacing(value: &Spacing, font_size: LogicalPixels) -> Result<LogicalPixels> {
+    match value {
+        Spacing::Normal => Ok(0.0.lpx()),
+        Spacing::Length(Length::Value(value)) => convert_length(value, Some(font_size)),
+        Spacing::Length(Length::Calc(_)) => bail!("calc is unsupported"),
+    }
+}
+
 // This function requires passing root font separately because it has special logic
 // for default line height depending on whether font size was specified for the element or not.
+// Other font properties are inherited from the root font if they are not specified.
 pub fn convert_font(properties: &[&Property<'static>], root: Option<&FontStyle>) -> FontStyle {
     let mut self_font_size = None;
     let mut line_height = None;
+    let mut font_family = None;
+    let mut font_weight = None;
+    let mut font_slant = None;
+    let mut letter_spacing = None;
+    let mut text_decoration = None;
+    let inherited_weight = root.map_or(FontStyle::NORMAL_WEIGHT, |root| root.font_weight);
     for property in properties {
         match property {
             Property::FontSize(size) => match convert_font_size(size) {
                 Ok(value) => self_font_size = Some(value),
                 Err(err) => warn!("invalid font size: {err:?}"),
             },
-            Property::Font(font) => match convert_font_size(&font.size) {
-                Ok(value) => self_font_size = Some(value),
-                Err(err) => warn!("invalid font size: {err:?}"),
+            Property::Font(font) => {
+                match convert_font_size(&font.size) {
+                    Ok(value) => self_font_size = Some(value),
+                    Err(err) => warn!("invalid font size: {err:?}"),
+                }
+                font_family = Some(convert_font_family(&font.family));
+                match convert_font_weight(&font.weight, inherited_weight) {
+                    Ok(value) => font_weight = Some(value),
+                    Err(err) => warn!("invalid font weight: {err:?}"),
+                }
+                font_slant = Some(convert_font_slant(&font.style));
+            }
+            Property::FontFamily(value) => font_family = Some(convert_font_family(value)),
+            Property::FontWeight(value) => match convert_font_weight(value, inherited_weight) {
+                Ok(value) => font_weight = Some(value),
+                Err(err) => warn!("invalid font weight: {err:?}"),
             },
+            Property::FontStyle(value) => font_slant = Some(convert_font_slant(value)),
+            Property::TextDecoration(value, _prefix) => {
+                text_decoration = Some(convert_text_decoration_line(&value.line));
+            }
+            Property::TextDecorationLine(value, _prefix) => {
+                text_decoration = Some(convert_text_decoration_line(value));
+            }
             _ => {}
         }
     }
@@ -141,6 +268,12 @@ pub fn convert_font(properties: &[&Property<'static>], root: Option<&FontStyle>)
                 Ok(value) => line_height = Some(value),
                 Err(err) => warn!("invalid line height: {err:?}"),
             },
+            Property::LetterSpacing(value) => {
+                match convert_letter_spacing(value, final_font_size) {
+                    Ok(value) => letter_spacing = Some(value),
+                    Err(err) => warn!("invalid letter spacing: {err:?}"),
+                }
+            }
             _ => {}
         }
     }
@@ -160,6 +293,19 @@ pub fn convert_font(properties: &[&Property<'static>], root: Option<&FontStyle>)
     FontStyle {
         font_size: final_font_size,
         line_height,
+        font_family: font_family
+            .or_else(|| root.map(|root| root.font_family.clone()))
+            .unwrap_or_default(),
+        font_weight: font_weight.unwrap_or(inherited_weight),
+        font_slant: font_slant
+            .or_else(|| root.map(|root| root.font_slant))
+            .unwrap_or_default(),
+        letter_spacing: letter_spacing
+            .or_else(|| root.map(|root| root.letter_spacing))
+            .unwrap_or_default(),
+        text_decoration: text_decoration
+            .or_else(|| root.map(|root| root.text_decoration))
+            .unwrap_or_default(),
     }
 }
 
@@ -212,7 +358,7 @@ pub fn convert_main_color(properties: &[&Property<'static>]) -> Option<Color> {
     color
 }
 
-fn convert_single_padding(
+fn convert_side_length(
     value: &LengthPercentageOrAuto,
     font_size: LogicalPixels,
 ) -> Result<LogicalPixels> {
@@ -240,39 +386,109 @@ fn convert_single_spacing(value: &GapValue, font_size: LogicalPixels) -> Result<
     }
 }
 
-pub fn convert_padding(
-    properties: &[&Property<'static>],
+// Converts a property that can be specified separately for each side of a box.
+// `sides` returns the values that the property sets.
+fn convert_sides<'a>(
+    properties: &[&'a Property<'static>],
     scale: f32,
     font_size: LogicalPixels,
-) -> Point {
-    let mut left = None;
-    let mut top = None;
+    name: &str,
+    sides: impl Fn(&'a Property<'static>) -> Sides<Option<&'a LengthPercentageOrAuto>>,
+) -> Sides<PhysicalPixels> {
+    let mut output = Sides::<Option<LogicalPixels>>::default();
     for property in properties {
-        match property {
-            Property::Padding(value) => {
-                match convert_single_padding(&value.left, font_size) {
-                    Ok(value) => left = Some(value),
-                    Err(err) => warn!("invalid padding: {err:?}"),
-                }
-                match convert_single_padding(&value.top, font_size) {
-                    Ok(value) => top = Some(value),
-                    Err(err) => warn!("invalid padding: {err:?}"),
-                }
+        let values = sides(property);
+        for (value, output) in [
+            (values.left, &mut output.left),
+            (values.top, &mut output.top),
+            (values.right, &mut output.right),
+            (values.bottom, &mut output.bottom),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+            match convert_side_length(value, font_size) {
+                Ok(value) => *output = Some(value),
+                Err(err) => warn!("invalid {name}: {err:?}"),
             }
-            Property::PaddingLeft(value) => match convert_single_padding(value, font_size) {
-                Ok(value) => left = Some(value),
-                Err(err) => warn!("invalid padding: {err:?}"),
-            },
-            Property::PaddingTop(value) => match convert_single_padding(value, font_size) {
-                Ok(value) => top = Some(value),
-                Err(err) => warn!("invalid padding: {err:?}"),
-            },
-            _ => {}
         }
     }
-    Point::new(
-        left.unwrap_or_default().to_physical(scale),
-        top.unwrap_or_default().to_physical(scale),
+    output.map(|value| value.unwrap_or_default().to_physical(scale))
+}
+
+pub fn convert_padding(
+    properties: &[&Property<'static>],
+    scale: f32,
+    font_size: LogicalPixels,
+) -> Sides<PhysicalPixels> {
+    convert_sides(
+        properties,
+        scale,
+        font_size,
+        "padding",
+        |property| match property {
+            Property::Padding(value) => Sides::new(
+                Some(&value.left),
+                Some(&value.top),
+                Some(&value.right),
+                Some(&value.bottom),
+            ),
+            Property::PaddingLeft(value) => Sides {
+                left: Some(value),
+                ..Sides::default()
+            },
+            Property::PaddingTop(value) => Sides {
+                top: Some(value),
+                ..Sides::default()
+            },
+            Property::PaddingRight(value) => Sides {
+                right: Some(value),
+                ..Sides::default()
+            },
+            Property::PaddingBottom(value) => Sides {
+                bottom: Some(value),
+                ..Sides::default()
+            },
+            _ => Sides::default(),
+        },
+    )
+}
+
+pub fn convert_margin(
+    properties: &[&Property<'static>],
+    scale: f32,
+    font_size: LogicalPixels,
+) -> Sides<PhysicalPixels> {
+    convert_sides(
+        properties,
+        scale,
+        font_size,
+        "margin",
+        |property| match property {
+            Property::Margin(value) => Sides::new(
+                Some(&value.left),
+                Some(&value.top),
+                Some(&value.right),
+                Some(&value.bottom),
+            ),
+            Property::MarginLeft(value) => Sides {
+                left: Some(value),
+                ..Sides::default()
+            },
+            Property::MarginTop(value) => Sides {
+                top: Some(value),
+                ..Sides::default()
+            },
+            Property::MarginRight(value) => Sides {
+                right: Some(value),
+                ..Sides::default()
+            },
+            Property::MarginBottom(value) => Sides {
+                bottom: Some(value),
+                ..Sides::default()
+            },
+            _ => Sides::default(),
+        },
     )
 }
 
@@ -335,6 +551,27 @@ pub fn convert_width(
     Ok(width.map(|width| width.to_physical(scale)))
 }
 
+pub fn convert_height(
+    properties: &[&Property<'static>],
+    scale: f32,
+    font_size: LogicalPixels,
+) -> Result<Option<PhysicalPixels>> {
+    let mut height = None;
+    for property in properties {
+        match property {
+            Property::Height(value) => match value {
+                Size::Auto => {}
+                Size::LengthPercentage(value) => {
+                    height = Some(convert_dimension_percentage(value, None, Some(font_size))?);
+                }
+                _ => warn!("unsupported height value: {value:?}"),
+            },
+            _ => {}
+        }
+    }
+    Ok(height.map(|height| height.to_physical(scale)))
+}
+
 fn convert_border_width(width: &BorderSideWidth) -> Result<LogicalPixels> {
     if let BorderSideWidth::Length(width) = width {
         match width {
@@ -346,63 +583,156 @@ fn convert_border_width(width: &BorderSideWidth) -> Result<LogicalPixels> {
     }
 }
 
+fn border_side_width(value: &BorderSideWidth) -> Option<LogicalPixels> {
+    convert_border_width(value)
+        .context("invalid border")
+        .or_report_err()
+}
+
+fn border_side_color(value: &CssColor) -> Option<Color> {
+    convert_color(value)
+        .context("invalid border")
+        .or_report_err()
+}
+
+// Only circular corners are supported, so the vertical radius is ignored.
+fn border_corner_radius(value: &Size2D<LengthPercentage>) -> Option<LogicalPixels> {
+    convert_dimension_percentage(&value.0, None, None)
+        .context("invalid border radius")
+        .or_report_err()
+}
+
 pub fn convert_border(
     properties: &[&Property<'static>],
     scale: f32,
     text_color: Color,
 ) -> ComputedBorderStyle {
-    let mut width = None;
-    let mut color = None;
-    let mut radius = None;
-    let mut style = LineStyle::None;
+    let mut width = Sides::<Option<LogicalPixels>>::default();
+    let mut color = Sides::<Option<Color>>::default();
+    let mut radius = Corners::<Option<LogicalPixels>>::default();
+    let mut style = Sides::all(LineStyle::None);
     for property in properties {
         match property {
             Property::Border(value) => {
-                match convert_border_width(&value.width) {
-                    Ok(value) => width = Some(value),
-                    Err(err) => warn!("invalid border: {err:?}"),
+                if let Some(value) = border_side_width(&value.width) {
+                    width = Sides::all(Some(value));
                 }
-                match convert_color(&value.color) {
-                    Ok(value) => color = Some(value),
-                    Err(err) => warn!("invalid border: {err:?}"),
+                if let Some(value) = border_side_color(&value.color) {
+                    color = Sides::all(Some(value));
                 }
-                style = value.style;
+                style = Sides::all(value.style);
+            }
+            Property::BorderLeft(value) => {
+                width.left = border_side_width(&value.width).or(width.left);
+                color.left = border_side_color(&value.color).or(color.left);
+                style.left = value.style;
+            }
+            Property::BorderTop(value) => {
+                width.top = border_side_width(&value.width).or(width.top);
+                color.top = border_side_color(&value.color).or(color.top);
+                style.top = value.style;
+            }
+            Property::BorderRight(value) => {
+                width.right = border_side_width(&value.width).or(width.right);
+                color.right = border_side_color(&value.color).or(color.right);
+                style.right = value.style;
+            }
+            Property::BorderBottom(value) => {
+                width.bottom = border_side_width(&value.width).or(width.bottom);
+                color.bottom = border_side_color(&value.color).or(color.bottom);
+                style.bottom = value.style;
             }
             Property::BorderWidth(value) => {
-                // TODO: support different sides
-                match convert_border_width(&value.top) {
-                    Ok(value) => width = Some(value),
-                    Err(err) => warn!("invalid border: {err:?}"),
-                }
+                width.left = border_side_width(&value.left).or(width.left);
+                width.top = border_side_width(&value.top).or(width.top);
+                width.right = border_side_width(&value.right).or(width.right);
+                width.bottom = border_side_width(&value.bottom).or(width.bottom);
+            }
+            Property::BorderLeftWidth(value) => {
+                width.left = border_side_width(value).or(width.left)
+            }
+            Property::BorderTopWidth(value) => width.top = border_side_width(value).or(width.top),
+            Property::BorderRightWidth(value) => {
+                width.right = border_side_width(value).or(width.right)
+            }
+            Property::BorderBottomWidth(value) => {
+                width.bottom = border_side_width(value).or(width.bottom)
+            }
+            Property::BorderColor(value) => {
+                color.left = border_side_color(&value.left).or(color.left);
+                color.top = border_side_color(&value.top).or(color.top);
+                color.right = border_side_color(&value.right).or(color.right);
+                color.bottom = border_side_color(&value.bottom).or(color.bottom);
+            }
+            Property::BorderLeftColor(value) => {
+                color.left = border_side_color(value).or(color.left)
+            }
+            Property::BorderTopColor(value) => color.top = border_side_color(value).or(color.top),
+            Property::BorderRightColor(value) => {
+                color.right = border_side_color(value).or(color.right)
+            }
+            Property::BorderBottomColor(value) => {
+                color.bottom = border_side_color(value).or(color.bottom)
             }
-            Property::BorderColor(value) => match convert_color(&value.top) {
-                Ok(value) => color = Some(value),
-                Err(err) => warn!("invalid border: {err:?}"),
-            },
             Property::BorderStyle(value) => {
-                style = value.top;
+                style = Sides::new(value.left, value.top, value.right, value.bottom);
             }
+            Property::BorderLeftStyle(value) => style.left = *value,
+            Property::BorderTopStyle(value) => style.top = *value,
+            Property::BorderRightStyle(value) => style.right = *value,
+            Property::BorderBottomStyle(value) => style.bottom = *value,
             Property::BorderRadius(value, _prefix) => {
-                match convert_dimension_percentage(&value.top_left.0, None, None) {
-                    Ok(value) => radius = Some(value),
-                    Err(err) => warn!("invalid border radius: {err:?}"),
-                }
+                radius.top_left = border_corner_radius(&value.top_left).or(radius.top_left);
+                radius.top_right = border_corner_radius(&value.top_right).or(radius.top_right);
+                radius.bottom_right =
+                    border_corner_radius(&value.bottom_right).or(radius.bottom_right);
+                radius.bottom_left =
+                    border_corner_radius(&value.bottom_left).or(radius.bottom_left);
+            }
+            Property::BorderTopLeftRadius(value, _prefix) => {
+                radius.top_left = border_corner_radius(value).or(radius.top_left);
+            }
+            Property::BorderTopRightRadius(value, _prefix) => {
+                radius.top_right = border_corner_radius(value).or(radius.top_right);
+            }
+            Property::BorderBottomRightRadius(value, _prefix) => {
+                radius.bottom_right = border_corner_radius(value).or(radius.bottom_right);
+            }
+            Property::BorderBottomLeftRadius(value, _prefix) => {
+                radius.bottom_left = border_corner_radius(value).or(radius.bottom_left);
             }
             _ => {}
         }
     }
 
-    match style {
-        LineStyle::None => ComputedBorderStyle::default(),
-        LineStyle::Solid => ComputedBorderStyle {
-            width: width.unwrap_or_default().to_physical(scale),
-            color: color.unwrap_or(text_color),
-            radius: radius.unwrap_or_default().to_physical(scale),
-        },
+    let is_visible = |style: LineStyle| match style {
+        LineStyle::None => false,
+        LineStyle::Solid => true,
         _ => {
             warn!("unsupported border line style: {style:?}");
-            ComputedBorderStyle::default()
+            false
         }
+    };
+    let visible = style.map(is_visible);
+    if !(visible.left || visible.top || visible.right || visible.bottom) {
+        return ComputedBorderStyle::default();
+    }
+    let side_width = |visible: bool, width: Option<LogicalPixels>| {
+        if visible {
+            width.unwrap_or_default().to_physical(scale)
+        } else {
+            0.ppx()
+        }
+    };
+    ComputedBorderStyle {
+        width: Sides::new(
+            side_width(visible.left, width.left),
+            side_width(visible.top, width.top),
+            side_width(visible.right, width.right),
+            side_width(visible.bottom, width.bottom),
+        ),
+        color: color.map(|color| color.unwrap_or(text_color)),
+        radius: radius.map(|radius| radius.unwrap_or_default().to_physical(scale)),
     }
 }
 
@@ -633,14 +963,47 @@ pub fn convert_content_url(properties: &[&Property<'static>]) -> Option<String>
     final_url
 }
 
+/// Returns the name of a theme icon specified by the `icon` property (e.g. `icon: tab_close;`).
+pub fn convert_icon_name(properties: &[&Property<'static>]) -> Option<String> {
+    let mut final_name = None;
+    for property in properties {
+        match property {
+            Property::Custom(property) => {
+                if let CustomPropertyName::Unknown(name) = &property.name {
+                    if name.as_ref() == "icon" {
+                        if property.value.0.len() != 1 {
+                            warn!("expected 1 token in icon proprety");
+                            continue;
+                        }
+                        match &property.value.0[0] {
+                            TokenOrValue::Token(Token::Ident(ident))
+                                if ident.as_ref() == "none" =>
+                            {
+                                final_name = None;
+                            }
+                            TokenOrValue::Token(Token::Ident(ident) | Token::String(ident)) => {
+                                final_name = Some(ident.to_string());
+                            }
+                            _ => {
+                                warn!("expected icon name in icon proprety");
+                                continue;
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    final_name
+}
+
 pub fn replace_vars(style_sheet: &mut StyleSheet) {
     //let mut style_sheet: StyleSheet<'static, 'static> = style_sheet.into_owned();
     let mut vars = HashMap::new();
     for rule in &style_sheet.rules.0 {
         if let CssRule::Style(rule) = rule {
-            // println!("selectors: {:?}", rule.selectors);
             for selector in &rule.selectors.0 {
-                // print_selector(selector);
                 if is_root(selector) {
                     // println!("found root!");
                     for (property, _) in rule.declarations.iter() {
@@ -652,14 +1015,6 @@ pub fn replace_vars(style_sheet: &mut StyleSheet) {
                         }
                     }
                 }
-                // if let Some(data) = as_tag_with_class(selector) {
-                // println!(
-                //     "found tag with class {:?}, {}",
-                //     data.tag,
-                //     serde_json::to_string(&data.class).unwrap()
-                // );
-                // }
-                // print_selector(selector);
             }
         }
     }
@@ -689,69 +1044,6 @@ pub fn replace_vars(style_sheet: &mut StyleSheet) {
 }
 
 #[allow(dead_code)]
-fn print_selector(selector: &Selector) {
-    println!("selector: {:?}", selector);
-    let mut iter = selector.iter();
-    loop {
-        for x in &mut iter {
-            println!("selector item: {:?}", x);
-            print_component(x);
-            if matches!(x, Component::Root) {
-                println!("found root!");
-            }
-            if let Component::Negation(inner) = x {
-                println!("found not! inner:");
-                print_selector(&inner[0]);
-                println!("inner end");
-            }
-        }
-        if let Some(seq) = iter.next_sequence() {
-            println!("seq: {seq:?}");
-        } else {
-            println!("no seq");
-            break;
-        }
-    }
-}
-
-fn print_component(component: &Component) {
-    match component {
-        Component::Combinator(_) => println!("Combinator"),
-        Component::ExplicitAnyNamespace => println!("ExplicitAnyNamespace"),
-        Component::ExplicitNoNamespace => println!("ExplicitNoNamespace"),
-        Component::DefaultNamespace(_) => println!("DefaultNamespace"),
-        Component::Namespace(..) => println!("Namespace"),
-        Component::ExplicitUniversalType => println!("ExplicitUniversalType"),
-        Component::LocalName(_) => println!("LocalName"),
-        Component::ID(_) => println!("ID"),
-        Component::Class(_) => println!("Class"),
-        Component::AttributeInNoNamespaceExists { .. } => println!("AttributeInNoNamespaceExists"),
-        Component::AttributeInNoNamespace { .. } => println!("AttributeInNoNamespace"),
-        Component::AttributeOther(_) => println!("AttributeOther"),
-        Component::Negation(_) => println!("Negation"),
-        Component::Root => println!("Root"),
-        Component::Empty => println!("Empty"),
-        Component::Scope => println!("Scope"),
-        Component::Nth(_) => println!("Nth"),
-        Component::NthOf(_) => println!("NthOf"),
-        Component::NonTSPseudoClass(x) => {
-            println!("NonTSPseudoClass");
-            if let selector::PseudoClass::Custom { name } = x {
-                println!("name = {name:?}");
-            }
-        }
-        Component::Slotted(_) => println!("Slotted"),
-        Component::Part(_) => println!("Part"),
-        Component::Host(_) => println!("Host"),
-        Component::Where(_) => println!("Where"),
-        Component::Is(_) => println!("Is"),
-        Component::Any(..) => println!("Any"),
-        Component::Has(_) => println!("Has"),
-        Component::PseudoElement(_) => println!("PseudoElement"),
-        Component::Nesting => println!("Nesting"),
-    }
-}
-
 pub fn selector_items<'i, 'a>(selector: &'a Selector<'i>) -> Option<Vec<&'a Component<'i>>> {
     let mut iter = selector.iter();
     let components = (&mut iter).collect_vec();
@@ -802,7 +1094,18 @@ pub enum PseudoClass {
     Active,
     Enabled,
     Disabled,
-    // TODO: add more relevant classes
+    Checked,
+    Indeterminate,
+    /// Focus that wasn't set by the mouse.
+    FocusVisible,
+    /// Text that can't be edited by the user.
+    ReadOnly,
+    Valid,
+    Invalid,
+    /// Default button of a dialog.
+    Default,
+    /// Any widget in a window that doesn't have focus.
+    WindowInactive,
     Custom(CowArcStr<'static>),
 }
 
@@ -814,18 +1117,106 @@ impl PseudoClass {
             selector::PseudoClass::Active => Some(Self::Active),
             selector::PseudoClass::Enabled => Some(Self::Enabled),
             selector::PseudoClass::Disabled => Some(Self::Disabled),
+            selector::PseudoClass::Checked => Some(Self::Checked),
+            selector::PseudoClass::Indeterminate => Some(Self::Indeterminate),
+            selector::PseudoClass::FocusVisible => Some(Self::FocusVisible),
+            selector::PseudoClass::ReadOnly(_) => Some(Self::ReadOnly),
+            selector::PseudoClass::Valid => Some(Self::Valid),
+            selector::PseudoClass::Invalid => Some(Self::Invalid),
+            selector::PseudoClass::Default => Some(Self::Default),
+            selector::PseudoClass::WebKitScrollbar(WebKitScrollbarPseudoClass::WindowInactive) => {
+                Some(Self::WindowInactive)
+            }
+            selector::PseudoClass::Custom { name } if **name == *WINDOW_INACTIVE => {
+                Some(Self::WindowInactive)
+            }
             selector::PseudoClass::Custom { name } => Some(Self::Custom(name.clone())),
             _ => None,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+// lightningcss only accepts `:window-inactive` after a `::-webkit-scrollbar` pseudo-element,
+// so it's renamed to this custom pseudo-class before parsing.
+const WINDOW_INACTIVE: &str = "-widgem-window-inactive";
+
+/// Prepares the CSS code for parsing by replacing pseudo-classes
+/// that lightningcss doesn't accept in arbitrary selectors.
+pub fn preprocess_css(css: &str) -> Cow<'_, str> {
+    const NAME: &str = ":window-inactive";
+    if !css.contains(NAME) {
+        return Cow::Borrowed(css);
+    }
+    let mut output = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(index) = rest.find(NAME) {
+        let end = index + NAME.len();
+        output.push_str(&rest[..index]);
+        let is_whole_name =
+            !rest[end..].starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_');
+        if is_whole_name {
+            output.push(':');
+            output.push_str(WINDOW_INACTIVE);
+        } else {
+            output.push_str(NAME);
+        }
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    Cow::Owned(output)
+}
+
+/// Position of an element among the children of its parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChildPosition {
+    /// Zero-based index of the element.
+    pub index: usize,
+    /// Number of children of the parent.
+    pub count: usize,
+}
+
+/// State of a widget that is matched against the selectors of the style.
+///
+/// Comparison and hashing take into account the ancestors through a precomputed
+/// hash of their states, so the element can be used as a compact cache key.
+#[derive(Debug, Clone)]
 pub struct Element {
     tag: String,
     // TODO: small vec?
     classes: Vec<Cow<'static, str>>,
     pseudo_classes: Vec<PseudoClass>,
+    // Sorted by name.
+    attributes: Vec<(Cow<'static, str>, String)>,
+    // Only tracked if the style has selectors that depend on it.
+    position: Option<ChildPosition>,
+    parent: Option<Rc<Element>>,
+    // Hash of the states of the parent and its ancestors. Used instead of `parent`
+    // in comparisons and hashing.
+    ancestry_hash: u64,
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+            && self.classes == other.classes
+            && self.pseudo_classes == other.pseudo_classes
+            && self.attributes == other.attributes
+            && self.position == other.position
+            && self.ancestry_hash == other.ancestry_hash
+    }
+}
+
+impl Eq for Element {}
+
+impl Hash for Element {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.tag.hash(state);
+        self.classes.hash(state);
+        self.pseudo_classes.hash(state);
+        self.attributes.hash(state);
+        self.position.hash(state);
+        self.ancestry_hash.hash(state);
+    }
 }
 
 impl Element {
@@ -834,6 +1225,10 @@ impl Element {
             tag,
             classes: Vec::new(),
             pseudo_classes: Vec::new(),
+            attributes: Vec::new(),
+            position: None,
+            parent: None,
+            ancestry_hash: 0,
         }
     }
 
@@ -880,6 +1275,35 @@ impl Element {
         }
     }
 
+    /// Returns the value of the attribute that can be matched by attribute selectors
+    /// (e.g. `[orientation="vertical"]`).
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attr_name, _)| attr_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets or removes (if `value` is `None`) the attribute.
+    pub fn set_attribute(&mut self, name: Cow<'static, str>, value: Option<String>) {
+        match self
+            .attributes
+            .binary_search_by(|(attr_name, _)| attr_name.cmp(&name))
+        {
+            Ok(index) => match value {
+                Some(value) => self.attributes[index].1 = value,
+                None => {
+                    self.attributes.remove(index);
+                }
+            },
+            Err(index) => {
+                if let Some(value) = value {
+                    self.attributes.insert(index, (name, value));
+                }
+            }
+        }
+    }
+
     pub fn with_class(mut self, class: Cow<'static, str>) -> Self {
         self.add_class(class);
         self
@@ -890,35 +1314,139 @@ impl Element {
         self
     }
 
+    /// Returns the element of the parent widget. It's `None` for the root widget of a window.
+    pub fn parent(&self) -> Option<&Element> {
+        self.parent.as_deref()
+    }
+
+    pub fn set_parent(&mut self, parent: Option<Rc<Element>>) {
+        self.ancestry_hash = parent.as_deref().map_or(0, |parent| {
+            let mut hasher = DefaultHasher::new();
+            parent.hash(&mut hasher);
+            hasher.finish()
+        });
+        self.parent = parent;
+    }
+
+    /// Returns a copy of the element that doesn't hold references to its ancestors
+    /// but is still equal to the original element.
+    pub fn cache_key(&self) -> Element {
+        Element {
+            tag: self.tag.clone(),
+            classes: self.classes.clone(),
+            pseudo_classes: self.pseudo_classes.clone(),
+            attributes: self.attributes.clone(),
+            position: self.position,
+            parent: None,
+            ancestry_hash: self.ancestry_hash,
+        }
+    }
+
+    pub fn position(&self) -> Option<ChildPosition> {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Option<ChildPosition>) {
+        self.position = position;
+    }
+
     pub fn matches(&self, selector: &Selector<'static>) -> bool {
-        let Some(items) = selector_items(selector) else {
+        self.matches_sequence(selector.iter_raw_match_order().as_slice())
+    }
+
+    // Checks the rightmost compound selector of `components` (in match order), and then
+    // the compound selectors to the left of it against the ancestors.
+    fn matches_sequence(&self, components: &[Component<'static>]) -> bool {
+        let compound_len = components
+            .iter()
+            .position(|component| matches!(component, Component::Combinator(_)))
+            .unwrap_or(components.len());
+        if !components[..compound_len]
+            .iter()
+            .all(|component| self.matches_component(component))
+        {
             return false;
+        }
+        let Some(Component::Combinator(combinator)) = components.get(compound_len) else {
+            return true;
         };
-        for item in items {
-            match item {
-                Component::NonTSPseudoClass(item_class) => {
-                    if let Some(item_class) = PseudoClass::from_css(item_class) {
-                        if !self.pseudo_classes.contains(&item_class) {
-                            return false;
-                        }
-                    } else {
-                        return false;
-                    }
-                }
-                Component::Class(c) => {
-                    if !self.classes.iter().any(|i| **i == **c) {
-                        return false;
-                    }
-                }
-                Component::LocalName(name) => {
-                    if self.tag != name.lower_name.as_ref() {
-                        return false;
+        let rest = &components[compound_len + 1..];
+        match combinator {
+            Combinator::Child => self
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.matches_sequence(rest)),
+            Combinator::Descendant => {
+                let mut ancestor = self.parent.as_deref();
+                while let Some(element) = ancestor {
+                    if element.matches_sequence(rest) {
+                        return true;
                     }
+                    ancestor = element.parent.as_deref();
                 }
-                _ => return false,
+                false
             }
+            // Sibling combinators and pseudo-elements are not supported.
+            _ => false,
+        }
+    }
+
+    fn matches_component(&self, component: &Component<'static>) -> bool {
+        match component {
+            Component::NonTSPseudoClass(class) => PseudoClass::from_css(class)
+                .is_some_and(|class| self.pseudo_classes.contains(&class)),
+            Component::Class(c) => self.classes.iter().any(|i| **i == **c),
+            Component::LocalName(name) => self.tag == name.lower_name.as_ref(),
+            Component::ExplicitUniversalType | Component::ExplicitAnyNamespace => true,
+            Component::Negation(selectors) => {
+                !selectors.iter().any(|selector| self.matches(selector))
+            }
+            Component::Is(selectors) | Component::Where(selectors) => {
+                selectors.iter().any(|selector| self.matches(selector))
+            }
+            Component::Nth(data) => self.matches_nth(data),
+            Component::AttributeInNoNamespaceExists { local_name, .. } => {
+                self.attribute(local_name).is_some()
+            }
+            Component::AttributeInNoNamespace {
+                local_name,
+                operator,
+                value,
+                case_sensitivity,
+                never_matches,
+            } => {
+                !never_matches
+                    && self.attribute(local_name).is_some_and(|attribute| {
+                        operator.eval_str(
+                            attribute,
+                            value,
+                            case_sensitivity.to_unconditional(false),
+                        )
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    fn matches_nth(&self, data: &NthSelectorData) -> bool {
+        let Some(position) = self.position else {
+            return false;
+        };
+        // 1-based position as in CSS.
+        let index = match data.ty {
+            NthType::Child => position.index + 1,
+            NthType::LastChild => position.count - position.index,
+            NthType::OnlyChild => return position.count == 1,
+            // Types of siblings are unknown. Such selectors are removed when the style is loaded.
+            _ => return false,
+        } as i32;
+        // Checks if `index == a * n + b` for some `n >= 0`.
+        if data.a == 0 {
+            index == data.b
+        } else {
+            let diff = index - data.b;
+            diff % data.a == 0 && diff / data.a >= 0
         }
-        true
     }
 
     pub fn tag(&self) -> &str {
@@ -926,6 +1454,39 @@ impl Element {
     }
 }
 
+/// Returns `true` if the selector depends on the position of the element among its siblings.
+pub fn depends_on_position(selector: &Selector) -> bool {
+    selector
+        .iter_raw_match_order()
+        .any(|component| match component {
+            Component::Nth(_) | Component::NthOf(_) => true,
+            Component::Negation(selectors)
+            | Component::Is(selectors)
+            | Component::Where(selectors) => selectors.iter().any(depends_on_position),
+            _ => false,
+        })
+}
+
+/// Returns `true` if the selector can be matched against an [Element].
+///
+/// `:nth-child(An+B of S)` and `*-of-type` pseudo-classes are not supported
+/// because an element doesn't know about its siblings.
+pub fn is_supported_selector(selector: &Selector) -> bool {
+    selector
+        .iter_raw_match_order()
+        .all(|component| match component {
+            Component::Nth(data) => matches!(
+                data.ty,
+                NthType::Child | NthType::LastChild | NthType::OnlyChild
+            ),
+            Component::NthOf(_) => false,
+            Component::Negation(selectors)
+            | Component::Is(selectors)
+            | Component::Where(selectors) => selectors.iter().all(is_supported_selector),
+            _ => true,
+        })
+}
+
 // pub struct TagSelector<'a, 'b> {
 //     pub tag: &'a str,
 //     pub class: Option<&'a PseudoClass<'b>>,
@@ -978,3 +1539,112 @@ impl Element {
 //         None
 //     }
 // }
+
+#[test]
+fn match_selectors() {
+    let css = "window .toolbar button, row > button, button:not(.flat), button:is(.a, .b), \
+        button:first-child, button:nth-child(2n), button:last-child, \
+        button[checkable], slider[axis=\"y\"], button:nth-child(n+2 of .a), \
+        button:not(:first-of-type) {}";
+    let sheet = StyleSheet::parse(css, Default::default()).unwrap();
+    let CssRule::Style(rule) = &sheet.rules.0[0] else {
+        panic!("expected style rule");
+    };
+    let selectors = &rule.selectors.0;
+
+    let window = Rc::new(Element::new("window".into()));
+    let mut toolbar = Element::new("tool-bar".into()).with_class("toolbar".into());
+    toolbar.set_parent(Some(window.clone()));
+    let toolbar = Rc::new(toolbar);
+    let mut row = Element::new("row".into());
+    row.set_parent(Some(toolbar.clone()));
+    let row = Rc::new(row);
+
+    let mut button = Element::new("button".into()).with_class("flat".into());
+    button.set_parent(Some(row.clone()));
+    assert!(button.matches(&selectors[0]));
+    assert!(button.matches(&selectors[1]));
+    assert!(!button.matches(&selectors[2]));
+    assert!(!button.matches(&selectors[3]));
+    button.set_parent(Some(toolbar.clone()));
+    assert!(button.matches(&selectors[0]));
+    assert!(!button.matches(&selectors[1]));
+    button.set_parent(Some(window.clone()));
+    assert!(!button.matches(&selectors[0]));
+
+    let mut other_button = button.clone();
+    assert_eq!(other_button, button.cache_key());
+    other_button.set_parent(Some(toolbar.clone()));
+    assert_ne!(other_button, button);
+
+    button.remove_class("flat".into());
+    button.add_class("b".into());
+    assert!(button.matches(&selectors[2]));
+    assert!(button.matches(&selectors[3]));
+
+    assert!(!button.matches(&selectors[4]));
+    button.set_position(Some(ChildPosition { index: 0, count: 3 }));
+    assert!(button.matches(&selectors[4]));
+    assert!(!button.matches(&selectors[5]));
+    assert!(!button.matches(&selectors[6]));
+    button.set_position(Some(ChildPosition { index: 1, count: 3 }));
+    assert!(!button.matches(&selectors[4]));
+    assert!(button.matches(&selectors[5]));
+    button.set_position(Some(ChildPosition { index: 2, count: 3 }));
+    assert!(button.matches(&selectors[6]));
+
+    assert!(!button.matches(&selectors[7]));
+    button.set_attribute("checkable".into(), Some(String::new()));
+    assert!(button.matches(&selectors[7]));
+    button.set_attribute("checkable".into(), None);
+    assert!(!button.matches(&selectors[7]));
+
+    let mut slider = Element::new("slider".into());
+    slider.set_attribute("axis".into(), Some("x".into()));
+    assert!(!slider.matches(&selectors[8]));
+    slider.set_attribute("axis".into(), Some("y".into()));
+    assert!(slider.matches(&selectors[8]));
+
+    assert!(depends_on_position(&selectors[4]));
+    assert!(!depends_on_position(&selectors[0]));
+
+    assert!(selectors[..9].iter().all(is_supported_selector));
+    assert!(!is_supported_selector(&selectors[9]));
+    assert!(!is_supported_selector(&selectors[10]));
+}
+
+#[test]
+fn match_pseudo_classes() {
+    let css = "button:focus-visible, text-input:read-only, spin-box:valid, spin-box:invalid, \
+        button:default, button:window-inactive, button:checked, button:indeterminate {}";
+    let css = preprocess_css(css).into_owned().leak();
+    let sheet = StyleSheet::parse(css, Default::default()).unwrap();
+    let CssRule::Style(rule) = &sheet.rules.0[0] else {
+        panic!("expected style rule");
+    };
+    let selectors = &rule.selectors.0;
+    let cases = [
+        ("button", PseudoClass::FocusVisible),
+        ("text-input", PseudoClass::ReadOnly),
+        ("spin-box", PseudoClass::Valid),
+        ("spin-box", PseudoClass::Invalid),
+        ("button", PseudoClass::Default),
+        ("button", PseudoClass::WindowInactive),
+        ("button", PseudoClass::Checked),
+        ("button", PseudoClass::Indeterminate),
+    ];
+    for (selector, (tag, class)) in selectors.iter().zip(&cases) {
+        // Other pseudo-classes don't match.
+        let mut element = Element::new(tag.to_string());
+        for (_, other) in &cases {
+            element.set_pseudo_class(other.clone(), other != class);
+        }
+        assert!(!element.matches(selector), "{tag}: {class:?}");
+        element.set_pseudo_class(class.clone(), true);
+        assert!(element.matches(selector), "{tag}: {class:?}");
+    }
+    assert_eq!(
+        preprocess_css("button:window-inactive-x {}"),
+        "button:window-inactive-x {}"
+    );
+}
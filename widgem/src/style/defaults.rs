@@ -1,7 +1,7 @@
 use {
     super::Style,
     crate::{
-        style::FontStyle,
+        style::{FontSlant, FontStyle, TextDecoration},
         types::{LogicalPixels, LpxSuffix},
     },
     tiny_skia::Color,
@@ -32,6 +32,30 @@ pub fn default_style() -> Style {
             file!("scroll_down_disabled.svg"),
             file!("scroll_grip_x_disabled.svg"),
             file!("scroll_grip_y_disabled.svg"),
+            file!("menu_check.svg"),
+            file!("menu_radio.svg"),
+            file!("menu_submenu.svg"),
+            file!("menu_submenu_disabled.svg"),
+            file!("check_box.svg"),
+            file!("check_box_checked.svg"),
+            file!("check_box_indeterminate.svg"),
+            file!("check_box_disabled.svg"),
+            file!("check_box_checked_disabled.svg"),
+            file!("check_box_indeterminate_disabled.svg"),
+            file!("radio_button.svg"),
+            file!("radio_button_checked.svg"),
+            file!("radio_button_disabled.svg"),
+            file!("radio_button_checked_disabled.svg"),
+            file!("combo_box_arrow.svg"),
+            file!("combo_box_arrow_disabled.svg"),
+            file!("tab_close.svg"),
+            file!("tree_collapsed.svg"),
+            file!("tree_expanded.svg"),
+            file!("tree_leaf.svg"),
+            file!("message_information.svg"),
+            file!("message_warning.svg"),
+            file!("message_error.svg"),
+            file!("message_question.svg"),
         ],
     )
     .unwrap()
@@ -59,6 +83,8 @@ pub fn selected_text_background() -> Color {
 
 pub const DEFAULT_PREFERRED_WIDTH_EM: f32 = 10.0;
 pub const DEFAULT_MIN_WIDTH_EM: f32 = 2.0;
+pub const DEFAULT_PREFERRED_HEIGHT_EM: f32 = 6.0;
+pub const DEFAULT_MIN_HEIGHT_EM: f32 = 2.0;
 
 pub const DEFAULT_LINE_HEIGHT: f32 = 1.2;
 
@@ -66,5 +92,10 @@ pub fn font_style() -> FontStyle {
     FontStyle {
         font_size: font_size(),
         line_height: font_size() * DEFAULT_LINE_HEIGHT,
+        font_family: Vec::new(),
+        font_weight: FontStyle::NORMAL_WEIGHT,
+        font_slant: FontSlant::Normal,
+        letter_spacing: 0.0.lpx(),
+        text_decoration: TextDecoration::default(),
     }
 }
@@ -0,0 +1,187 @@
+use {
+    super::{
+        css::{convert_content_url, convert_icon_name},
+        Style,
+    },
+    anyhow::{Context, Result},
+    lightningcss::rules::CssRule,
+    log::info,
+    std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+        time::{Duration, Instant, SystemTime},
+    },
+};
+
+// How often the files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reloads the style when the CSS file or the files referenced in it are modified.
+///
+/// The files are polled from the event loop, so it doesn't require any platform-specific
+/// file system notifications.
+pub(crate) struct StyleWatcher {
+    css_path: PathBuf,
+    // Paths of the CSS file and the files referenced in the current style.
+    watched_paths: Vec<PathBuf>,
+    // Modification time of each watched file (`None` if the file doesn't exist).
+    modified: BTreeMap<PathBuf, Option<SystemTime>>,
+    next_check: Instant,
+}
+
+impl StyleWatcher {
+    pub fn new(css_path: &Path, style: &Style) -> Self {
+        let mut this = Self {
+            css_path: css_path.into(),
+            watched_paths: Vec::new(),
+            modified: BTreeMap::new(),
+            next_check: Instant::now() + POLL_INTERVAL,
+        };
+        this.watch_files_of(style);
+        this
+    }
+
+    pub fn next_check(&self) -> Instant {
+        self.next_check
+    }
+
+    /// Returns the new style if any of the watched files were modified since the last check.
+    ///
+    /// Returns an error if the new style fails to load. The files are not reloaded again
+    /// until they change.
+    ///
+    /// This function reads and parses the files, so it shouldn't be called
+    /// while the system data is borrowed.
+    pub fn check(&mut self) -> Result<Option<Style>> {
+        let now = Instant::now();
+        if now < self.next_check {
+            return Ok(None);
+        }
+        self.next_check = now + POLL_INTERVAL;
+        let modified = modification_times(&self.watched_paths);
+        if modified == self.modified {
+            return Ok(None);
+        }
+        self.modified = modified;
+        let style = Style::load_from_file(&self.css_path)
+            .with_context(|| format!("failed to reload style from {:?}", self.css_path))?;
+        info!("reloaded style from {:?}", self.css_path);
+        // The new style may reference other files.
+        self.watch_files_of(&style);
+        Ok(Some(style))
+    }
+
+    fn watch_files_of(&mut self, style: &Style) {
+        let mut paths = vec![self.css_path.clone()];
+        if let Some(parent_dir) = self.css_path.parent() {
+            paths.extend(
+                referenced_files(style)
+                    .into_iter()
+                    .map(|path| parent_dir.join(path)),
+            );
+        }
+        self.modified = modification_times(&paths);
+        self.watched_paths = paths;
+    }
+}
+
+fn modification_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs_err::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+// Returns the relative paths of the files referenced in the style.
+fn referenced_files(style: &Style) -> Vec<String> {
+    let mut paths = Vec::new();
+    for rule in &style.css.rules.0 {
+        let CssRule::Style(rule) = rule else {
+            continue;
+        };
+        for (property, _important) in rule.declarations.iter() {
+            if let Some(url) = convert_content_url(&[property]) {
+                paths.push(url);
+            }
+            if let Some(name) = convert_icon_name(&[property]) {
+                for suffix in ["", "_disabled"] {
+                    for ext in ["svg", "png"] {
+                        paths.push(format!("{name}{suffix}.{ext}"));
+                    }
+                }
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+fn write_css(path: &Path, css: &str, modified: SystemTime) {
+    fs_err::write(path, css).unwrap();
+    fs_err::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .file()
+        .set_modified(modified)
+        .unwrap();
+}
+
+#[cfg(test)]
+fn test_css_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("widgem-test-{}-{name}", std::process::id()));
+    fs_err::create_dir_all(&dir).unwrap();
+    dir.join("theme.css")
+}
+
+#[test]
+fn changed_file_reloads() {
+    let path = test_css_path("changed_file_reloads");
+    let start = SystemTime::now();
+    write_css(&path, "button { color: red; }", start);
+    let style = Style::load_from_file(&path).unwrap();
+    let mut watcher = StyleWatcher::new(&path, &style);
+
+    watcher.next_check = Instant::now();
+    assert!(watcher.check().unwrap().is_none());
+
+    write_css(
+        &path,
+        "button { color: red; } label { color: blue; }",
+        start + Duration::from_secs(1),
+    );
+    watcher.next_check = Instant::now();
+    let new_style = watcher.check().unwrap().expect("style should be reloaded");
+    assert_eq!(new_style.css.rules.0.len(), 2);
+
+    watcher.next_check = Instant::now();
+    assert!(watcher.check().unwrap().is_none());
+    fs_err::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn invalid_css_is_reported() {
+    let path = test_css_path("invalid_css_is_reported");
+    let start = SystemTime::now();
+    write_css(&path, "button { color: red; }", start);
+    let style = Style::load_from_file(&path).unwrap();
+    let mut watcher = StyleWatcher::new(&path, &style);
+
+    write_css(
+        &path,
+        "button { color: red; } }",
+        start + Duration::from_secs(1),
+    );
+    watcher.next_check = Instant::now();
+    assert!(watcher.check().is_err());
+
+    // The invalid file is not reloaded again until it changes.
+    watcher.next_check = Instant::now();
+    assert!(watcher.check().unwrap().is_none());
+    fs_err::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -28,6 +28,8 @@ thread_local! {
 pub struct SystemConfig {
     pub auto_repeat_delay: Duration,
     pub auto_repeat_interval: Duration,
+    pub tooltip_delay: Duration,
+    pub mnemonics_on_alt: bool,
     pub exit_after_last_window_closes: bool,
 }
 
@@ -3,7 +3,11 @@ pub mod edit;
 pub mod editor;
 
 use {
-    cosmic_text::{Attrs, AttrsOwned, Buffer, BufferLine},
+    crate::style::{FontSlant, FontStyle},
+    cosmic_text::{
+        fontdb, Attrs, AttrsList, AttrsOwned, Buffer, BufferLine, FamilyOwned, LetterSpacing,
+        Style, Weight,
+    },
     derive_more::{From, Into},
     itertools::Itertools,
     std::{borrow::Cow, ops::Range},
@@ -71,6 +75,17 @@ impl AttrsExt for Attrs<'_> {
     }
 }
 
+// Bits of `Metadata` that mark the attributes of a span that were set explicitly
+// and shouldn't be replaced by the attributes of the widget style.
+const EXPLICIT_FAMILY: usize = 1 << 4;
+const EXPLICIT_WEIGHT: usize = 1 << 5;
+const EXPLICIT_STYLE: usize = 1 << 6;
+const EXPLICIT_LETTER_SPACING: usize = 1 << 7;
+const EXPLICIT_UNDERLINE: usize = 1 << 8;
+const EXPLICIT_LINE_THROUGH: usize = 1 << 9;
+// Number of bits of `Metadata` used for flags. The rest is used for the link index.
+const FLAG_BITS: u32 = 10;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, From, Into)]
 pub struct Metadata(pub usize);
 
@@ -83,4 +98,201 @@ impl Metadata {
         self.0 = (self.0 & !1) | (preedit as usize);
         self
     }
+
+    pub fn is_underlined(self) -> bool {
+        self.0 & 0x2 != 0
+    }
+
+    pub fn with_underline(mut self, underline: bool) -> Self {
+        self.0 = (self.0 & !2) | ((underline as usize) << 1);
+        self
+    }
+
+    /// Returns `true` if the text is a mnemonic character.
+    ///
+    /// Mnemonics are underlined unless the application only shows them while Alt is held.
+    pub fn is_mnemonic(self) -> bool {
+        self.0 & 0x4 != 0
+    }
+
+    pub fn with_mnemonic(mut self, mnemonic: bool) -> Self {
+        self.0 = (self.0 & !4) | ((mnemonic as usize) << 2);
+        self
+    }
+
+    pub fn is_line_through(self) -> bool {
+        self.0 & 0x8 != 0
+    }
+
+    pub fn with_line_through(mut self, line_through: bool) -> Self {
+        self.0 = (self.0 & !8) | ((line_through as usize) << 3);
+        self
+    }
+
+    /// Index of the link that contains the text.
+    pub fn link(self) -> Option<usize> {
+        (self.0 >> FLAG_BITS).checked_sub(1)
+    }
+
+    pub fn with_link(mut self, link: Option<usize>) -> Self {
+        self.0 =
+            (self.0 & ((1 << FLAG_BITS) - 1)) | (link.map_or(0, |index| index + 1) << FLAG_BITS);
+        self
+    }
+
+    fn has_flag(self, flag: usize) -> bool {
+        self.0 & flag != 0
+    }
+
+    fn with_flag(mut self, flag: usize, value: bool) -> Self {
+        if value {
+            self.0 |= flag;
+        } else {
+            self.0 &= !flag;
+        }
+        self
+    }
+}
+
+/// Text attributes that are determined by the font style of the widget.
+///
+/// Attributes that were set explicitly (e.g. bold spans of rich text) are marked in the metadata
+/// of the span and are preserved when the style changes. Other attributes are inherited
+/// from the style and are replaced when the style changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleAttrs {
+    family: FamilyOwned,
+    weight: Weight,
+    style: Style,
+    // Relative to the font size.
+    letter_spacing: f32,
+    underline: bool,
+    line_through: bool,
+}
+
+impl Default for StyleAttrs {
+    // Matches the attributes of `Attrs::new()`.
+    fn default() -> Self {
+        Self {
+            family: FamilyOwned::SansSerif,
+            weight: Weight::NORMAL,
+            style: Style::Normal,
+            letter_spacing: 0.0,
+            underline: false,
+            line_through: false,
+        }
+    }
+}
+
+impl StyleAttrs {
+    pub fn new(font: &FontStyle, db: &fontdb::Database) -> Self {
+        Self {
+            family: font.resolve_family(db),
+            weight: Weight(font.font_weight),
+            style: match font.font_slant {
+                FontSlant::Normal => Style::Normal,
+                FontSlant::Italic => Style::Italic,
+                FontSlant::Oblique => Style::Oblique,
+            },
+            letter_spacing: font.letter_spacing_em(),
+            underline: font.text_decoration.underline,
+            line_through: font.text_decoration.line_through,
+        }
+    }
+
+    /// Replaces the attributes that weren't set explicitly with the attributes of `self`.
+    pub fn restyle(&self, attrs: &mut AttrsOwned) {
+        let mut metadata = Metadata(attrs.metadata);
+        if !metadata.has_flag(EXPLICIT_FAMILY) {
+            attrs.family_owned = self.family.clone();
+        }
+        if !metadata.has_flag(EXPLICIT_WEIGHT) {
+            attrs.weight = self.weight;
+        }
+        if !metadata.has_flag(EXPLICIT_STYLE) {
+            attrs.style = self.style;
+        }
+        if !metadata.has_flag(EXPLICIT_LETTER_SPACING) {
+            attrs.letter_spacing_opt =
+                (self.letter_spacing != 0.0).then_some(LetterSpacing(self.letter_spacing));
+        }
+        if !metadata.has_flag(EXPLICIT_UNDERLINE) {
+            metadata = metadata.with_underline(self.underline);
+        }
+        if !metadata.has_flag(EXPLICIT_LINE_THROUGH) {
+            metadata = metadata.with_line_through(self.line_through);
+        }
+        attrs.metadata = metadata.into();
+    }
+
+    /// Applies [restyle](Self::restyle) to the default attributes and all spans of `list`.
+    pub fn restyle_list(&self, list: &AttrsList) -> AttrsList {
+        let mut defaults = AttrsOwned::new(&list.defaults());
+        self.restyle(&mut defaults);
+        let mut output = AttrsList::new(&defaults.as_attrs());
+        for (range, attrs) in list.spans_iter() {
+            let mut attrs = attrs.clone();
+            self.restyle(&mut attrs);
+            output.add_span(range.clone(), &attrs.as_attrs());
+        }
+        output
+    }
+
+    /// Applies the style to attributes that don't take the style into account yet.
+    ///
+    /// Attributes that differ from the defaults of `Attrs::new()` are marked as set explicitly.
+    pub fn apply(&self, attrs: &Attrs) -> AttrsOwned {
+        let mut attrs = AttrsOwned::new(attrs);
+        let default = StyleAttrs::default();
+        let metadata = Metadata(attrs.metadata);
+        let explicit = [
+            (EXPLICIT_FAMILY, attrs.family_owned != default.family),
+            (EXPLICIT_WEIGHT, attrs.weight != default.weight),
+            (EXPLICIT_STYLE, attrs.style != default.style),
+            (
+                EXPLICIT_LETTER_SPACING,
+                attrs
+                    .letter_spacing_opt
+                    .is_some_and(|spacing| spacing.0 != 0.0),
+            ),
+            (EXPLICIT_UNDERLINE, metadata.is_underlined()),
+            (EXPLICIT_LINE_THROUGH, metadata.is_line_through()),
+        ];
+        let metadata = explicit
+            .into_iter()
+            .fold(metadata, |metadata, (flag, is_explicit)| {
+                metadata.with_flag(flag, metadata.has_flag(flag) || is_explicit)
+            });
+        attrs.metadata = metadata.into();
+        self.restyle(&mut attrs);
+        attrs
+    }
+}
+
+#[test]
+fn restyle_keeps_explicit_attrs() {
+    let bold_style = StyleAttrs {
+        weight: Weight::BOLD,
+        underline: true,
+        ..StyleAttrs::default()
+    };
+    let normal_style = StyleAttrs::default();
+
+    let mut inherited = bold_style.apply(&Attrs::new());
+    assert_eq!(inherited.weight, Weight::BOLD);
+    assert!(Metadata(inherited.metadata).is_underlined());
+    normal_style.restyle(&mut inherited);
+    assert_eq!(inherited.weight, Weight::NORMAL);
+    assert!(!Metadata(inherited.metadata).is_underlined());
+
+    // An explicitly bold span in a bold widget stays bold when the style changes.
+    let mut explicit = bold_style.apply(
+        &Attrs::new()
+            .weight(Weight::BOLD)
+            .metadata(Metadata::default().with_link(Some(3)).into()),
+    );
+    normal_style.restyle(&mut explicit);
+    assert_eq!(explicit.weight, Weight::BOLD);
+    assert!(!Metadata(explicit.metadata).is_underlined());
+    assert_eq!(Metadata(explicit.metadata).link(), Some(3));
 }
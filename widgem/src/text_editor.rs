@@ -9,20 +9,20 @@ use {
         impl_widget_base,
         layout::SizeHints,
         shared_window::{ScrollToRectRequest, SetFocusRequest},
-        shortcut::standard_shortcuts,
+        shortcut::{standard_shortcuts, MnemonicText},
         style::{
             common::ComputedElementStyle,
             css::{
                 convert_background_color, convert_font, convert_main_color, is_selection, Element,
             },
-            defaults, get_style, Style,
+            defaults, get_style, FontStyle, Style,
         },
         system::{add_interval, report_error, send_window_request, with_system, ReportError},
         text::{
-            action::Action,
+            action::{self, Action},
             edit::Edit,
             editor::{Editor, EditorDrawStyle},
-            text_without_preedit, Metadata,
+            text_without_preedit, Metadata, StyleAttrs,
         },
         timer::TimerId,
         types::{PhysicalPixels, Point, PpxSuffix, Rect, Size},
@@ -31,8 +31,8 @@ use {
     accesskit::{ActionData, NodeId, Role, TextDirection, TextPosition, TextSelection},
     anyhow::Result,
     cosmic_text::{
-        Affinity, Attrs, AttrsList, AttrsOwned, BorrowedWithFontSystem, Buffer, Cursor, Motion,
-        Shaping, Wrap,
+        Affinity, Attrs, AttrsList, AttrsOwned, BorrowedWithFontSystem, Buffer, Change, Cursor,
+        Motion, Selection, Shaping, Wrap,
     },
     line_straddler::{GlyphStyle, LineGenerator, LineType},
     log::warn,
@@ -57,6 +57,7 @@ use {
 };
 
 struct TextStyle {
+    font: FontStyle,
     font_metrics: cosmic_text::Metrics,
     text_color: Color,
     selected_text_color: Color,
@@ -71,6 +72,7 @@ impl TextStyle {
         LAZY.with(|lazy| {
             Rc::clone(lazy.get_or_init(|| {
                 Rc::new(TextStyle {
+                    font: defaults::font_style(),
                     font_metrics: defaults::font_style().to_metrics(scale),
                     text_color: defaults::text_color(),
                     selected_text_color: defaults::selected_text_color(),
@@ -96,8 +98,10 @@ impl ComputedElementStyle for TextStyle {
                 warn!("selected text background is unspecified");
                 defaults::selected_text_background()
             });
+        let font = convert_font(&rules, Some(&style.root_font_style()));
         Self {
-            font_metrics: convert_font(&rules, Some(&style.root_font_style())).to_metrics(scale),
+            font_metrics: font.to_metrics(scale),
+            font,
             text_color: convert_main_color(&rules).unwrap_or_else(|| style.root_color()),
             selected_text_color,
             selected_text_background,
@@ -108,8 +112,12 @@ impl ComputedElementStyle for TextStyle {
 pub struct Text {
     base: WidgetBaseOf<Self>,
     style: Rc<TextStyle>,
+    // Attributes of `style` that are currently applied to the text.
+    style_attrs: StyleAttrs,
     editor: Editor<'static>,
     pixmap: Option<Pixmap>,
+    // Whether mnemonics were underlined when the pixmap was drawn.
+    pixmap_has_mnemonics_visible: bool,
     size: Size,
     is_multiline: bool,
     is_editable: bool,
@@ -121,6 +129,109 @@ pub struct Text {
     blink_timer: Option<TimerId>,
     selected_text: String,
     accessible_line_id: NodeId,
+    history: History,
+    wrap_width: Option<PhysicalPixels>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Other,
+}
+
+#[derive(Debug)]
+struct HistoryEntry {
+    change: Change,
+    kind: EditKind,
+    cursor_before: Cursor,
+    selection_before: Selection,
+    cursor_after: Cursor,
+    selection_after: Selection,
+}
+
+#[derive(Debug, Default)]
+struct History {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+}
+
+impl History {
+    fn push(&mut self, entry: HistoryEntry) {
+        self.redo.clear();
+        if let Some(last) = self.undo.last_mut() {
+            // Consecutive typing is undone in one step.
+            if entry.kind == EditKind::Typing
+                && last.kind == EditKind::Typing
+                && entry.selection_before == Selection::None
+                && entry.cursor_before == last.cursor_after
+            {
+                last.change.items.extend(entry.change.items);
+                last.cursor_after = entry.cursor_after;
+                last.selection_after = entry.selection_after;
+                return;
+            }
+        }
+        self.undo.push(entry);
+    }
+
+    fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    // Starts recording changes of `editor`. Call `finish_edit` after the changes.
+    fn start_edit(editor: &mut Editor) -> (Cursor, Selection) {
+        editor.start_change();
+        (editor.cursor(), editor.selection())
+    }
+
+    fn finish_edit(
+        &mut self,
+        editor: &mut Editor,
+        kind: EditKind,
+        (cursor_before, selection_before): (Cursor, Selection),
+    ) {
+        let Some(change) = editor.finish_change() else {
+            return;
+        };
+        if change.items.is_empty() {
+            return;
+        }
+        self.push(HistoryEntry {
+            change,
+            kind,
+            cursor_before,
+            selection_before,
+            cursor_after: editor.cursor(),
+            selection_after: editor.selection(),
+        });
+    }
+
+    // Returns `false` if there is nothing to undo.
+    fn undo(&mut self, editor: &mut Editor) -> bool {
+        let Some(entry) = self.undo.pop() else {
+            return false;
+        };
+        let mut change = entry.change.clone();
+        change.reverse();
+        editor.apply_change(&change);
+        editor.set_cursor(entry.cursor_before);
+        editor.set_selection(entry.selection_before);
+        self.redo.push(entry);
+        true
+    }
+
+    // Returns `false` if there is nothing to redo.
+    fn redo(&mut self, editor: &mut Editor) -> bool {
+        let Some(entry) = self.redo.pop() else {
+            return false;
+        };
+        editor.apply_change(&entry.change);
+        editor.set_cursor(entry.cursor_after);
+        editor.set_selection(entry.selection_after);
+        self.undo.push(entry);
+        true
+    }
 }
 
 // TODO: get system setting
@@ -156,6 +267,10 @@ impl Text {
         self
     }
 
+    pub fn is_editable(&self) -> bool {
+        self.is_editable
+    }
+
     pub fn set_multiline(&mut self, multiline: bool) -> &mut Self {
         self.is_multiline = multiline;
         if !multiline {
@@ -177,9 +292,26 @@ impl Text {
 
     pub fn set_host_style_element(&mut self, element: Element) -> &mut Self {
         self.host_element = element;
+        self.refresh_style();
+        self
+    }
+
+    fn refresh_style(&mut self) {
         self.style = get_style(&self.host_element, self.base.scale());
+        let style_attrs =
+            with_system(|system| StyleAttrs::new(&self.style.font, system.font_system.db()));
+        if style_attrs != self.style_attrs {
+            self.editor.with_buffer_mut(|buffer| {
+                for line in &mut buffer.lines {
+                    let attrs_list = style_attrs.restyle_list(line.attrs_list());
+                    line.set_attrs_list(attrs_list);
+                }
+            });
+            self.style_attrs = style_attrs;
+            self.editor.set_redraw(true);
+            self.base.update();
+        }
         self.set_font_metrics(self.style.font_metrics);
-        self
     }
 
     fn sanitize(&self, text: &str) -> String {
@@ -190,6 +322,10 @@ impl Text {
         }
     }
 
+    pub fn font_metrics(&self) -> cosmic_text::Metrics {
+        self.editor.with_buffer(|buffer| buffer.metrics())
+    }
+
     pub fn set_font_metrics(&mut self, metrics: cosmic_text::Metrics) {
         with_system(|system| {
             self.editor
@@ -199,6 +335,19 @@ impl Text {
         self.request_scroll();
     }
 
+    /// Sets the width at which the lines are wrapped.
+    ///
+    /// If `None`, lines are only broken at paragraph separators and the width of the widget
+    /// is determined by the longest line. Has no effect if wrapping is disabled with [set_wrap](Self::set_wrap).
+    pub fn set_wrap_width(&mut self, width: Option<PhysicalPixels>) {
+        if self.wrap_width == width {
+            return;
+        }
+        self.wrap_width = width;
+        self.adjust_size();
+        self.request_scroll();
+    }
+
     pub fn set_wrap(&mut self, wrap: Wrap) {
         with_system(|system| {
             self.editor
@@ -210,7 +359,7 @@ impl Text {
 
     pub fn handle_host_focus_in(&mut self, reason: FocusReason) -> Result<()> {
         self.is_host_focused = true;
-        if reason == FocusReason::Tab {
+        if matches!(reason, FocusReason::Tab | FocusReason::Mnemonic) {
             self.action(Action::SelectAll);
         }
         self.reset_blink_timer();
@@ -281,12 +430,12 @@ impl Text {
                 select: false,
             });
         } else if shortcuts.delete.matches(&event) {
-            self.action(Action::Delete);
+            self.edit(EditKind::Other, |this| this.action(Action::Delete));
         } else if shortcuts.backspace.matches(&event) {
-            self.action(Action::Backspace);
+            self.edit(EditKind::Other, |this| this.action(Action::Backspace));
         } else if shortcuts.cut.matches(&event) {
             self.copy_to_clipboard();
-            self.action(Action::Delete);
+            self.edit(EditKind::Other, |this| this.action(Action::Delete));
         } else if shortcuts.copy.matches(&event) {
             self.copy_to_clipboard();
         } else if shortcuts.paste.matches(&event) {
@@ -294,14 +443,14 @@ impl Text {
             match r {
                 Ok(text) => {
                     let text = self.sanitize(&text);
-                    self.insert_string(&text, None);
+                    self.edit(EditKind::Other, |this| this.insert_string(&text, None));
                 }
                 Err(err) => report_error(err),
             }
         } else if shortcuts.undo.matches(&event) {
-            // TODO
+            self.undo();
         } else if shortcuts.redo.matches(&event) {
-            // TODO
+            self.redo();
         } else if shortcuts.select_all.matches(&event) {
             self.action(Action::SelectAll);
         } else if shortcuts.deselect.matches(&event) {
@@ -358,9 +507,49 @@ impl Text {
                 select: true,
             });
         } else if shortcuts.delete_start_of_word.matches(&event) {
-            self.action(Action::DeleteStartOfWord);
+            self.edit(EditKind::Other, |this| {
+                this.action(Action::DeleteStartOfWord)
+            });
         } else if shortcuts.delete_end_of_word.matches(&event) {
-            self.action(Action::DeleteEndOfWord);
+            self.edit(EditKind::Other, |this| this.action(Action::DeleteEndOfWord));
+        } else if self.is_multiline && shortcuts.insert_paragraph_separator.matches(&event) {
+            self.edit(EditKind::Other, |this| this.action(Action::Enter));
+        } else if self.is_multiline && shortcuts.move_to_previous_line.matches(&event) {
+            self.vertical_motion(Motion::Up, false);
+        } else if self.is_multiline && shortcuts.move_to_next_line.matches(&event) {
+            self.vertical_motion(Motion::Down, false);
+        } else if self.is_multiline && shortcuts.select_previous_line.matches(&event) {
+            self.vertical_motion(Motion::Up, true);
+        } else if self.is_multiline && shortcuts.select_next_line.matches(&event) {
+            self.vertical_motion(Motion::Down, true);
+        } else if self.is_multiline && shortcuts.move_to_previous_page.matches(&event) {
+            self.vertical_motion(Motion::Vertical(-self.page_height()), false);
+        } else if self.is_multiline && shortcuts.move_to_next_page.matches(&event) {
+            self.vertical_motion(Motion::Vertical(self.page_height()), false);
+        } else if self.is_multiline && shortcuts.select_previous_page.matches(&event) {
+            self.vertical_motion(Motion::Vertical(-self.page_height()), true);
+        } else if self.is_multiline && shortcuts.select_next_page.matches(&event) {
+            self.vertical_motion(Motion::Vertical(self.page_height()), true);
+        } else if shortcuts.move_to_start_of_document.matches(&event) {
+            self.action(Action::Motion {
+                motion: action::Motion::DocumentStart,
+                select: false,
+            });
+        } else if shortcuts.move_to_end_of_document.matches(&event) {
+            self.action(Action::Motion {
+                motion: action::Motion::DocumentEnd,
+                select: false,
+            });
+        } else if shortcuts.select_start_of_document.matches(&event) {
+            self.action(Action::Motion {
+                motion: action::Motion::DocumentStart,
+                select: true,
+            });
+        } else if shortcuts.select_end_of_document.matches(&event) {
+            self.action(Action::Motion {
+                motion: action::Motion::DocumentEnd,
+                select: true,
+            });
         } else if let Some(text) = &event.info.text {
             if let Key::Named(key) = &event.info.logical_key {
                 if [NamedKey::Tab, NamedKey::Enter, NamedKey::Escape].contains(key) {
@@ -368,7 +557,7 @@ impl Text {
                 }
             }
             let text = self.sanitize(text);
-            self.insert_string(&text, None);
+            self.edit(EditKind::Typing, |this| this.insert_string(&text, None));
         } else {
             return Ok(false);
         }
@@ -380,6 +569,24 @@ impl Text {
         Ok(true)
     }
 
+    fn vertical_motion(&mut self, motion: Motion, select: bool) {
+        self.shape_as_needed();
+        self.action(Action::Motion {
+            motion: motion.into(),
+            select,
+        });
+    }
+
+    // Height of the visible part of the text, in pixels.
+    fn page_height(&self) -> i32 {
+        let line_height = self.line_height().ceil() as i32;
+        let visible_height = self
+            .base
+            .visible_rect()
+            .map_or(0, |rect| rect.size_y().to_i32());
+        max(line_height, visible_height - line_height)
+    }
+
     pub fn handle_host_ime(&mut self, event: InputMethodEvent) -> Result<bool> {
         if !self.is_editable {
             return Ok(false);
@@ -395,7 +602,10 @@ impl Text {
                 });
             }
             Ime::Commit(string) => {
-                self.editor.insert_string(&self.sanitize(&string), None);
+                let text = self.sanitize(&string);
+                self.edit(EditKind::Typing, |this| {
+                    this.editor.insert_string(&text, None)
+                });
             }
             Ime::Disabled => {}
         }
@@ -407,13 +617,18 @@ impl Text {
         Ok(true)
     }
 
+    /// Replaces the content of the editor.
+    ///
+    /// This also clears the undo history.
     pub fn set_text(&mut self, text: impl Display, attrs: Attrs) {
+        self.history.clear();
+        let attrs = self.style_attrs.apply(&attrs);
         with_system(|system| {
             self.editor.with_buffer_mut(|buffer| {
                 buffer.set_text(
                     &mut system.font_system,
                     &text.to_string(),
-                    &attrs,
+                    &attrs.as_attrs(),
                     Shaping::Advanced,
                 )
             });
@@ -425,10 +640,112 @@ impl Text {
         self.request_scroll();
     }
 
+    /// Replaces the content of the editor with spans of text that have different attributes.
+    ///
+    /// This also clears the undo history.
+    pub fn set_rich_text<'r, 's>(&mut self, spans: impl IntoIterator<Item = (&'s str, Attrs<'r>)>) {
+        self.history.clear();
+        let spans = spans
+            .into_iter()
+            .map(|(text, attrs)| (text, self.style_attrs.apply(&attrs)))
+            .collect::<Vec<_>>();
+        let default_attrs = self.style_attrs.apply(&Attrs::new());
+        with_system(|system| {
+            self.editor.with_buffer_mut(|buffer| {
+                buffer.set_rich_text(
+                    &mut system.font_system,
+                    spans.iter().map(|(text, attrs)| (*text, attrs.as_attrs())),
+                    &default_attrs.as_attrs(),
+                    Shaping::Advanced,
+                    None,
+                )
+            });
+        });
+        self.adjust_size();
+        self.after_change();
+        self.reset_blink_timer();
+        self.base.update();
+        self.request_scroll();
+    }
+
+    /// Replaces the content of the editor with a text that may have a mnemonic.
+    ///
+    /// The mnemonic character is underlined.
+    pub fn set_mnemonic_text(&mut self, text: &MnemonicText, attrs: Attrs) {
+        let Some(index) = text.mnemonic else {
+            self.set_text(&text.text, attrs);
+            return;
+        };
+        let end = index + text.mnemonic_char().map_or(0, char::len_utf8);
+        let mnemonic_attrs = attrs
+            .clone()
+            .metadata(Metadata(attrs.metadata).with_mnemonic(true).into());
+        self.set_rich_text([
+            (&text.text[..index], attrs.clone()),
+            (&text.text[index..end], mnemonic_attrs),
+            (&text.text[end..], attrs),
+        ]);
+    }
+
     pub fn text(&self) -> String {
         self.editor.with_buffer(text_without_preedit)
     }
 
+    /// Inserts `text` at the cursor, replacing the selected text.
+    ///
+    /// Unlike [set_text](Self::set_text), the change is recorded in the undo history.
+    pub fn insert_text(&mut self, text: &str) {
+        let text = self.sanitize(text);
+        self.edit(EditKind::Other, |this| this.insert_string(&text, None));
+    }
+
+    // Records changes made by `f` in the undo history.
+    fn edit(&mut self, kind: EditKind, f: impl FnOnce(&mut Self)) {
+        let before = History::start_edit(&mut self.editor);
+        f(self);
+        self.history.finish_edit(&mut self.editor, kind, before);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Reverts the last edit made by the user.
+    pub fn undo(&mut self) {
+        if self.editor.preedit_range().is_some() {
+            return;
+        }
+        if self.history.undo(&mut self.editor) {
+            self.after_history_change();
+        }
+    }
+
+    /// Reapplies the last edit reverted by [undo](Self::undo).
+    pub fn redo(&mut self) {
+        if self.editor.preedit_range().is_some() {
+            return;
+        }
+        if self.history.redo(&mut self.editor) {
+            self.after_history_change();
+        }
+    }
+
+    /// Forgets all edits, so that they can't be undone.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    fn after_history_change(&mut self) {
+        self.adjust_size();
+        self.after_change();
+        self.base.update();
+        self.request_scroll();
+    }
+
     fn after_change(&mut self) {
         let new_selected_text = self.selected_text().unwrap_or_default();
         if new_selected_text != self.selected_text {
@@ -481,7 +798,7 @@ impl Text {
         .or_report_err();
         if let Some(text) = text {
             let text = self.sanitize(&text);
-            self.insert_string(&text, None);
+            self.edit(EditKind::Other, |this| this.insert_string(&text, None));
         }
     }
 
@@ -694,6 +1011,28 @@ impl Text {
         self.size
     }
 
+    /// Returns the size of the text if it was wrapped at `wrap_width`, without changing
+    /// the current layout.
+    ///
+    /// If `wrap_width` is `None`, returns the size of the text without wrapping.
+    pub fn measure(&mut self, wrap_width: Option<PhysicalPixels>) -> Size {
+        let current_size = self.size;
+        with_system(|system| {
+            self.editor.with_buffer_mut(|buffer| {
+                let mut buffer = buffer.borrow_with(&mut system.font_system);
+                let size = match wrap_width {
+                    Some(wrap_width) => wrapped_text_size(&mut buffer, wrap_width),
+                    None => unrestricted_text_size(&mut buffer),
+                };
+                buffer.set_size(
+                    Some(current_size.x().to_i32() as f32),
+                    Some(current_size.y().to_i32() as f32),
+                );
+                size
+            })
+        })
+    }
+
     pub fn size_x(&self) -> PhysicalPixels {
         self.size.x()
     }
@@ -712,13 +1051,26 @@ impl Text {
         self.editor.redraw()
     }
 
+    fn are_mnemonics_visible(&self) -> bool {
+        !with_system(|system| system.config.mnemonics_on_alt)
+            || self
+                .base
+                .window
+                .as_ref()
+                .is_some_and(|window| window.modifiers().alt_key())
+    }
+
     pub fn is_mouse_interaction_forbidden(&self) -> bool {
         self.forbid_mouse_interaction
     }
 
     // TODO: private
     pub fn pixmap(&mut self) -> &Pixmap {
-        if self.pixmap.is_none() || self.needs_redraw() {
+        let mnemonics_visible = self.are_mnemonics_visible();
+        if self.pixmap.is_none()
+            || self.needs_redraw()
+            || self.pixmap_has_mnemonics_visible != mnemonics_visible
+        {
             let (buffer_width, buffer_height) = self.editor.with_buffer(|buffer| buffer.size());
             let size_x = max(1, buffer_width.unwrap_or(0.).ceil() as u32);
             let size_y = max(1, buffer_height.unwrap_or(0.).ceil() as u32);
@@ -750,44 +1102,56 @@ impl Text {
                     },
                 );
             });
-            let mut alg = LineGenerator::new(LineType::Underline);
+            let mut underline_alg = LineGenerator::new(LineType::Underline);
+            let mut line_through_alg = LineGenerator::new(LineType::StrikeThrough);
             let mut lines = Vec::new();
             let line_height = self
                 .editor
                 .with_buffer(|buffer| buffer.metrics().line_height);
             // TODO: determine from glyph width?
             let stroke_width = 1.0;
+            let align_line_y =
+                |line_y: f32| (line_y + stroke_width / 2.0).round() - stroke_width / 2.0;
             self.editor.with_buffer(|buffer| {
                 for run in buffer.layout_runs() {
                     let underline_space = line_height - run.line_y;
-                    let line_y = run.line_top + underline_space / 2.0;
-                    let line_y = (line_y + stroke_width / 2.0).round() - stroke_width / 2.0;
+                    let underline_y = align_line_y(run.line_top + underline_space / 2.0);
                     for glyph in run.glyphs {
-                        if Metadata(glyph.metadata).is_preedit() {
-                            let color = glyph
-                                .color_opt
-                                .unwrap_or(convert_color(self.style.text_color));
-                            let glyph = line_straddler::Glyph {
-                                line_y,
-                                font_size: glyph.font_size,
-                                width: glyph.w,
-                                x: glyph.x,
-                                style: GlyphStyle {
-                                    boldness: 1,
-                                    color: line_straddler::Color::rgba(
-                                        color.r(),
-                                        color.g(),
-                                        color.b(),
-                                        color.a(),
-                                    ),
-                                },
-                            };
-                            lines.extend(alg.add_glyph(glyph));
+                        let metadata = Metadata(glyph.metadata);
+                        let color = glyph
+                            .color_opt
+                            .unwrap_or(convert_color(self.style.text_color));
+                        let line_glyph = |line_y: f32| line_straddler::Glyph {
+                            line_y,
+                            font_size: glyph.font_size,
+                            width: glyph.w,
+                            x: glyph.x,
+                            style: GlyphStyle {
+                                boldness: 1,
+                                color: line_straddler::Color::rgba(
+                                    color.r(),
+                                    color.g(),
+                                    color.b(),
+                                    color.a(),
+                                ),
+                            },
+                        };
+                        if metadata.is_preedit()
+                            || metadata.is_underlined()
+                            || (metadata.is_mnemonic() && mnemonics_visible)
+                        {
+                            lines.extend(underline_alg.add_glyph(line_glyph(underline_y)));
+                        }
+                        if metadata.is_line_through() {
+                            // Approximately the middle of lowercase letters.
+                            let line_y = align_line_y(run.line_y - glyph.font_size * 0.3);
+                            lines.extend(line_through_alg.add_glyph(line_glyph(line_y)));
                         }
                     }
                 }
             });
-            lines.extend(alg.pop_line());
+            lines.extend(underline_alg.pop_line());
+            lines.extend(line_through_alg.pop_line());
             for line in lines {
                 let mut path = PathBuilder::new();
                 path.move_to(line.start_x, line.y);
@@ -812,11 +1176,26 @@ impl Text {
                 );
             }
             self.pixmap = Some(pixmap);
+            self.pixmap_has_mnemonics_visible = mnemonics_visible;
             self.editor.set_redraw(false);
         }
         self.pixmap.as_ref().expect("created above")
     }
 
+    // Returns metadata of the glyph at `pos`.
+    pub(crate) fn metadata_at(&self, pos: Point) -> Option<Metadata> {
+        let x = pos.x().to_i32() as f32;
+        let y = pos.y().to_i32() as f32;
+        self.editor.with_buffer(|buffer| {
+            buffer
+                .layout_runs()
+                .filter(|run| y >= run.line_top && y < run.line_top + run.line_height)
+                .flat_map(|run| run.glyphs.iter())
+                .find(|glyph| x >= glyph.x && x < glyph.x + glyph.w)
+                .map(|glyph| Metadata(glyph.metadata))
+        })
+    }
+
     pub fn cursor_position(&self) -> Option<Point> {
         self.editor
             .cursor_position()
@@ -879,7 +1258,8 @@ impl Text {
                 cursor: None,
                 attrs: None,
             });
-            self.insert_string(&text, None);
+            // The committed text is recorded like a regular IME commit.
+            self.edit(EditKind::Typing, |this| this.insert_string(&text, None));
             if let Some(window) = &self.base.window {
                 window.cancel_ime_preedit();
             } else {
@@ -897,10 +1277,16 @@ impl Text {
     }
 
     fn adjust_size(&mut self) {
+        let wrap_width = self.wrap_width;
         let size = with_system(|system| {
             self.editor.with_buffer_mut(|buffer| {
-                let new_size =
-                    unrestricted_text_size(&mut buffer.borrow_with(&mut system.font_system));
+                let new_size = match wrap_width {
+                    Some(wrap_width) if buffer.wrap() != Wrap::None => wrapped_text_size(
+                        &mut buffer.borrow_with(&mut system.font_system),
+                        wrap_width,
+                    ),
+                    _ => unrestricted_text_size(&mut buffer.borrow_with(&mut system.font_system)),
+                };
                 buffer.set_size(
                     &mut system.font_system,
                     Some(new_size.x().to_i32() as f32),
@@ -1007,8 +1393,10 @@ impl Widget for Text {
         let mut t = Self {
             editor,
             pixmap: None,
+            pixmap_has_mnemonics_visible: false,
             host_element: Element::new("_unknown_".into()),
             style,
+            style_attrs: StyleAttrs::default(),
             size: Size::default(),
             is_multiline: true,
             is_editable: false,
@@ -1019,6 +1407,8 @@ impl Widget for Text {
             blink_timer: None,
             selected_text: String::new(),
             accessible_line_id: accessible::new_accessible_node_id(),
+            history: History::default(),
+            wrap_width: None,
             base,
         };
         if let Some(window) = &t.base.window {
@@ -1176,13 +1566,7 @@ impl Widget for Text {
         };
         window.accessibility_node_updated(self.accessible_line_id, Some(line_node));
 
-        // TODO: configurable role
-        let role = if self.is_multiline {
-            Role::TextInput
-        } else {
-            Role::MultilineTextInput
-        };
-        let mut node = accesskit::Node::new(role);
+        let mut node = accesskit::Node::new(accessible_role(self.is_multiline));
         // TODO: use label widget and `Node::set_labeled_by`
         node.set_label("some input");
         node.add_action(accesskit::Action::Click);
@@ -1208,7 +1592,7 @@ impl Widget for Text {
     }
 
     fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
-        self.style = get_style(&self.host_element, self.base.scale());
+        self.refresh_style();
         Ok(())
     }
 }
@@ -1218,7 +1602,10 @@ const MEASURE_MAX_SIZE: f32 = 10_000.;
 fn unrestricted_text_size(buffer: &mut BorrowedWithFontSystem<'_, Buffer>) -> Size {
     buffer.set_size(Some(MEASURE_MAX_SIZE), Some(MEASURE_MAX_SIZE));
     buffer.shape_until_scroll(false);
-    let height = (buffer.lines.len() as f32 * buffer.metrics().line_height).ceil() as i32;
+    let height = max(
+        (buffer.lines.len() as f32 * buffer.metrics().line_height).ceil() as i32,
+        layout_runs_height(buffer),
+    );
     let width = buffer
         .layout_runs()
         .map(|run| run.line_w.ceil() as i32)
@@ -1231,7 +1618,40 @@ fn unrestricted_text_size(buffer: &mut BorrowedWithFontSystem<'_, Buffer>) -> Si
     )
 }
 
-fn convert_color(color: Color) -> cosmic_text::Color {
+fn wrapped_text_size(
+    buffer: &mut BorrowedWithFontSystem<'_, Buffer>,
+    wrap_width: PhysicalPixels,
+) -> Size {
+    buffer.set_size(Some(wrap_width.to_i32() as f32), Some(MEASURE_MAX_SIZE));
+    buffer.shape_until_scroll(false);
+    let num_runs = max(1, buffer.layout_runs().count());
+    let height = max(
+        (num_runs as f32 * buffer.metrics().line_height).ceil() as i32,
+        layout_runs_height(buffer),
+    );
+    Size::new(wrap_width, PhysicalPixels::from_i32(height))
+}
+
+// Returns the bottom of the last line. Lines can have different heights if the text
+// contains spans with different font sizes.
+fn layout_runs_height(buffer: &BorrowedWithFontSystem<'_, Buffer>) -> i32 {
+    buffer
+        .layout_runs()
+        .map(|run| (run.line_top + run.line_height).ceil() as i32)
+        .max()
+        .unwrap_or(0)
+}
+
+// TODO: configurable role
+fn accessible_role(is_multiline: bool) -> Role {
+    if is_multiline {
+        Role::MultilineTextInput
+    } else {
+        Role::TextInput
+    }
+}
+
+pub(crate) fn convert_color(color: Color) -> cosmic_text::Color {
     let c = color.to_color_u8();
     cosmic_text::Color::rgba(c.red(), c.green(), c.blue(), c.alpha())
 }
@@ -1243,3 +1663,98 @@ impl Drop for Text {
         }
     }
 }
+
+#[cfg(test)]
+fn record_insert(editor: &mut Editor, history: &mut History, kind: EditKind, text: &str) {
+    let before = History::start_edit(editor);
+    editor.insert_string(text, None);
+    history.finish_edit(editor, kind, before);
+}
+
+#[test]
+fn undo_redo_grouping() {
+    let mut editor = Editor::new(Buffer::new_empty(cosmic_text::Metrics::new(14.0, 20.0)));
+    let mut history = History::default();
+    let text = |editor: &Editor| editor.with_buffer(text_without_preedit);
+
+    record_insert(&mut editor, &mut history, EditKind::Typing, "ab");
+    record_insert(&mut editor, &mut history, EditKind::Typing, "c");
+    record_insert(&mut editor, &mut history, EditKind::Other, "XY");
+    record_insert(&mut editor, &mut history, EditKind::Typing, "d");
+    editor.set_cursor(Cursor::new(0, 0));
+    record_insert(&mut editor, &mut history, EditKind::Typing, "e");
+    assert_eq!(text(&editor), "eabcXYd");
+
+    // Typing at another position is a separate step.
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "abcXYd");
+    // Typing after a paste is a separate step.
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "abcXY");
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "abc");
+    // Consecutive typing is undone in one step.
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "");
+    assert!(!history.undo(&mut editor));
+
+    assert!(history.redo(&mut editor));
+    assert_eq!(text(&editor), "abc");
+    assert_eq!(editor.cursor(), Cursor::new(0, 3));
+    assert!(history.redo(&mut editor));
+    assert_eq!(text(&editor), "abcXY");
+
+    // A new edit discards the redo history.
+    record_insert(&mut editor, &mut history, EditKind::Other, "Z");
+    assert!(!history.redo(&mut editor));
+    assert_eq!(text(&editor), "abcXYZ");
+}
+
+#[test]
+fn undo_ime_commit() {
+    let mut font_system = cosmic_text::FontSystem::new_with_locale_and_db(
+        "en-US".into(),
+        cosmic_text::fontdb::Database::new(),
+    );
+    let mut editor = Editor::new(Buffer::new_empty(cosmic_text::Metrics::new(14.0, 20.0)));
+    let mut history = History::default();
+    let text = |editor: &Editor| editor.with_buffer(text_without_preedit);
+
+    record_insert(&mut editor, &mut history, EditKind::Other, "hello ");
+    editor.action(
+        &mut font_system,
+        Action::SetPreedit {
+            preedit: "wor".into(),
+            cursor: None,
+            attrs: None,
+        },
+    );
+    assert_eq!(editor.preedit_text().as_deref(), Some("wor"));
+    // Same as `Text::interrupt_preedit`: the preedit is removed and committed as typed text.
+    editor.action(
+        &mut font_system,
+        Action::SetPreedit {
+            preedit: String::new(),
+            cursor: None,
+            attrs: None,
+        },
+    );
+    record_insert(&mut editor, &mut history, EditKind::Typing, "wor");
+    // Regular IME commit.
+    record_insert(&mut editor, &mut history, EditKind::Typing, "ld");
+    assert_eq!(text(&editor), "hello world");
+
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "hello ");
+    assert!(history.redo(&mut editor));
+    assert_eq!(text(&editor), "hello world");
+    assert!(history.undo(&mut editor));
+    assert!(history.undo(&mut editor));
+    assert_eq!(text(&editor), "");
+}
+
+#[test]
+fn text_area_accessible_role() {
+    assert_eq!(accessible_role(true), Role::MultilineTextInput);
+    assert_eq!(accessible_role(false), Role::TextInput);
+}
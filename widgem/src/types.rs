@@ -207,6 +207,15 @@ impl From<Point> for winit::dpi::PhysicalPosition<i32> {
     }
 }
 
+impl From<winit::dpi::PhysicalPosition<i32>> for Point {
+    fn from(value: winit::dpi::PhysicalPosition<i32>) -> Self {
+        Point::new(
+            PhysicalPixels::from_i32(value.x),
+            PhysicalPixels::from_i32(value.y),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Size {
     x: PhysicalPixels,
@@ -343,12 +352,123 @@ impl Rect {
     }
 }
 
+/// A value for each side of a box (e.g. padding or border width).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sides<T> {
+    pub left: T,
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+}
+
+impl<T> Sides<T> {
+    pub const fn new(left: T, top: T, right: T, bottom: T) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Sides<U> {
+        Sides::new(f(self.left), f(self.top), f(self.right), f(self.bottom))
+    }
+}
+
+impl<T: Copy> Sides<T> {
+    pub const fn all(value: T) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Returns `true` if all sides have the same value.
+    pub fn is_uniform(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.left == self.top && self.left == self.right && self.left == self.bottom
+    }
+}
+
+impl Sides<PhysicalPixels> {
+    pub const ZERO: Self = Self::all(PhysicalPixels::ZERO);
+
+    /// Sum of the left and right values.
+    pub fn sum_x(&self) -> PhysicalPixels {
+        self.left + self.right
+    }
+
+    /// Sum of the top and bottom values.
+    pub fn sum_y(&self) -> PhysicalPixels {
+        self.top + self.bottom
+    }
+}
+
+impl<T: Add<Output = T>> Add for Sides<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            left: self.left + rhs.left,
+            top: self.top + rhs.top,
+            right: self.right + rhs.right,
+            bottom: self.bottom + rhs.bottom,
+        }
+    }
+}
+
+/// A value for each corner of a box (e.g. border radius).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Corners<T> {
+    pub top_left: T,
+    pub top_right: T,
+    pub bottom_right: T,
+    pub bottom_left: T,
+}
+
+impl<T> Corners<T> {
+    pub const fn new(top_left: T, top_right: T, bottom_right: T, bottom_left: T) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Corners<U> {
+        Corners::new(
+            f(self.top_left),
+            f(self.top_right),
+            f(self.bottom_right),
+            f(self.bottom_left),
+        )
+    }
+}
+
+impl<T: Copy> Corners<T> {
+    pub const fn all(value: T) -> Self {
+        Self::new(value, value, value, value)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Axis {
     X,
     Y,
 }
 
+impl Axis {
+    /// Returns the name of the axis as used in style attribute selectors
+    /// (e.g. `slider[axis="y"]`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Axis::X => "x",
+            Axis::Y => "y",
+        }
+    }
+}
+
 impl TryFrom<Rect> for tiny_skia::Rect {
     type Error = anyhow::Error;
 
@@ -6,18 +6,20 @@ use {
             AccessibilityActionEvent, FocusReason, KeyboardInputEvent, MouseInputEvent,
             MouseMoveEvent, StyleChangeEvent,
         },
+        icon::Icon,
         impl_widget_base,
         shared_window::SetFocusRequest,
+        shortcut::{KeyCombinations, MnemonicText, Shortcut, ShortcutId, ShortcutScope},
         style::{
             common::ComputedElementStyle,
-            css::{convert_content_url, convert_zoom, Element, PseudoClass},
+            css::{convert_content_url, convert_icon_name, convert_zoom, Element, PseudoClass},
             get_style, Style,
         },
-        system::{add_interval, add_timer, send_window_request, with_system},
+        system::{add_interval, add_timer, send_window_request, with_system, ReportError},
         text_editor::Text,
         timer::TimerId,
     },
-    accesskit::{Action, Role},
+    accesskit::{Action, Role, Toggled},
     anyhow::Result,
     cosmic_text::Attrs,
     log::warn,
@@ -30,15 +32,34 @@ use {
     },
 };
 
+/// Position of the icon relative to the text of a [Button].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconPosition {
+    #[default]
+    Left,
+    Top,
+}
+
 pub struct Button {
     auto_repeat: bool,
     is_mouse_leave_sensitive: bool,
     trigger_on_press: bool,
     on_triggered: CallbackVec<()>,
+    is_checkable: bool,
+    is_checked: bool,
+    is_default: bool,
+    on_toggled: CallbackVec<bool>,
     is_pressed: bool,
     was_pressed_but_moved_out: bool,
     auto_repeat_delay_timer: Option<TimerId>,
     auto_repeat_interval: Option<TimerId>,
+    mnemonic: MnemonicText,
+    mnemonic_shortcut: Option<ShortcutId>,
+    shortcut: Option<(KeyCombinations, ShortcutId)>,
+    icon: Option<Icon>,
+    icon_position: IconPosition,
+    // The last rendered icon with its scale and disabled state.
+    rendered_icon: Option<(f32, bool, Rc<Pixmap>)>,
     base: WidgetBaseOf<Self>,
     style: Rc<ComputedButtonStyle>,
 }
@@ -62,13 +83,58 @@ impl Button {
         self.base.get_child_mut::<Text>(1).unwrap()
     }
 
+    /// Sets the text of the button.
+    ///
+    /// An ampersand marks the next character as a mnemonic (e.g. `"&Save"`):
+    /// Alt and this character trigger the button. Use `&&` to display an ampersand.
     pub fn set_text(&mut self, text: impl Display) -> &mut Self {
-        self.text_widget_mut().set_text(text, Attrs::new());
+        self.mnemonic = MnemonicText::parse(&text.to_string());
+        let mnemonic = self.mnemonic.clone();
+        self.text_widget_mut()
+            .set_mnemonic_text(&mnemonic, Attrs::new());
+        if let Some(id) = self.mnemonic_shortcut.take() {
+            self.base.remove_shortcut(id);
+        }
+        if let Some(key_combinations) = mnemonic.key_combinations() {
+            let callback = self.callback(|this, ()| {
+                this.activate_mnemonic();
+                Ok(())
+            });
+            let id = self.base.add_shortcut(Shortcut::new(
+                key_combinations,
+                ShortcutScope::Window,
+                callback,
+            ));
+            self.mnemonic_shortcut = Some(id);
+        }
         self.base.size_hint_changed();
         self.base.update();
         self
     }
 
+    fn activate_mnemonic(&mut self) {
+        if !self.base.is_enabled() || self.base.size().is_none() {
+            return;
+        }
+        if self.base.is_focusable() && !self.base.is_focused() {
+            if let Some(window_id) = self.base.window_id() {
+                send_window_request(
+                    window_id,
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        reason: FocusReason::Mnemonic,
+                    },
+                );
+            }
+        }
+        self.trigger();
+    }
+
+    /// Returns the text of the button without mnemonic markers.
+    pub fn text(&self) -> &str {
+        &self.mnemonic.text
+    }
+
     pub fn set_text_visible(&mut self, value: bool) -> &mut Self {
         self.text_widget_mut().set_visible(value);
         self.base.size_hint_changed();
@@ -91,19 +157,131 @@ impl Button {
         self
     }
 
-    // TODO: set_icon should preferably work with SVG icons
-    // pub fn set_icon(&mut self, icon: Option<Rc<Pixmap>>) {
-    //     self.icon = icon;
-    //     self.common.size_hint_changed();
-    //     self.common.update();
-    // }
+    /// Sets the icon displayed next to the text.
+    ///
+    /// If no icon is set, the icon is taken from the `content` or `icon` property in CSS.
+    /// Use [set_text_visible](Self::set_text_visible) to display only the icon.
+    pub fn set_icon(&mut self, icon: Option<Icon>) -> &mut Self {
+        self.icon = icon;
+        self.rendered_icon = None;
+        self.refresh_icon();
+        self
+    }
+
+    pub fn icon(&self) -> Option<&Icon> {
+        self.icon.as_ref()
+    }
+
+    pub fn set_icon_position(&mut self, position: IconPosition) -> &mut Self {
+        if self.icon_position == position {
+            return self;
+        }
+        self.icon_position = position;
+        let (column, row) = match position {
+            IconPosition::Left => (1, 0),
+            IconPosition::Top => (0, 1),
+        };
+        self.text_widget_mut().set_column(column).set_row(row);
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn icon_position(&self) -> IconPosition {
+        self.icon_position
+    }
+
+    /// Makes the button toggle its checked state when it's triggered.
+    pub fn set_checkable(&mut self, value: bool) -> &mut Self {
+        self.is_checkable = value;
+        if !value {
+            self.set_checked(false);
+        }
+        self.set_style_attribute("checkable".into(), value.then(String::new));
+        self.base.update();
+        self
+    }
+
+    pub fn is_checkable(&self) -> bool {
+        self.is_checkable
+    }
+
+    pub fn set_checked(&mut self, value: bool) -> &mut Self {
+        let value = value && self.is_checkable;
+        if self.is_checked == value {
+            return self;
+        }
+        self.is_checked = value;
+        self.set_pseudo_class(PseudoClass::Checked, value);
+        self.base.update();
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+
+    /// Marks the button as the default button of a dialog (the one triggered by Enter).
+    ///
+    /// It only affects the style of the button (`:default` pseudo-class).
+    pub fn set_default(&mut self, value: bool) -> &mut Self {
+        self.is_default = value;
+        self.set_pseudo_class(PseudoClass::Default, value);
+        self
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+
+    /// Registers a shortcut that triggers the button.
+    ///
+    /// The shortcut works while the button's window is focused. It's reported in
+    /// the accessible description of the button.
+    pub fn set_shortcut(&mut self, shortcut: Option<KeyCombinations>) -> &mut Self {
+        if let Some((_, id)) = self.shortcut.take() {
+            self.base.remove_shortcut(id);
+        }
+        if let Some(key_combinations) = shortcut {
+            let callback = self.callback(|this, ()| {
+                if this.base.is_enabled() {
+                    this.trigger();
+                }
+                Ok(())
+            });
+            let id = self.base.add_shortcut(Shortcut::new(
+                key_combinations.clone(),
+                ShortcutScope::Window,
+                callback,
+            ));
+            self.shortcut = Some((key_combinations, id));
+        }
+        self.base.update();
+        self
+    }
+
+    pub fn shortcut(&self) -> Option<&KeyCombinations> {
+        self.shortcut
+            .as_ref()
+            .map(|(key_combinations, _)| key_combinations)
+    }
 
     pub fn on_triggered(&mut self, callback: Callback<()>) -> &mut Self {
         self.on_triggered.push(callback);
         self
     }
 
+    /// The callback is invoked when the checked state is changed by triggering the button.
+    pub fn on_toggled(&mut self, callback: Callback<bool>) -> &mut Self {
+        self.on_toggled.push(callback);
+        self
+    }
+
     pub fn trigger(&mut self) {
+        if self.is_checkable {
+            self.set_checked(!self.is_checked);
+            self.on_toggled.invoke(self.is_checked);
+        }
         self.on_triggered.invoke(());
     }
 
@@ -161,11 +339,47 @@ impl Button {
 
     fn refresh_style(&mut self) {
         self.style = get_style(self.base.style_element(), self.base.scale());
-        let icon = self.style.icon.clone();
+        self.refresh_icon();
+    }
+
+    fn refresh_icon(&mut self) {
+        let icon = self.render_icon();
         self.image_widget_mut().set_visible(icon.is_some());
         self.image_widget_mut().set_prescaled(true);
         self.image_widget_mut().set_pixmap(icon);
     }
+
+    // Returns the icon set with `set_icon` or specified in CSS, rendered for the current state.
+    fn render_icon(&mut self) -> Option<Rc<Pixmap>> {
+        let icon = match &self.icon {
+            Some(icon) => icon.clone(),
+            None => {
+                if let Some(pixmap) = &self.style.icon {
+                    return Some(pixmap.clone());
+                }
+                Icon::Named(self.style.icon_name.clone()?)
+            }
+        };
+        let scale = self.base.scale() * self.style.zoom;
+        // The pseudo class is updated before the enabled state of the widget.
+        let is_disabled = self
+            .base
+            .style_element()
+            .has_pseudo_class(PseudoClass::Disabled);
+        if self.icon.is_some() {
+            if let Some((rendered_scale, rendered_disabled, pixmap)) = &self.rendered_icon {
+                if *rendered_scale == scale && *rendered_disabled == is_disabled {
+                    return Some(pixmap.clone());
+                }
+            }
+        }
+        let pixmap =
+            with_system(|system| icon.render(&system.style, scale, is_disabled)).or_report_err()?;
+        if self.icon.is_some() {
+            self.rendered_icon = Some((scale, is_disabled, pixmap.clone()));
+        }
+        Some(pixmap)
+    }
 }
 
 impl Widget for Button {
@@ -190,11 +404,21 @@ impl Widget for Button {
             is_mouse_leave_sensitive: true,
             trigger_on_press: false,
             on_triggered: CallbackVec::new(),
+            is_checkable: false,
+            is_checked: false,
+            is_default: false,
+            on_toggled: CallbackVec::new(),
             is_pressed: false,
             was_pressed_but_moved_out: false,
             base,
             auto_repeat_delay_timer: None,
             auto_repeat_interval: None,
+            mnemonic: MnemonicText::default(),
+            mnemonic_shortcut: None,
+            shortcut: None,
+            icon: None,
+            icon_position: IconPosition::Left,
+            rendered_icon: None,
         };
         // TODO: remove and use declare_children
         b.refresh_style();
@@ -285,6 +509,25 @@ impl Widget for Button {
     fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
         let mut node = accesskit::Node::new(Role::Button);
         node.set_label(self.text_widget().text().as_str());
+        if let Some(c) = self.mnemonic.mnemonic_char() {
+            node.set_access_key(c.to_string());
+        }
+        if self.is_checkable {
+            node.set_toggled(if self.is_checked {
+                Toggled::True
+            } else {
+                Toggled::False
+            });
+        }
+        if let Some((key_combinations, _)) = &self.shortcut {
+            let shortcut = key_combinations.to_string();
+            let description = match self.base.tooltip() {
+                Some(tooltip) => format!("{tooltip} ({shortcut})"),
+                None => shortcut.clone(),
+            };
+            node.set_keyboard_shortcut(shortcut);
+            node.set_description(description);
+        }
         node.add_action(Action::Click);
         node.add_action(Action::Focus);
         Ok(Some(node))
@@ -303,13 +546,16 @@ impl Widget for Button {
 #[derive(Debug, Clone, Default)]
 struct ComputedButtonStyle {
     pub icon: Option<Rc<Pixmap>>,
+    pub icon_name: Option<String>,
+    pub zoom: f32,
 }
 
 impl ComputedElementStyle for ComputedButtonStyle {
     fn new(style: &Style, element: &Element, scale: f32) -> ComputedButtonStyle {
         let properties = style.find_rules(|s| element.matches(s));
 
-        let scale = scale * convert_zoom(&properties);
+        let zoom = convert_zoom(&properties);
+        let scale = scale * zoom;
         let mut icon = None;
         if let Some(url) = convert_content_url(&properties) {
             //println!("icon url: {url:?}");
@@ -318,6 +564,10 @@ impl ComputedElementStyle for ComputedButtonStyle {
                 Err(err) => warn!("failed to load icon: {err:?}"),
             }
         }
-        Self { icon }
+        Self {
+            icon,
+            icon_name: convert_icon_name(&properties),
+            zoom,
+        }
     }
 }
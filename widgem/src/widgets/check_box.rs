@@ -0,0 +1,301 @@
+use {
+    super::{image::Image, Widget, WidgetBaseOf, WidgetExt},
+    crate::{
+        callback::{Callback, CallbackVec},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, MouseInputEvent,
+            MouseMoveEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        shared_window::SetFocusRequest,
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_content_url, convert_zoom, Element, PseudoClass},
+            get_style, Style,
+        },
+        system::send_window_request,
+        text_editor::Text,
+    },
+    accesskit::{Action, Role, Toggled},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    log::warn,
+    std::{fmt::Display, rc::Rc},
+    tiny_skia::Pixmap,
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key, KeyCode, NamedKey, PhysicalKey},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Neither checked nor unchecked, e.g. when only some of the child options are checked.
+    Indeterminate,
+}
+
+impl From<bool> for CheckState {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
+impl From<CheckState> for Toggled {
+    fn from(value: CheckState) -> Self {
+        match value {
+            CheckState::Unchecked => Toggled::False,
+            CheckState::Checked => Toggled::True,
+            CheckState::Indeterminate => Toggled::Mixed,
+        }
+    }
+}
+
+pub struct CheckBox {
+    base: WidgetBaseOf<Self>,
+    check_state: CheckState,
+    is_tristate: bool,
+    is_pressed: bool,
+    on_toggled: CallbackVec<CheckState>,
+    style: Rc<ComputedIndicatorStyle>,
+}
+
+#[impl_with]
+impl CheckBox {
+    fn image_widget_mut(&mut self) -> &mut Image {
+        self.base.get_child_mut::<Image>(0).unwrap()
+    }
+
+    fn text_widget(&self) -> &Text {
+        self.base.get_child::<Text>(1).unwrap()
+    }
+
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(1).unwrap()
+    }
+
+    pub fn set_text(&mut self, text: impl Display) -> &mut Self {
+        self.text_widget_mut().set_text(text, Attrs::new());
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.text_widget().text()
+    }
+
+    pub fn set_check_state(&mut self, state: CheckState) -> &mut Self {
+        if self.check_state == state {
+            return self;
+        }
+        self.check_state = state;
+        self.set_pseudo_class(PseudoClass::Checked, state == CheckState::Checked);
+        self.set_pseudo_class(
+            PseudoClass::Indeterminate,
+            state == CheckState::Indeterminate,
+        );
+        self.base.update();
+        self
+    }
+
+    pub fn check_state(&self) -> CheckState {
+        self.check_state
+    }
+
+    pub fn set_checked(&mut self, checked: bool) -> &mut Self {
+        self.set_check_state(checked.into())
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.check_state == CheckState::Checked
+    }
+
+    /// Allows the user to switch the check box to the indeterminate state.
+    ///
+    /// If disabled (the default), the indeterminate state can only be set programmatically.
+    pub fn set_tristate(&mut self, value: bool) -> &mut Self {
+        self.is_tristate = value;
+        self
+    }
+
+    /// The callback is invoked when the state is changed by the user.
+    pub fn on_toggled(&mut self, callback: Callback<CheckState>) -> &mut Self {
+        self.on_toggled.push(callback);
+        self
+    }
+
+    /// Switches to the next state as if the check box was clicked.
+    pub fn toggle(&mut self) {
+        let state = match self.check_state {
+            CheckState::Unchecked => CheckState::Checked,
+            CheckState::Checked if self.is_tristate => CheckState::Indeterminate,
+            CheckState::Checked | CheckState::Indeterminate => CheckState::Unchecked,
+        };
+        self.set_check_state(state);
+        self.on_toggled.invoke(state);
+    }
+
+    fn set_pressed(&mut self, value: bool) {
+        if self.is_pressed == value {
+            return;
+        }
+        self.is_pressed = value;
+        self.set_pseudo_class(PseudoClass::Active, value);
+        self.base.update();
+    }
+
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        self.image_widget_mut().set_visible(icon.is_some());
+        self.image_widget_mut().set_prescaled(true);
+        self.image_widget_mut().set_pixmap(icon);
+    }
+}
+
+impl Widget for CheckBox {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child::<Image>()
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child::<Text>()
+            .set_column(1)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            check_state: CheckState::Unchecked,
+            is_tristate: false,
+            is_pressed: false,
+            on_toggled: CallbackVec::new(),
+        };
+        this.refresh_style();
+        this
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if self.is_pressed {
+            let rect = self.base.rect_in_self_or_err()?;
+            self.set_pseudo_class(PseudoClass::Active, rect.contains(event.pos));
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button == MouseButton::Left {
+            if event.state.is_pressed() {
+                self.set_pressed(true);
+                if !self.base.is_focused() && self.base.is_focusable() {
+                    send_window_request(
+                        self.base.window_or_err()?.id(),
+                        SetFocusRequest {
+                            widget_id: self.base.id().into(),
+                            reason: FocusReason::Mouse,
+                        },
+                    );
+                }
+            } else if self.is_pressed {
+                self.set_pressed(false);
+                if self.base.rect_in_self_or_err()?.contains(event.pos) {
+                    self.toggle();
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if event.info.physical_key == PhysicalKey::Code(KeyCode::Space)
+            || event.info.logical_key == Key::Named(NamedKey::Space)
+        {
+            if event.info.state.is_pressed() {
+                self.set_pressed(true);
+            } else if self.is_pressed {
+                self.set_pressed(false);
+                self.toggle();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Click => self.toggle(),
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::CheckBox);
+        node.set_label(self.text());
+        node.set_toggled(self.check_state.into());
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Click);
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+/// Style of a widget that displays a state indicator specified by the `content` property.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ComputedIndicatorStyle {
+    pub icon: Option<Rc<Pixmap>>,
+}
+
+impl ComputedElementStyle for ComputedIndicatorStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> ComputedIndicatorStyle {
+        let properties = style.find_rules(|s| element.matches(s));
+
+        let scale = scale * convert_zoom(&properties);
+        let mut icon = None;
+        if let Some(url) = convert_content_url(&properties) {
+            match style.load_pixmap(&url, scale) {
+                Ok(pixmap) => icon = Some(pixmap),
+                Err(err) => warn!("failed to load indicator: {err:?}"),
+            }
+        }
+        Self { icon }
+    }
+}
@@ -0,0 +1,454 @@
+use {
+    super::{
+        check_box::ComputedIndicatorStyle, image::Image, menu::Menu, text_input::TextInput, Widget,
+        WidgetBaseOf, WidgetExt,
+    },
+    crate::{
+        callback::{Callback, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, MouseInputEvent,
+            StyleChangeEvent,
+        },
+        impl_widget_base,
+        shared_window::SetFocusRequest,
+        style::get_style,
+        system::{send_window_request, ReportError},
+        text_editor::Text,
+    },
+    accesskit::{Action, HasPopup, Role},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    std::{
+        fmt::Display,
+        rc::Rc,
+        time::{Duration, Instant},
+    },
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key, NamedKey},
+    },
+};
+
+const FIELD_KEY: u64 = 0;
+const ARROW_KEY: u64 = 1;
+const POPUP_KEY: u64 = 2;
+
+// Typed characters are combined into one search string if they're typed within this interval.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A field that allows to select one of the items from a drop-down list.
+///
+/// In editable mode, the field is a [TextInput] that allows to enter arbitrary text and
+/// completes the entered text using the items.
+pub struct ComboBox {
+    base: WidgetBaseOf<Self>,
+    items: Vec<String>,
+    current_index: Option<usize>,
+    is_editable: bool,
+    on_current_index_changed: Callbacks<Option<usize>>,
+    type_ahead: String,
+    last_type_ahead: Option<Instant>,
+    // Text typed by the user, excluding the completion.
+    last_edited_text: String,
+    style: Rc<ComputedIndicatorStyle>,
+}
+
+#[impl_with]
+impl ComboBox {
+    fn popup(&self) -> &Menu {
+        self.base.get_child::<Menu>(POPUP_KEY).unwrap()
+    }
+
+    fn popup_mut(&mut self) -> &mut Menu {
+        self.base.get_child_mut::<Menu>(POPUP_KEY).unwrap()
+    }
+
+    pub fn add_item(&mut self, text: impl Display) -> &mut Self {
+        let index = self.items.len();
+        self.items.push(text.to_string());
+        let callback = self.callback(move |this, _| {
+            this.set_current_index_internal(Some(index), false);
+            Ok(())
+        });
+        self.popup_mut()
+            .add_item()
+            .set_text(text)
+            .on_triggered(callback);
+        self
+    }
+
+    pub fn set_items<T: Display>(&mut self, items: impl IntoIterator<Item = T>) -> &mut Self {
+        self.clear();
+        for item in items {
+            self.add_item(item);
+        }
+        self
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.popup_mut().clear();
+        self.set_current_index(None);
+    }
+
+    pub fn set_current_index(&mut self, index: Option<usize>) -> &mut Self {
+        self.set_current_index_internal(index, true);
+        self
+    }
+
+    fn set_current_index_internal(&mut self, index: Option<usize>, from_setter: bool) {
+        let index = index.filter(|index| *index < self.items.len());
+        if self.current_index == index {
+            return;
+        }
+        self.current_index = index;
+        let text = self.current_text().unwrap_or_default().to_string();
+        if self.is_editable {
+            self.last_edited_text = text.clone();
+            self.base
+                .get_child_mut::<TextInput>(FIELD_KEY)
+                .unwrap()
+                .set_text(text);
+        } else {
+            self.base
+                .get_child_mut::<Text>(FIELD_KEY)
+                .unwrap()
+                .set_text(text, Attrs::new());
+        }
+        self.on_current_index_changed.invoke(index, from_setter);
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    pub fn current_index(&self) -> Option<usize> {
+        self.current_index
+    }
+
+    pub fn current_text(&self) -> Option<&str> {
+        self.current_index
+            .and_then(|index| self.items.get(index))
+            .map(|s| s.as_str())
+    }
+
+    /// Returns the text displayed in the field.
+    ///
+    /// In editable mode, it may be different from the current item.
+    pub fn text(&self) -> String {
+        if self.is_editable {
+            self.base.get_child::<TextInput>(FIELD_KEY).unwrap().text()
+        } else {
+            self.current_text().unwrap_or_default().to_string()
+        }
+    }
+
+    /// The callback is invoked when the current index is changed by the user.
+    ///
+    /// Changes made by calling a setter are only reported if the callback was created
+    /// with `with_send_signals_on_setter_calls(true)`.
+    pub fn on_current_index_changed(&mut self, callback: Callback<Option<usize>>) -> &mut Self {
+        self.on_current_index_changed.add(callback);
+        self
+    }
+
+    /// Allows the user to enter arbitrary text in the field.
+    pub fn set_editable(&mut self, value: bool) -> &mut Self {
+        if self.is_editable == value {
+            return self;
+        }
+        self.is_editable = value;
+        self.base.remove_child(FIELD_KEY).or_report_err();
+        self.add_field();
+        self.set_class("editable".into(), value);
+        self.base.set_supports_focus(!value);
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.is_editable
+    }
+
+    pub fn open_popup(&mut self) {
+        if self.items.is_empty() || self.is_popup_open() {
+            return;
+        }
+        let Some(rect) = self.base.rect_in_window() else {
+            return;
+        };
+        let current_index = self.current_index;
+        let popup = self.popup_mut();
+        popup.set_min_width(rect.size_x());
+        popup.set_anchor_rect(rect);
+        popup.popup();
+        popup.set_current_index(current_index);
+        self.base.update();
+    }
+
+    pub fn close_popup(&mut self) {
+        self.popup_mut().close();
+        self.base.update();
+    }
+
+    pub fn is_popup_open(&self) -> bool {
+        self.popup().is_open()
+    }
+
+    fn add_field(&mut self) {
+        let text = self.current_text().unwrap_or_default().to_string();
+        if self.is_editable {
+            let callback = self.callback(|this, text| {
+                this.text_edited(text);
+                Ok(())
+            });
+            let input = self
+                .base
+                .add_child_with_key::<TextInput>(FIELD_KEY)
+                .set_column(0)
+                .set_row(0);
+            input.set_text(&text);
+            input.on_text_edited(callback);
+            self.last_edited_text = text;
+        } else {
+            let id = self.base.id().raw();
+            let element = self.base.style_element().clone();
+            self.base
+                .add_child_with_key::<Text>(FIELD_KEY)
+                .set_column(0)
+                .set_row(0)
+                .set_host_id(id)
+                .set_host_style_element(element)
+                .set_text(text, Attrs::new());
+        }
+    }
+
+    fn text_edited(&mut self, text: String) {
+        let is_typing = text.len() > self.last_edited_text.len()
+            && text.starts_with(self.last_edited_text.as_str());
+        self.last_edited_text = text.clone();
+        let mut full_text = text.clone();
+        if is_typing && !text.is_empty() {
+            if let Some(item) = self
+                .items
+                .iter()
+                .find(|item| item.len() > text.len() && starts_with_ignore_case(item, &text))
+            {
+                let completion = &item[text.len()..];
+                full_text = format!("{text}{completion}");
+                let input = self.base.get_child_mut::<TextInput>(FIELD_KEY).unwrap();
+                // Insert the completion as an edit, so that it doesn't clear the undo history.
+                input.set_selection(text.len()..text.len());
+                input.insert_text(completion);
+                input.set_selection(text.len()..full_text.len());
+            }
+        }
+        let index = self
+            .items
+            .iter()
+            .position(|item| item.to_lowercase() == full_text.to_lowercase());
+        if self.current_index != index {
+            self.current_index = index;
+            self.on_current_index_changed.invoke(index, false);
+            self.base.update();
+        }
+    }
+
+    fn type_ahead(&mut self, text: &str) {
+        let now = Instant::now();
+        if self
+            .last_type_ahead
+            .is_none_or(|time| now.duration_since(time) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.type_ahead.clear();
+        }
+        self.last_type_ahead = Some(now);
+        self.type_ahead.push_str(text);
+        // Search from the next item so that typing the same letter repeatedly cycles through items.
+        let start = if self.type_ahead.chars().count() == 1 {
+            self.current_index.map_or(0, |index| index + 1)
+        } else {
+            self.current_index.unwrap_or(0)
+        };
+        let len = self.items.len();
+        let found = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|index| starts_with_ignore_case(&self.items[*index], &self.type_ahead));
+        if let Some(index) = found {
+            self.set_current_index_internal(Some(index), false);
+        }
+    }
+
+    fn move_current(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let index = match self.current_index {
+            Some(index) => index.saturating_add_signed(delta).min(self.items.len() - 1),
+            None => 0,
+        };
+        self.set_current_index_internal(Some(index), false);
+    }
+
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        let arrow = self.base.get_child_mut::<Image>(ARROW_KEY).unwrap();
+        arrow.set_visible(icon.is_some());
+        arrow.set_prescaled(true);
+        arrow.set_pixmap(icon);
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.len() >= prefix.len()
+        && text.is_char_boundary(prefix.len())
+        && text[..prefix.len()].to_lowercase() == prefix.to_lowercase()
+}
+
+impl Widget for ComboBox {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child_with_key::<Image>(ARROW_KEY)
+            .set_column(1)
+            .set_row(0)
+            .set_visible(false);
+        let on_closed = base.callback(|this, _| {
+            this.base.update();
+            Ok(())
+        });
+        base.add_child_with_key::<Menu>(POPUP_KEY)
+            .on_closed(on_closed)
+            .close();
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            items: Vec::new(),
+            current_index: None,
+            is_editable: false,
+            on_current_index_changed: Callbacks::default(),
+            type_ahead: String::new(),
+            last_type_ahead: None,
+            last_edited_text: String::new(),
+        };
+        this.add_field();
+        this.refresh_style();
+        this
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button == MouseButton::Left && event.state.is_pressed() {
+            if !self.is_editable && !self.base.is_focused() && self.base.is_focusable() {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            if self.is_popup_open() {
+                self.close_popup();
+            } else {
+                self.open_popup();
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() {
+            return Ok(false);
+        }
+        let alt = self.base.window_or_err()?.modifiers().alt_key();
+        match &event.info.logical_key {
+            Key::Named(NamedKey::ArrowDown) if alt => self.open_popup(),
+            Key::Named(NamedKey::F4 | NamedKey::Space | NamedKey::Enter) => self.open_popup(),
+            Key::Named(NamedKey::ArrowDown) => self.move_current(1),
+            Key::Named(NamedKey::ArrowUp) => self.move_current(-1),
+            Key::Named(NamedKey::Home) => {
+                if !self.items.is_empty() {
+                    self.set_current_index_internal(Some(0), false);
+                }
+            }
+            Key::Named(NamedKey::End) => {
+                let last = self.items.len().checked_sub(1);
+                self.set_current_index_internal(last, false);
+            }
+            _ => {
+                let Some(text) = event
+                    .info
+                    .text
+                    .as_ref()
+                    .filter(|text| !text.is_empty() && !text.chars().any(|c| c.is_control()))
+                else {
+                    return Ok(false);
+                };
+                let text = text.to_string();
+                self.type_ahead(&text);
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Click | Action::Expand => self.open_popup(),
+            Action::Collapse => self.close_popup(),
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::ComboBox);
+        node.set_value(self.text());
+        node.set_has_popup(HasPopup::Menu);
+        let is_expanded = self.is_popup_open();
+        node.set_expanded(is_expanded);
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Click);
+        node.add_action(Action::Focus);
+        node.add_action(if is_expanded {
+            Action::Collapse
+        } else {
+            Action::Expand
+        });
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        if !self.is_editable {
+            let element = self.base.style_element().clone();
+            self.base
+                .get_child_mut::<Text>(FIELD_KEY)?
+                .set_host_style_element(element);
+        }
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
@@ -1,33 +1,35 @@
 use {
     super::{address, RawWidgetId, Widget, WidgetAddress, WidgetId, WidgetNotFound},
     crate::{
-        callback::{widget_callback, Callback},
-        event::Event,
+        callback::{raw_widget_callback, widget_callback, Callback},
+        event::{Event, FocusReason, StyleChangeEvent},
         key::Key,
         layout::{LayoutItemOptions, SizeHints},
         shared_window::{SharedWindow, WindowId},
         shortcut::{Shortcut, ShortcutId, ShortcutScope},
         style::{
             common::CommonComputedStyle,
-            css::{Element, PseudoClass},
+            css::{ChildPosition, Element, PseudoClass},
             get_style,
         },
         system::{
-            register_address, request_children_update, unregister_address, with_system,
+            add_timer, register_address, request_children_update, unregister_address, with_system,
             ChildrenUpdateState, ReportError,
         },
+        timer::TimerId,
         types::{PhysicalPixels, Point, Rect, Size},
-        widgets::WidgetExt,
+        widgets::{tooltip::Tooltip, WidgetExt},
     },
     anyhow::{Context, Result},
     derivative::Derivative,
+    itertools::Itertools,
     log::{error, warn},
     std::{
         collections::{BTreeMap, HashMap, HashSet},
-        fmt::Debug,
+        fmt::{Debug, Display},
         marker::PhantomData,
         mem,
-        ops::{Deref, DerefMut},
+        ops::{Deref, DerefMut, RangeInclusive},
         rc::Rc,
     },
     stringcase::kebab_case,
@@ -42,6 +44,8 @@ pub struct WidgetCreationContext {
     pub parent_scale: f32,
     pub is_parent_enabled: bool,
     pub is_window_root: bool,
+    /// Style element of the parent widget. It's `None` for the root widget of a window.
+    pub parent_style_element: Option<Rc<Element>>,
 }
 
 pub type EventFilterFn = dyn Fn(Event) -> Result<bool>;
@@ -145,6 +149,8 @@ auto_bitflags! {
         supports_focus,
         // widget currently has focus
         focused,
+        // focus was not set by the mouse, so the focus indicator should be shown
+        focus_visible,
         // true if the widget is currently added to the window's list of focusable widgets
         registered_as_focusable,
         // whether IME is enabled when the widget has focus
@@ -205,6 +211,10 @@ pub struct WidgetBase {
     pub style_element: Element,
     pub common_style: Rc<CommonComputedStyle>,
 
+    tooltip: Option<String>,
+    tooltip_timer: Option<TimerId>,
+    labelled_by: Option<RawWidgetId>,
+
     pub num_added_children: u32,
     // Direct and indirect children created by last call of this widget's
     // `handle_update_children`.
@@ -213,19 +223,22 @@ pub struct WidgetBase {
 
 impl Drop for WidgetBase {
     fn drop(&mut self) {
+        if let Some(timer) = self.tooltip_timer.take() {
+            timer.cancel();
+        }
         unregister_address(self.id);
         // Drop and unmount children before unmounting self.
         self.children.clear();
         self.unmount_accessible();
-        for shortcut in &self.shortcuts {
-            // TODO: deregister widget/window shortcuts
-            if shortcut.scope == ShortcutScope::Application {
-                with_system(|system| system.application_shortcuts.retain(|s| s.id != shortcut.id));
-            }
+        for shortcut in std::mem::take(&mut self.shortcuts) {
+            self.deregister_shortcut(&shortcut);
         }
     }
 }
 
+// Key of the popup window created by `set_tooltip`.
+const TOOLTIP_KEY: &str = "widgem::tooltip";
+
 fn last_path_part(str: &str) -> &str {
     str.rsplit("::")
         .next()
@@ -239,8 +252,16 @@ impl WidgetBase {
         register_address(id, ctx.address.clone());
 
         let type_name = T::type_name();
-        let style_element = Element::new(kebab_case(last_path_part(type_name)))
+        let mut style_element = Element::new(kebab_case(last_path_part(type_name)))
             .with_pseudo_class(PseudoClass::Enabled);
+        if ctx
+            .window
+            .as_ref()
+            .is_some_and(|window| !window.is_focused())
+        {
+            style_element.add_pseudo_class(PseudoClass::WindowInactive);
+        }
+        style_element.set_parent(ctx.parent_style_element);
         let common_style = get_style(&style_element, ctx.parent_scale);
         let mut common = Self {
             id,
@@ -274,6 +295,9 @@ impl WidgetBase {
             shortcuts: Vec::new(),
             style_element,
             common_style,
+            tooltip: None,
+            tooltip_timer: None,
+            labelled_by: None,
             num_added_children: 0,
             declared_children: Default::default(),
         };
@@ -317,6 +341,22 @@ impl WidgetBase {
         self.window.as_ref().map(|w| w.id())
     }
 
+    /// Returns the window that contains the closest indirect parent of the widget.
+    ///
+    /// For a widget that is the root of its own window (e.g. a menu or a dialog),
+    /// it's the window that the widget's window belongs to.
+    pub(crate) fn parent_window(&self) -> Option<SharedWindow> {
+        with_system(|system| {
+            self.address.path.iter().rev().skip(1).find_map(|(_, id)| {
+                system
+                    .windows
+                    .values()
+                    .find(|window| window.root_widget_id == *id)
+                    .map(|window| window.shared_window.clone())
+            })
+        })
+    }
+
     // TODO: revise behavior on hidden windows
 
     /// True if this widget is currently visible.
@@ -448,6 +488,12 @@ impl WidgetBase {
         self.flags.contains(Flags::focused) && self.is_enabled()
     }
 
+    /// Returns `true` if the widget has focus that wasn't set by the mouse
+    /// (e.g. it was set using the keyboard), so the focus indicator should be shown.
+    pub fn is_focus_visible(&self) -> bool {
+        self.flags.contains(Flags::focus_visible) && self.is_focused()
+    }
+
     /// Returns `true` if the widget's OS window is focused.
     pub fn is_window_focused(&self) -> bool {
         self.window.as_ref().is_some_and(|w| w.is_focused())
@@ -472,6 +518,11 @@ impl WidgetBase {
             parent_id: Some(self.id),
             address: self.address.clone().join(key, new_id),
             is_window_root: root_of_window.is_some(),
+            parent_style_element: if root_of_window.is_some() {
+                None
+            } else {
+                Some(Rc::new(self.style_element.clone()))
+            },
             window: root_of_window.or_else(|| self.window.clone()),
             parent_scale: self.scale(),
             is_parent_enabled: self.is_enabled(),
@@ -559,6 +610,7 @@ impl WidgetBase {
         self.children
             .insert(key.clone(), Box::new(T::new(WidgetBase::new::<T>(ctx))));
         self.size_hint_changed();
+        self.update_child_positions();
         let widget = self.children.get_mut(&key).unwrap();
         if declare {
             with_system(|system| {
@@ -620,9 +672,58 @@ impl WidgetBase {
     pub fn remove_child(&mut self, key: impl Into<Key>) -> Result<(), WidgetNotFound> {
         self.children.remove(&key.into()).ok_or(WidgetNotFound)?;
         self.size_hint_changed();
+        self.update_child_positions();
         Ok(())
     }
 
+    // Sets `:window-inactive` pseudo-class for this widget and all its children in the same window
+    // without notifying them.
+    pub(crate) fn set_window_inactive(&mut self, inactive: bool) {
+        self.style_element
+            .set_pseudo_class(PseudoClass::WindowInactive, inactive);
+        for child in self.children.values_mut() {
+            if !child.base().is_window_root() {
+                child.base_mut().set_window_inactive(inactive);
+            }
+        }
+    }
+
+    // Updates positions of the children used for matching `:first-child` and similar selectors,
+    // and notifies the children whose positions have changed.
+    pub(crate) fn update_child_positions(&mut self) {
+        for key in self.set_child_positions() {
+            if let Some(child) = self.children.get_mut(&key) {
+                child.dispatch(StyleChangeEvent {}.into());
+            }
+        }
+    }
+
+    // Sets positions of the children in their style elements and returns keys of the children
+    // whose positions have changed. Positions are not tracked if the style doesn't use them.
+    pub(crate) fn set_child_positions(&mut self) -> Vec<Key> {
+        let uses_child_positions = with_system(|system| system.style.uses_child_positions);
+        let mut children = self
+            .children
+            .iter_mut()
+            .filter(|(_, child)| !child.base().is_window_root())
+            .collect_vec();
+        if uses_child_positions {
+            children.sort_by_key(|(_, child)| {
+                child_order_key(child.base().layout_item_options(), child.base().id())
+            });
+        }
+        let count = children.len();
+        let mut changed = Vec::new();
+        for (index, (key, child)) in children.into_iter().enumerate() {
+            let position = uses_child_positions.then_some(ChildPosition { index, count });
+            if child.base().style_element.position() != position {
+                child.base_mut().style_element.set_position(position);
+                changed.push(key.clone());
+            }
+        }
+        changed
+    }
+
     pub fn remove_child_by_id(&mut self, id: RawWidgetId) -> Result<(), WidgetNotFound> {
         if id == self.id {
             warn!("remove_child_by_id: cannot delete self");
@@ -734,11 +835,13 @@ impl WidgetBase {
     /// Returns `true` if the event is consumed and shouldn't be dispatched to the widget.
     pub(crate) fn before_event(&mut self, event: &Event) -> bool {
         match &event {
-            Event::FocusIn(_) => {
+            Event::FocusIn(event) => {
                 self.flags.insert(Flags::focused);
+                self.flags
+                    .set(Flags::focus_visible, event.reason != FocusReason::Mouse);
             }
             Event::FocusOut(_) => {
-                self.flags.remove(Flags::focused);
+                self.flags.remove(Flags::focused | Flags::focus_visible);
             }
             Event::WindowFocusChange(_) => {}
             Event::MouseInput(event) => {
@@ -905,15 +1008,47 @@ impl WidgetBase {
 
     pub fn add_shortcut(&mut self, shortcut: Shortcut) -> ShortcutId {
         let id = shortcut.id;
-        if shortcut.scope == ShortcutScope::Application {
-            with_system(|system| system.application_shortcuts.push(shortcut.clone()));
+        match shortcut.scope {
+            ShortcutScope::Application => {
+                with_system(|system| system.application_shortcuts.push(shortcut.clone()));
+            }
+            ShortcutScope::Window => {
+                if let Some(window) = &self.window {
+                    window.add_shortcut(shortcut.clone());
+                } else {
+                    warn!("cannot register window shortcut for a widget without a window");
+                }
+            }
+            // TODO: register widget shortcuts
+            ShortcutScope::Widget => {}
         }
-        // TODO: register widget/window shortcuts
         self.shortcuts.push(shortcut);
         id
     }
 
-    // TODO: remove_shortcut
+    pub fn remove_shortcut(&mut self, id: ShortcutId) {
+        let Some(index) = self.shortcuts.iter().position(|s| s.id == id) else {
+            warn!("shortcut not found: {id:?}");
+            return;
+        };
+        let shortcut = self.shortcuts.remove(index);
+        self.deregister_shortcut(&shortcut);
+    }
+
+    fn deregister_shortcut(&self, shortcut: &Shortcut) {
+        match shortcut.scope {
+            ShortcutScope::Application => {
+                with_system(|system| system.application_shortcuts.retain(|s| s.id != shortcut.id));
+            }
+            ShortcutScope::Window => {
+                if let Some(window) = &self.window {
+                    window.remove_shortcut(shortcut.id);
+                }
+            }
+            // TODO: deregister widget shortcuts
+            ShortcutScope::Widget => {}
+        }
+    }
 
     pub fn refresh_common_style(&mut self) {
         self.common_style = get_style(&self.style_element, self.scale());
@@ -1043,6 +1178,80 @@ impl WidgetBase {
         self
     }
 
+    /// Sets the text displayed in a popup when the mouse pointer rests over this widget.
+    ///
+    /// The popup is shown after [tooltip_delay](crate::system::SystemConfig::tooltip_delay)
+    /// and hidden when the mouse pointer leaves the widget or when a key or a mouse button is pressed.
+    /// The text is also used as the accessible description of the widget.
+    pub fn set_tooltip(&mut self, text: impl Display) -> &mut Self {
+        let text = text.to_string();
+        if let Ok(tooltip) = self.get_child_mut::<Tooltip>(TOOLTIP_KEY) {
+            tooltip.set_text(&text);
+        }
+        self.tooltip = Some(text);
+        self.update();
+        self
+    }
+
+    /// Removes the tooltip set with [set_tooltip](Self::set_tooltip).
+    pub fn clear_tooltip(&mut self) -> &mut Self {
+        self.tooltip = None;
+        self.hide_tooltip();
+        self.update();
+        self
+    }
+
+    pub fn tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
+    pub(crate) fn start_tooltip_timer(&mut self) {
+        self.hide_tooltip();
+        if self.tooltip.is_none() {
+            return;
+        }
+        let delay = with_system(|system| system.config.tooltip_delay);
+        let callback = raw_widget_callback(self.id, |widget, _| {
+            widget.base_mut().show_tooltip();
+            Ok(())
+        });
+        self.tooltip_timer = Some(add_timer(delay, callback));
+    }
+
+    fn show_tooltip(&mut self) {
+        self.tooltip_timer = None;
+        let Some(text) = self.tooltip.clone() else {
+            return;
+        };
+        if !self.has_child(TOOLTIP_KEY) {
+            self.add_child_with_key::<Tooltip>(TOOLTIP_KEY)
+                .set_text(text);
+        }
+    }
+
+    /// ID of the widget that labels this widget (e.g. a [Label](super::label::Label)
+    /// with this widget as a buddy).
+    pub fn labelled_by(&self) -> Option<RawWidgetId> {
+        self.labelled_by
+    }
+
+    pub(crate) fn set_labelled_by(&mut self, id: Option<RawWidgetId>) {
+        if self.labelled_by == id {
+            return;
+        }
+        self.labelled_by = id;
+        self.update();
+    }
+
+    pub(crate) fn hide_tooltip(&mut self) {
+        if let Some(timer) = self.tooltip_timer.take() {
+            timer.cancel();
+        }
+        if self.has_child(TOOLTIP_KEY) {
+            self.remove_child(TOOLTIP_KEY).or_report_err();
+        }
+    }
+
     /// ID of the parent widget.
     ///
     /// The parent widget is the owner of its direct children.
@@ -1137,3 +1346,53 @@ impl<T> From<WidgetBaseOf<T>> for WidgetBase {
         value.base
     }
 }
+
+// Children are ordered by their row and column in the grid, and then by creation order.
+fn child_order_key(options: &LayoutItemOptions, id: RawWidgetId) -> (i32, i32, RawWidgetId) {
+    let start =
+        |pos: &Option<RangeInclusive<i32>>| pos.as_ref().map_or(i32::MAX, |pos| *pos.start());
+    (
+        start(&options.y.pos_in_grid),
+        start(&options.x.pos_in_grid),
+        id,
+    )
+}
+
+#[test]
+fn child_order() {
+    // More than 10 children, so the order of keys differs from the order of numbers.
+    let mut children = (0..12)
+        .map(|i| {
+            let key = Key::from(i);
+            let options = LayoutItemOptions::from_pos_in_grid(0, i);
+            (key, (options, RawWidgetId::new_unique()))
+        })
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(children.keys().nth(2), Some(&Key::from(10)));
+    let ordered_keys = |children: &BTreeMap<Key, (LayoutItemOptions, RawWidgetId)>| {
+        children
+            .iter()
+            .sorted_by_key(|(_, (options, id))| child_order_key(options, *id))
+            .map(|(key, _)| key.clone())
+            .collect_vec()
+    };
+    assert_eq!(
+        ordered_keys(&children),
+        (0..12).map(Key::from).collect_vec()
+    );
+
+    // Rows take priority over creation order.
+    children.get_mut(&Key::from(0)).unwrap().0 = LayoutItemOptions::from_pos_in_grid(0, 20);
+    let mut expected = (1..12).map(Key::from).collect_vec();
+    expected.push(Key::from(0));
+    assert_eq!(ordered_keys(&children), expected);
+
+    // Children without a position in the grid are ordered by creation.
+    for (options, _) in children.values_mut() {
+        *options = LayoutItemOptions::default();
+    }
+    assert_eq!(
+        ordered_keys(&children),
+        (0..12).map(Key::from).collect_vec()
+    );
+}
@@ -0,0 +1,262 @@
+use {
+    super::{Widget, WidgetBase, WidgetBaseOf},
+    crate::{
+        callback::{Callback, CallbackVec},
+        event::LayoutEvent,
+        impl_widget_base,
+        layout::grid::grid_layout,
+        shared_window::{SharedWindow, X11WindowType},
+        shortcut::{KeyCombinations, Modifiers, Shortcut, ShortcutScope},
+        types::{Point, Size},
+    },
+    accesskit::Role,
+    anyhow::Result,
+    std::{
+        cmp::{max, min},
+        fmt::Display,
+    },
+    widgem_macros::impl_with,
+    winit::keyboard::NamedKey,
+};
+
+/// Outcome of a [Dialog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogResult {
+    Accepted,
+    Rejected,
+}
+
+/// Modal state of a window that blocks input to its parent window while it's open.
+///
+/// This is the logic shared by [Dialog] and [MessageBox](super::message_box::MessageBox).
+pub(crate) struct Modality {
+    parent_window: Option<SharedWindow>,
+    is_open: bool,
+    needs_centering: bool,
+}
+
+impl Modality {
+    pub fn new(parent_window: Option<SharedWindow>) -> Self {
+        Self {
+            parent_window,
+            is_open: false,
+            needs_centering: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Shows the window and blocks input to the parent window.
+    ///
+    /// Returns `false` if the window is already open.
+    pub fn open(&mut self, window: &SharedWindow, size: Option<Size>) -> bool {
+        if self.is_open {
+            return false;
+        }
+        self.is_open = true;
+        if let Some(parent_window) = &self.parent_window {
+            parent_window.add_modal_blocker(window.id());
+        }
+        self.needs_centering = true;
+        if let Some(size) = size {
+            self.center(window, size);
+        }
+        window.set_visible(true);
+        window.focus_window();
+        true
+    }
+
+    /// Hides the window and unblocks the parent window.
+    ///
+    /// Returns `false` if the window is not open.
+    pub fn close(&mut self, window: &SharedWindow) -> bool {
+        if !self.is_open {
+            return false;
+        }
+        self.is_open = false;
+        window.set_visible(false);
+        if let Some(parent_window) = &self.parent_window {
+            parent_window.remove_modal_blocker(window.id());
+            parent_window.focus_window();
+        }
+        true
+    }
+
+    /// Places the window in the center of the parent window.
+    ///
+    /// It's only done once after opening the window. It's postponed until the position
+    /// of the parent window is known. Call it when the size of the window changes.
+    pub fn center(&mut self, window: &SharedWindow, size: Size) {
+        if !self.needs_centering {
+            return;
+        }
+        let Some(parent_window) = &self.parent_window else {
+            self.needs_centering = false;
+            return;
+        };
+        let Some(parent_position) = parent_window.inner_position() else {
+            return;
+        };
+        self.needs_centering = false;
+        let parent_size = parent_window.inner_size();
+        let mut x = parent_position.x() + (parent_size.x() - size.x()) / 2;
+        let mut y = parent_position.y() + (parent_size.y() - size.y()) / 2;
+        if let Some(screen) = parent_window.monitor_rect() {
+            x = max(min(x, screen.right() - size.x()), screen.left());
+            y = max(min(y, screen.bottom() - size.y()), screen.top());
+        }
+        window.set_outer_position(Point::new(x, y));
+    }
+}
+
+/// A modal top-level window.
+///
+/// The dialog is opened as soon as it's created. While it's open, it blocks input to the window
+/// that contains its parent widget, and it's placed in the center of that window.
+/// Enter accepts the dialog and Escape rejects it. Closing the window also rejects the dialog.
+///
+/// A finished dialog is hidden and can be opened again with [open](Self::open).
+/// Add content to the dialog as to any other container.
+pub struct Dialog {
+    base: WidgetBaseOf<Self>,
+    modality: Modality,
+    result: Option<DialogResult>,
+    on_finished: CallbackVec<DialogResult>,
+}
+
+#[impl_with]
+impl Dialog {
+    pub fn set_title(&mut self, title: impl Display) -> &mut Self {
+        self.base.window.as_ref().unwrap().set_title(title);
+        self
+    }
+
+    /// Shows the dialog if it's hidden.
+    pub fn open(&mut self) -> &mut Self {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.open(&window, self.base.size()) {
+            self.result = None;
+            self.base.update();
+        }
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.modality.is_open()
+    }
+
+    /// Closes the dialog with [DialogResult::Accepted].
+    pub fn accept(&mut self) {
+        self.done(DialogResult::Accepted);
+    }
+
+    /// Closes the dialog with [DialogResult::Rejected].
+    pub fn reject(&mut self) {
+        self.done(DialogResult::Rejected);
+    }
+
+    /// Closes the dialog with the specified result.
+    pub fn done(&mut self, result: DialogResult) {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.close(&window) {
+            self.result = Some(result);
+            self.on_finished.invoke(result);
+            self.base.update();
+        }
+    }
+
+    /// Result of the last time the dialog was finished.
+    pub fn result(&self) -> Option<DialogResult> {
+        self.result
+    }
+
+    /// Called when the dialog is accepted or rejected.
+    pub fn on_finished(&mut self, callback: Callback<DialogResult>) -> &mut Self {
+        self.on_finished.push(callback);
+        self
+    }
+}
+
+// Registers window shortcuts that accept and reject the dialog.
+pub(crate) fn add_dialog_shortcuts(
+    base: &mut WidgetBase,
+    accept: Callback<()>,
+    reject: Callback<()>,
+) {
+    base.add_shortcut(Shortcut::new(
+        KeyCombinations::new(Modifiers::empty(), NamedKey::Enter),
+        ShortcutScope::Window,
+        accept,
+    ));
+    base.add_shortcut(Shortcut::new(
+        KeyCombinations::new(Modifiers::empty(), NamedKey::Escape),
+        ShortcutScope::Window,
+        reject,
+    ));
+}
+
+// Configures the window of a dialog.
+pub(crate) fn init_dialog_window(window: &SharedWindow) {
+    window.set_x11_window_type(vec![X11WindowType::Dialog]);
+    window.set_skip_windows_taskbar(true);
+}
+
+impl Widget for Dialog {
+    impl_widget_base!();
+
+    fn is_window_root_type() -> bool {
+        true
+    }
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.window.clone().unwrap();
+        init_dialog_window(&window);
+        let id = base.id();
+        add_dialog_shortcuts(
+            &mut base,
+            id.callback(|this, ()| {
+                this.accept();
+                Ok(())
+            }),
+            id.callback(|this, ()| {
+                this.reject();
+                Ok(())
+            }),
+        );
+        let mut this = Self {
+            modality: Modality::new(base.parent_window()),
+            base,
+            result: None,
+            on_finished: CallbackVec::new(),
+        };
+        this.open();
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        if let Some(size) = self.base.size() {
+            let window = self.base.window.clone().unwrap();
+            self.modality.center(&window, size);
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Dialog);
+        node.set_modal();
+        Ok(Some(node))
+    }
+}
+
+impl Drop for Dialog {
+    fn drop(&mut self) {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.close(&window) {
+            self.on_finished.invoke(DialogResult::Rejected);
+        }
+        window.deregister();
+    }
+}
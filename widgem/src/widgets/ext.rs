@@ -10,7 +10,7 @@ use {
     },
     anyhow::Result,
     log::{error, warn},
-    std::borrow::Cow,
+    std::{borrow::Cow, fmt::Display, rc::Rc},
 };
 
 fn accept_mouse_move_or_enter_event(widget: &mut (impl Widget + ?Sized), is_enter: bool) {
@@ -55,6 +55,11 @@ pub trait WidgetExt: Widget {
         self.base_mut().set_focusable(value);
         self
     }
+
+    fn set_tooltip(&mut self, text: impl Display) -> &mut Self {
+        self.base_mut().set_tooltip(text);
+        self
+    }
     fn set_accessibility_node_enabled(&mut self, value: bool) -> &mut Self {
         self.base_mut().set_accessibility_node_enabled(value);
         self
@@ -119,15 +124,33 @@ pub trait WidgetExt: Widget {
             }
             Event::FocusIn(_) => {
                 self.set_pseudo_class(PseudoClass::Focus, self.base().is_window_focused());
+                self.set_pseudo_class(
+                    PseudoClass::FocusVisible,
+                    self.base().is_window_focused() && self.base().is_focus_visible(),
+                );
             }
             Event::FocusOut(_) => {
                 self.remove_pseudo_class(PseudoClass::Focus);
+                self.remove_pseudo_class(PseudoClass::FocusVisible);
             }
             Event::WindowFocusChange(event) => {
+                let is_window_inactive = !self.base().is_window_focused();
+                if self.base().is_window_root()
+                    && self.has_pseudo_class(PseudoClass::WindowInactive) != is_window_inactive
+                {
+                    // Update the whole window at once to avoid restyling each subtree
+                    // multiple times.
+                    self.base_mut().set_window_inactive(is_window_inactive);
+                    self.dispatch(StyleChangeEvent {}.into());
+                }
                 self.set_pseudo_class(
                     PseudoClass::Focus,
                     event.is_window_focused && self.base().is_focused(),
                 );
+                self.set_pseudo_class(
+                    PseudoClass::FocusVisible,
+                    event.is_window_focused && self.base().is_focus_visible(),
+                );
             }
             _ => (),
         }
@@ -184,10 +207,21 @@ pub trait WidgetExt: Widget {
                 self.base_mut().update();
             }
             Event::Layout(_) => {
+                // Grid positions of the children may have changed.
+                self.base_mut().update_child_positions();
                 self.base_mut().update();
             }
             Event::StyleChange(event) => {
+                // Positions may become relevant if the style has been reloaded.
+                self.base_mut().set_child_positions();
+                let style_element = Rc::new(self.base().style_element.clone());
                 for child in self.base_mut().children.values_mut() {
+                    if !child.base().is_window_root() {
+                        child
+                            .base_mut()
+                            .style_element
+                            .set_parent(Some(style_element.clone()));
+                    }
                     // TODO: only if really changed
                     child.dispatch(event.clone().into());
                 }
@@ -245,10 +279,19 @@ pub trait WidgetExt: Widget {
         };
         // TODO: refresh after layout event
         let rect = self.base().rect_in_window();
+        let tooltip = self.base().tooltip();
+        let labelled_by = self.base().labelled_by();
         let node = node.map(|mut node| {
             if let Some(rect) = rect {
                 node.set_bounds(rect.into());
             }
+            // The widget may provide a more detailed description that includes the tooltip.
+            if let (Some(tooltip), None) = (tooltip, node.description()) {
+                node.set_description(tooltip);
+            }
+            if let Some(labelled_by) = labelled_by {
+                node.set_labelled_by(vec![labelled_by.into()]);
+            }
             node
         });
         window.accessibility_node_updated(self.base().id().into(), node);
@@ -364,6 +407,17 @@ pub trait WidgetExt: Widget {
         self
     }
 
+    /// Sets or removes (if `value` is `None`) an attribute that can be matched by
+    /// attribute selectors in the style (e.g. `button[checkable]`).
+    fn set_style_attribute(&mut self, name: Cow<'static, str>, value: Option<String>) -> &mut Self {
+        if self.base().style_element.attribute(&name) == value.as_deref() {
+            return self;
+        }
+        self.base_mut().style_element.set_attribute(name, value);
+        self.dispatch(StyleChangeEvent {}.into());
+        self
+    }
+
     fn set_enabled(&mut self, enabled: bool) -> &mut Self {
         let new_enabled = enabled && self.base().is_parent_enabled();
         self.set_pseudo_class(PseudoClass::Enabled, new_enabled);
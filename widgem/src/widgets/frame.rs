@@ -0,0 +1,84 @@
+use {
+    super::{Widget, WidgetBaseOf, WidgetExt},
+    crate::impl_widget_base,
+    accesskit::Role,
+    anyhow::Result,
+    widgem_macros::impl_with,
+};
+
+/// Border style of a [Frame].
+///
+/// Each style (except the default one) corresponds to a CSS class of the `frame` element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrameStyle {
+    /// A plain border around the content.
+    #[default]
+    Box,
+    /// A border and a background that sets the content apart from the surrounding widgets.
+    Panel,
+    /// A border with rounded corners.
+    Rounded,
+    /// No border.
+    NoFrame,
+}
+
+impl FrameStyle {
+    const ALL: [FrameStyle; 4] = [
+        FrameStyle::Box,
+        FrameStyle::Panel,
+        FrameStyle::Rounded,
+        FrameStyle::NoFrame,
+    ];
+
+    fn class(self) -> Option<&'static str> {
+        match self {
+            FrameStyle::Box => None,
+            FrameStyle::Panel => Some("panel"),
+            FrameStyle::Rounded => Some("rounded"),
+            FrameStyle::NoFrame => Some("no_frame"),
+        }
+    }
+}
+
+/// A container that draws a border around its children.
+///
+/// Like [Column](super::column::Column), the frame arranges its children in a grid.
+pub struct Frame {
+    base: WidgetBaseOf<Self>,
+    frame_style: FrameStyle,
+}
+
+#[impl_with]
+impl Frame {
+    pub fn set_frame_style(&mut self, frame_style: FrameStyle) -> &mut Self {
+        if self.frame_style == frame_style {
+            return self;
+        }
+        self.frame_style = frame_style;
+        for style in FrameStyle::ALL {
+            if let Some(class) = style.class() {
+                self.set_class(class.into(), style == frame_style);
+            }
+        }
+        self
+    }
+
+    pub fn frame_style(&self) -> FrameStyle {
+        self.frame_style
+    }
+}
+
+impl Widget for Frame {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self {
+            base,
+            frame_style: FrameStyle::default(),
+        }
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::Group)))
+    }
+}
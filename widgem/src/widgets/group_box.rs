@@ -0,0 +1,308 @@
+use {
+    super::{
+        check_box::{CheckBox, CheckState},
+        column::Column,
+        label::Label,
+        Widget, WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        callback::{Callback, CallbackVec},
+        draw::DrawEvent,
+        event::{LayoutEvent, StyleChangeEvent},
+        impl_widget_base,
+        layout::{AxisPadding, SizeHints},
+        style::{
+            common::{ComputedBorderStyle, ComputedElementStyle},
+            css::{convert_border, Element, PseudoClass},
+            get_style, Style,
+        },
+        types::{PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::Role,
+    anyhow::Result,
+    std::{
+        cmp::{max, min},
+        fmt::Display,
+        rc::Rc,
+    },
+    widgem_macros::impl_with,
+};
+
+const KEY_CONTENT: u64 = 0;
+const KEY_TITLE: u64 = 1;
+const KEY_CHECK_BOX: u64 = 2;
+
+/// A container that draws a frame with a title around its content.
+///
+/// Child widgets should be added to the [content](Self::content_mut) of the group box.
+/// The content is a [Column] that arranges its children in a grid.
+///
+/// A checkable group box displays a check box in the title. When it's unchecked,
+/// the content of the group box is disabled.
+///
+/// The style of the frame is specified for the `group-box-frame` element in CSS.
+pub struct GroupBox {
+    base: WidgetBaseOf<Self>,
+    title: String,
+    is_checkable: bool,
+    on_toggled: CallbackVec<bool>,
+    frame_style: Rc<FrameStyle>,
+}
+
+#[impl_with]
+impl GroupBox {
+    pub fn content(&self) -> &Column {
+        self.base.get_child::<Column>(KEY_CONTENT).unwrap()
+    }
+
+    pub fn content_mut(&mut self) -> &mut Column {
+        self.base.get_child_mut::<Column>(KEY_CONTENT).unwrap()
+    }
+
+    fn label_mut(&mut self) -> &mut Label {
+        self.base.get_child_mut::<Label>(KEY_TITLE).unwrap()
+    }
+
+    fn check_box(&self) -> &CheckBox {
+        self.base.get_child::<CheckBox>(KEY_CHECK_BOX).unwrap()
+    }
+
+    fn check_box_mut(&mut self) -> &mut CheckBox {
+        self.base.get_child_mut::<CheckBox>(KEY_CHECK_BOX).unwrap()
+    }
+
+    pub fn set_title(&mut self, title: impl Display) -> &mut Self {
+        self.title = title.to_string();
+        let title = self.title.clone();
+        self.label_mut().set_text(&title);
+        self.check_box_mut().set_text(&title);
+        self.refresh_title_visibility();
+        self
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Displays a check box in the title. When it's unchecked, the content is disabled.
+    ///
+    /// A checkable group box is checked by default.
+    pub fn set_checkable(&mut self, checkable: bool) -> &mut Self {
+        if self.is_checkable == checkable {
+            return self;
+        }
+        self.is_checkable = checkable;
+        self.set_style_attribute("checkable".into(), checkable.then(String::new));
+        self.refresh_title_visibility();
+        self.refresh_checked_state();
+        self
+    }
+
+    pub fn is_checkable(&self) -> bool {
+        self.is_checkable
+    }
+
+    pub fn set_checked(&mut self, checked: bool) -> &mut Self {
+        self.check_box_mut().set_checked(checked);
+        self.refresh_checked_state();
+        self
+    }
+
+    /// Returns `true` if the group box is checkable and checked.
+    pub fn is_checked(&self) -> bool {
+        self.is_checkable && self.check_box().is_checked()
+    }
+
+    /// The callback is invoked when the check box in the title is toggled by the user.
+    pub fn on_toggled(&mut self, callback: Callback<bool>) -> &mut Self {
+        self.on_toggled.push(callback);
+        self
+    }
+
+    fn refresh_title_visibility(&mut self) {
+        let has_label = !self.is_checkable && !self.title.is_empty();
+        let is_checkable = self.is_checkable;
+        self.label_mut().set_visible(has_label);
+        self.check_box_mut().set_visible(is_checkable);
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    fn refresh_checked_state(&mut self) {
+        let enabled = !self.is_checkable || self.check_box().is_checked();
+        self.content_mut().set_enabled(enabled);
+        let is_checked = self.is_checked();
+        self.set_pseudo_class(PseudoClass::Checked, is_checked);
+    }
+
+    fn toggled(&mut self, state: CheckState) -> Result<()> {
+        self.refresh_checked_state();
+        self.on_toggled.invoke(state == CheckState::Checked);
+        Ok(())
+    }
+
+    fn title_key(&self) -> Option<u64> {
+        if self.is_checkable {
+            Some(KEY_CHECK_BOX)
+        } else if !self.title.is_empty() {
+            Some(KEY_TITLE)
+        } else {
+            None
+        }
+    }
+
+    // Horizontal offsets of the title from the left and right edges of the frame.
+    fn title_indent(&self) -> AxisPadding {
+        self.base.common_style.grid.x.preferred_padding
+    }
+
+    fn title_size(&mut self, size_x: PhysicalPixels) -> (PhysicalPixels, PhysicalPixels) {
+        let indent = self.title_indent();
+        let Some(key) = self.title_key() else {
+            return (0.ppx(), 0.ppx());
+        };
+        let title = self.base.get_dyn_child_mut(key).unwrap();
+        let title_size_x = max(
+            0.ppx(),
+            min(title.size_hint_x().preferred, size_x - indent.sum()),
+        );
+        let title_size_y = title.size_hint_y(title_size_x).preferred;
+        (title_size_x, title_size_y)
+    }
+}
+
+impl Widget for GroupBox {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<Column>(KEY_CONTENT)
+            .add_class("group_box_content".into());
+        base.add_child_with_key::<Label>(KEY_TITLE)
+            .add_class("group_box_title".into())
+            .set_visible(false);
+        let toggled = base.callback(Self::toggled);
+        base.add_child_with_key::<CheckBox>(KEY_CHECK_BOX)
+            .add_class("group_box_title".into())
+            .set_checked(true)
+            .on_toggled(toggled)
+            .set_visible(false);
+        Self {
+            frame_style: get_style(&frame_element(), base.scale()),
+            base,
+            title: String::new(),
+            is_checkable: false,
+            on_toggled: CallbackVec::new(),
+        }
+    }
+
+    fn handle_draw(&mut self, event: DrawEvent) -> Result<()> {
+        let size = self.base.size_or_err()?;
+        let border = &self.frame_style.border;
+        let title_rect = self.title_key().and_then(|key| {
+            self.base
+                .get_dyn_child(key)
+                .unwrap()
+                .base()
+                .rect_in_parent()
+        });
+        let frame_top = title_rect.map_or(0.ppx(), |rect| (rect.size_y() - border.width.top) / 2);
+        let frame_rect = Rect::from_xywh(0.ppx(), frame_top, size.x(), size.y() - frame_top);
+        let event = match title_rect {
+            Some(title_rect) => event.excluding_rect(title_rect),
+            None => event,
+        };
+        event.stroke_and_fill_rounded_rect(frame_rect, border, None);
+        Ok(())
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, &event.changed_size_hints);
+            }
+            return Ok(());
+        };
+        let indent = self.title_indent();
+        let (title_size_x, title_size_y) = self.title_size(geometry.size_x());
+        for key in [KEY_TITLE, KEY_CHECK_BOX] {
+            let rect = (self.title_key() == Some(key))
+                .then(|| Rect::from_xywh(indent.start, 0.ppx(), title_size_x, title_size_y));
+            self.base.get_dyn_child_mut(key).unwrap().set_geometry(
+                rect.map(|rect| WidgetGeometry::new(&geometry, rect)),
+                &event.changed_size_hints,
+            );
+        }
+        let content_rect = Rect::from_xywh(
+            0.ppx(),
+            title_size_y,
+            geometry.size_x(),
+            max(0.ppx(), geometry.size_y() - title_size_y),
+        );
+        self.content_mut().set_geometry(
+            Some(WidgetGeometry::new(&geometry, content_rect)),
+            &event.changed_size_hints,
+        );
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let indent = self.title_indent();
+        let title = match self.title_key() {
+            Some(key) => self.base.get_dyn_child_mut(key)?.size_hint_x(),
+            None => SizeHints {
+                min: 0.ppx(),
+                preferred: 0.ppx(),
+                is_fixed: true,
+            },
+        };
+        let content = self.content_mut().size_hint_x();
+        Ok(SizeHints {
+            min: max(content.min, title.min + indent.sum()),
+            preferred: max(content.preferred, title.preferred + indent.sum()),
+            is_fixed: content.is_fixed,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        let (_, title_size_y) = self.title_size(size_x);
+        let content = self.content_mut().size_hint_y(size_x);
+        Ok(SizeHints {
+            min: title_size_y + content.min,
+            preferred: title_size_y + content.preferred,
+            is_fixed: content.is_fixed,
+        })
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.frame_style = get_style(&frame_element(), self.base.scale());
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Group);
+        if !self.title.is_empty() {
+            node.set_label(self.title.clone());
+        }
+        Ok(Some(node))
+    }
+}
+
+fn frame_element() -> Element {
+    Element::new("group-box-frame".into())
+}
+
+/// Style of the frame of a [GroupBox].
+struct FrameStyle {
+    border: ComputedBorderStyle,
+}
+
+impl ComputedElementStyle for FrameStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> Self {
+        let properties = style.find_rules_for_element(element);
+        Self {
+            border: convert_border(&properties, scale, style.root_color()),
+        }
+    }
+}
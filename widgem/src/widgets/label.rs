@@ -1,16 +1,70 @@
 use {
-    super::{Widget, WidgetBaseOf, WidgetExt},
-    crate::{impl_widget_base, text_editor::Text},
-    cosmic_text::Attrs,
-    std::fmt::Display,
+    super::{RawWidgetId, Widget, WidgetBaseOf, WidgetExt},
+    crate::{
+        callback::{raw_widget_callback, Callback, CallbackVec},
+        event::{
+            FocusInEvent, FocusOutEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, MouseLeaveEvent, MouseMoveEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        layout::{
+            grid::{self, grid_layout},
+            SizeHints,
+        },
+        rich_text::{RichText, SpanStyle},
+        shared_window::SetFocusRequest,
+        shortcut::{standard_shortcuts, MnemonicText, Shortcut, ShortcutId, ShortcutScope},
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_main_color, Element, PseudoClass},
+            get_style, Style,
+        },
+        system::{send_window_request, with_system, ReportError},
+        text::{action::Action, Metadata},
+        text_editor::{convert_color, Text},
+        types::{LpxSuffix, PhysicalPixels, Point, PpxSuffix},
+    },
+    accesskit::Role,
+    anyhow::Result,
+    cosmic_text::{Attrs, Family, Metrics, Style as FontStyle, Weight, Wrap},
+    std::{cmp::min, fmt::Display, rc::Rc},
+    tiny_skia::Color,
+    widgem_macros::impl_with,
+    winit::{
+        event::{ElementState, MouseButton},
+        keyboard::{Key, NamedKey},
+        window::CursorIcon,
+    },
 };
 
+// Minimal width of a label with word wrap enabled.
+const MIN_WRAPPED_WIDTH: f32 = 80.0;
+
+/// A widget that displays plain or rich text.
+///
+/// Links in rich text can be activated with the mouse or with the keyboard: when the label
+/// has focus, arrow keys move between links and Enter or Space activates the current link.
+/// Link colors are specified for the `label-link` element in CSS.
+///
+/// A label can be a buddy of another widget (see [set_buddy](Self::set_buddy)): the mnemonic
+/// of the label focuses that widget, and the label is used as its accessible name.
 pub struct Label {
     base: WidgetBaseOf<Self>,
+    rich_text: RichText,
+    link_style: Rc<LinkStyle>,
+    is_word_wrap_enabled: bool,
+    is_selectable: bool,
+    is_selecting: bool,
+    current_link: Option<usize>,
+    pressed_link: Option<usize>,
+    on_link_activated: CallbackVec<String>,
+    mnemonic: MnemonicText,
+    mnemonic_shortcut: Option<ShortcutId>,
+    buddy: Option<RawWidgetId>,
 }
 
+#[impl_with]
 impl Label {
-    #[allow(dead_code)]
     fn text_widget(&self) -> &Text {
         self.base.get_child::<Text>(0).unwrap()
     }
@@ -19,12 +73,329 @@ impl Label {
         self.base.get_child_mut::<Text>(0).unwrap()
     }
 
+    /// Sets plain text of the label.
+    ///
+    /// An ampersand marks the next character as a mnemonic (e.g. `"&Name:"`): if the label
+    /// has a buddy, Alt and this character focus the buddy. Use `&&` to display an ampersand.
+    /// Use [set_rich_text](Self::set_rich_text) to display the text as is.
     pub fn set_text(&mut self, text: impl Display) -> &mut Self {
-        self.text_widget_mut().set_text(text, Attrs::new());
+        let mnemonic = MnemonicText::parse(&text.to_string());
+        self.set_content(RichText::from(mnemonic.text.clone()), mnemonic);
+        self
+    }
+
+    /// Returns the displayed text without styles.
+    pub fn text(&self) -> String {
+        self.rich_text.plain_text()
+    }
+
+    pub fn set_rich_text(&mut self, text: RichText) -> &mut Self {
+        self.set_content(text, MnemonicText::default());
+        self
+    }
+
+    fn set_content(&mut self, text: RichText, mnemonic: MnemonicText) {
+        self.rich_text = text;
+        self.mnemonic = mnemonic;
+        self.current_link = None;
+        self.pressed_link = None;
+        self.is_selecting = false;
+        self.refresh_text();
+        self.update_focusable();
+        self.update_mnemonic_shortcut();
         self.base.size_hint_changed();
         self.base.update();
+    }
+
+    pub fn rich_text(&self) -> &RichText {
+        &self.rich_text
+    }
+
+    /// Enables or disables wrapping long lines at word boundaries.
+    ///
+    /// If enabled, the height of the label depends on its width. Disabled by default.
+    pub fn set_word_wrap(&mut self, enabled: bool) -> &mut Self {
+        if self.is_word_wrap_enabled == enabled {
+            return self;
+        }
+        self.is_word_wrap_enabled = enabled;
+        let text = self.text_widget_mut();
+        if enabled {
+            text.set_wrap(Wrap::WordOrGlyph);
+        } else {
+            text.set_wrap(Wrap::None);
+            text.set_wrap_width(None);
+        }
+        self.base.size_hint_changed();
+        self
+    }
+
+    pub fn is_word_wrap_enabled(&self) -> bool {
+        self.is_word_wrap_enabled
+    }
+
+    /// Allows the user to select the text with the mouse and copy it to the clipboard.
+    ///
+    /// Disabled by default.
+    pub fn set_selectable(&mut self, selectable: bool) -> &mut Self {
+        if self.is_selectable == selectable {
+            return self;
+        }
+        self.is_selectable = selectable;
+        if !selectable {
+            self.is_selecting = false;
+            self.text_widget_mut().action(Action::ClearSelection);
+        }
+        self.update_focusable();
+        self.update_cursor_icon(None);
+        self
+    }
+
+    pub fn is_selectable(&self) -> bool {
+        self.is_selectable
+    }
+
+    /// Sets the widget that is focused when the mnemonic of the label is activated.
+    ///
+    /// The label is also reported as the accessible label of the buddy.
+    pub fn set_buddy(&mut self, buddy: Option<RawWidgetId>) -> &mut Self {
+        if self.buddy == buddy {
+            return self;
+        }
+        let id = self.base.id().raw();
+        if let Some(old) = self.buddy {
+            raw_widget_callback(old, move |widget, ()| {
+                if widget.base().labelled_by() == Some(id) {
+                    widget.base_mut().set_labelled_by(None);
+                }
+                Ok(())
+            })
+            .invoke(());
+        }
+        if let Some(new) = buddy {
+            raw_widget_callback(new, move |widget, ()| {
+                widget.base_mut().set_labelled_by(Some(id));
+                Ok(())
+            })
+            .invoke(());
+        }
+        self.buddy = buddy;
+        self.update_mnemonic_shortcut();
         self
     }
+
+    pub fn buddy(&self) -> Option<RawWidgetId> {
+        self.buddy
+    }
+
+    fn update_mnemonic_shortcut(&mut self) {
+        if let Some(id) = self.mnemonic_shortcut.take() {
+            self.base.remove_shortcut(id);
+        }
+        if self.buddy.is_none() {
+            return;
+        }
+        if let Some(key_combinations) = self.mnemonic.key_combinations() {
+            let callback = self.callback(|this, ()| {
+                this.activate_mnemonic();
+                Ok(())
+            });
+            let id = self.base.add_shortcut(Shortcut::new(
+                key_combinations,
+                ShortcutScope::Window,
+                callback,
+            ));
+            self.mnemonic_shortcut = Some(id);
+        }
+    }
+
+    fn activate_mnemonic(&mut self) {
+        let (Some(buddy), Some(window_id)) = (self.buddy, self.base.window_id()) else {
+            return;
+        };
+        if !self.base.is_enabled() || self.base.size().is_none() {
+            return;
+        }
+        send_window_request(
+            window_id,
+            SetFocusRequest {
+                widget_id: buddy,
+                reason: FocusReason::Mnemonic,
+            },
+        );
+    }
+
+    /// Called with the link target when a link is activated.
+    pub fn on_link_activated(&mut self, callback: Callback<String>) -> &mut Self {
+        self.on_link_activated.push(callback);
+        self
+    }
+
+    fn num_links(&self) -> usize {
+        self.rich_text.links().count()
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.is_selectable || self.num_links() > 0
+    }
+
+    fn update_focusable(&mut self) {
+        let focusable = self.is_interactive();
+        self.base.set_supports_focus(focusable);
+    }
+
+    fn refresh_text(&mut self) {
+        let metrics = self.text_widget().font_metrics();
+        let focused_link = self.current_link.filter(|_| self.base.is_focused());
+        let mnemonic = self
+            .mnemonic
+            .mnemonic
+            .map(|start| start..start + self.mnemonic.mnemonic_char().map_or(0, char::len_utf8));
+        let mut link_index = 0;
+        let mut offset = 0;
+        let mut spans = Vec::new();
+        for span in self.rich_text.spans() {
+            let link = if span.style.link.is_some() {
+                link_index += 1;
+                Some(link_index - 1)
+            } else {
+                None
+            };
+            let link_color = link.and_then(|link| {
+                if Some(link) == focused_link {
+                    self.link_style.focused_color
+                } else {
+                    self.link_style.color
+                }
+            });
+            let attrs = span_attrs(&span.style, metrics, link, link_color);
+            let range = offset..offset + span.text.len();
+            offset = range.end;
+            match &mnemonic {
+                Some(mnemonic) if range.contains(&mnemonic.start) => {
+                    let start = mnemonic.start - range.start;
+                    let end = mnemonic.end - range.start;
+                    let mnemonic_attrs = attrs
+                        .clone()
+                        .metadata(Metadata(attrs.metadata).with_mnemonic(true).into());
+                    spans.push((&span.text[..start], attrs.clone()));
+                    spans.push((&span.text[start..end], mnemonic_attrs));
+                    spans.push((&span.text[end..], attrs));
+                }
+                _ => spans.push((span.text.as_str(), attrs)),
+            }
+        }
+        self.base
+            .get_child_mut::<Text>(0)
+            .unwrap()
+            .set_rich_text(spans);
+    }
+
+    fn set_current_link(&mut self, link: Option<usize>) {
+        if self.current_link == link {
+            return;
+        }
+        self.current_link = link;
+        self.refresh_text();
+    }
+
+    fn activate_link(&mut self, index: usize) {
+        if let Some(link) = self
+            .rich_text
+            .links()
+            .nth(index)
+            .and_then(|span| span.style.link.clone())
+        {
+            self.on_link_activated.invoke(link);
+        }
+    }
+
+    // Converts a position in the label to a position in the text widget.
+    fn pos_in_text(&self, pos: Point) -> Option<Point> {
+        let rect = self.text_widget().base().rect_in_parent()?;
+        Some(pos - rect.top_left())
+    }
+
+    fn link_at(&self, pos: Point) -> Option<usize> {
+        let pos = self.pos_in_text(pos)?;
+        self.text_widget().metadata_at(pos).and_then(Metadata::link)
+    }
+
+    fn update_cursor_icon(&mut self, hovered_link: Option<usize>) {
+        let icon = if hovered_link.is_some() {
+            CursorIcon::Pointer
+        } else if self.is_selectable {
+            CursorIcon::Text
+        } else {
+            CursorIcon::Default
+        };
+        if self.base.cursor_icon() == icon {
+            return;
+        }
+        self.base.set_cursor_icon(icon);
+        self.text_widget_mut().base_mut().set_cursor_icon(icon);
+        if self.base.is_under_mouse() {
+            if let Some(window) = &self.base.window {
+                window.set_cursor(icon);
+            }
+        }
+    }
+
+    fn request_focus(&self) -> Result<()> {
+        send_window_request(
+            self.base.window_or_err()?.id(),
+            SetFocusRequest {
+                widget_id: self.base.id().into(),
+                reason: FocusReason::Mouse,
+            },
+        );
+        Ok(())
+    }
+
+    fn padding(&self) -> (PhysicalPixels, PhysicalPixels) {
+        let grid = &self.base.common_style.grid;
+        (
+            grid.x.preferred_padding.sum(),
+            grid.y.preferred_padding.sum(),
+        )
+    }
+}
+
+// Converts the style of a span to text attributes.
+fn span_attrs(
+    style: &SpanStyle,
+    metrics: Metrics,
+    link: Option<usize>,
+    link_color: Option<Color>,
+) -> Attrs<'static> {
+    let mut attrs = Attrs::new();
+    if style.bold {
+        attrs = attrs.weight(Weight::BOLD);
+    }
+    if style.italic {
+        attrs = attrs.style(FontStyle::Italic);
+    }
+    if style.monospace {
+        attrs = attrs.family(Family::Monospace);
+    }
+    if let Some(scale) = style.font_scale {
+        attrs = attrs.metrics(Metrics::new(
+            metrics.font_size * scale,
+            metrics.line_height * scale,
+        ));
+    }
+    if let Some(color) = style.color.or(link_color) {
+        attrs = attrs.color(convert_color(color));
+    }
+    if link.is_some() {
+        attrs = attrs.metadata(
+            Metadata::default()
+                .with_underline(true)
+                .with_link(link)
+                .into(),
+        );
+    }
+    attrs
 }
 
 impl Widget for Label {
@@ -38,6 +409,217 @@ impl Widget for Label {
             .set_row(0)
             .set_host_id(id)
             .set_host_style_element(element);
-        Self { base }
+        Self {
+            link_style: get_style(&link_element(), base.scale()),
+            base,
+            rich_text: RichText::new(),
+            is_word_wrap_enabled: false,
+            is_selectable: false,
+            is_selecting: false,
+            current_link: None,
+            pressed_link: None,
+            on_link_activated: CallbackVec::new(),
+            mnemonic: MnemonicText::default(),
+            mnemonic_shortcut: None,
+            buddy: None,
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        if self.is_word_wrap_enabled {
+            let width = self
+                .base
+                .size()
+                .map(|size| (size.x() - self.padding().0).max(0.ppx()));
+            self.text_widget_mut().set_wrap_width(width);
+        }
+        grid_layout(self, &event.changed_size_hints);
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let options = self.base.common_style.grid.clone();
+        let hints = grid::size_hint_x(&mut self.base.children, &options);
+        if !self.is_word_wrap_enabled {
+            return Ok(hints);
+        }
+        let padding = self.padding().0;
+        let preferred = self.text_widget_mut().measure(None).x() + padding;
+        let min_wrapped = MIN_WRAPPED_WIDTH.lpx().to_physical(self.base.scale()) + padding;
+        Ok(SizeHints {
+            min: min(preferred, min_wrapped),
+            preferred,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        if !self.is_word_wrap_enabled {
+            let options = self.base.common_style.grid.clone();
+            return Ok(grid::size_hint_y(&mut self.base.children, &options, size_x));
+        }
+        let (padding_x, padding_y) = self.padding();
+        let width = (size_x - padding_x).max(1.ppx());
+        let size = self.text_widget_mut().measure(Some(width)).y() + padding_y;
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.link_style = get_style(&link_element(), self.base.scale());
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.refresh_text();
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if !self.is_interactive() {
+            return Ok(false);
+        }
+        let link = self.link_at(event.pos);
+        self.update_cursor_icon(link);
+        if self.is_selecting {
+            if let Some(pos) = self.pos_in_text(event.pos) {
+                self.text_widget_mut().action(Action::Drag {
+                    x: pos.x().to_i32(),
+                    y: pos.y().to_i32(),
+                });
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_leave(&mut self, _event: MouseLeaveEvent) -> Result<()> {
+        self.update_cursor_icon(None);
+        Ok(())
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.is_interactive() || event.button != MouseButton::Left {
+            return Ok(false);
+        }
+        let link = self.link_at(event.pos);
+        match event.state {
+            ElementState::Pressed => {
+                self.pressed_link = link;
+                if let Some(link) = link {
+                    self.request_focus()?;
+                    self.set_current_link(Some(link));
+                } else if self.is_selectable {
+                    self.request_focus()?;
+                    if let Some(pos) = self.pos_in_text(event.pos) {
+                        let x = pos.x().to_i32();
+                        let y = pos.y().to_i32();
+                        let select = self.base.window_or_err()?.modifiers().shift_key();
+                        let action = match ((event.num_clicks - 1) % 3) + 1 {
+                            1 => Action::Click { x, y, select },
+                            2 => Action::DoubleClick { x, y },
+                            _ => Action::TripleClick { x, y },
+                        };
+                        self.text_widget_mut().action(action);
+                        self.is_selecting = true;
+                    }
+                }
+            }
+            ElementState::Released => {
+                self.is_selecting = false;
+                if let Some(pressed_link) = self.pressed_link.take() {
+                    if link == Some(pressed_link) {
+                        self.activate_link(pressed_link);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if event.info.state != ElementState::Pressed {
+            return Ok(false);
+        }
+        if self.is_selectable {
+            let shortcuts = standard_shortcuts();
+            if shortcuts.copy.matches(&event) {
+                if let Some(text) = self.text_widget_mut().selected_text() {
+                    with_system(|system| system.clipboard.set_text(text)).or_report_err();
+                }
+                return Ok(true);
+            }
+            if shortcuts.select_all.matches(&event) {
+                self.text_widget_mut().action(Action::SelectAll);
+                return Ok(true);
+            }
+        }
+        let num_links = self.num_links();
+        if num_links == 0 {
+            return Ok(false);
+        }
+        match &event.info.logical_key {
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowDown) => {
+                let link = self.current_link.map_or(0, |link| (link + 1) % num_links);
+                self.set_current_link(Some(link));
+            }
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowUp) => {
+                let link = self
+                    .current_link
+                    .map_or(num_links - 1, |link| (link + num_links - 1) % num_links);
+                self.set_current_link(Some(link));
+            }
+            Key::Named(NamedKey::Enter | NamedKey::Space) => {
+                let Some(link) = self.current_link else {
+                    return Ok(false);
+                };
+                self.activate_link(link);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_focus_in(&mut self, event: FocusInEvent) -> Result<()> {
+        if self.current_link.is_none() && event.reason != FocusReason::Mouse && self.num_links() > 0
+        {
+            self.current_link = Some(0);
+        }
+        self.refresh_text();
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Label);
+        node.set_value(self.text());
+        Ok(Some(node))
+    }
+
+    fn handle_focus_out(&mut self, _event: FocusOutEvent) -> Result<()> {
+        self.is_selecting = false;
+        self.text_widget_mut().handle_host_focus_out()?;
+        self.refresh_text();
+        Ok(())
+    }
+}
+
+fn link_element() -> Element {
+    Element::new("label-link".into())
+}
+
+/// Style of links in a [Label].
+struct LinkStyle {
+    color: Option<Color>,
+    focused_color: Option<Color>,
+}
+
+impl ComputedElementStyle for LinkStyle {
+    fn new(style: &Style, element: &Element, _scale: f32) -> Self {
+        let focused = element.clone().with_pseudo_class(PseudoClass::Focus);
+        Self {
+            color: convert_main_color(&style.find_rules_for_element(element)),
+            focused_color: convert_main_color(&style.find_rules_for_element(&focused)),
+        }
     }
 }
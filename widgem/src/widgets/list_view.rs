@@ -0,0 +1,900 @@
+use {
+    super::{
+        scroll_bar::ScrollBar, Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        callback::{Callback, CallbackVec, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, MouseScrollEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        layout::{grid::grid_layout, SizeHints},
+        shared_window::{ScrollToRectRequest, SetFocusRequest},
+        shortcut::standard_shortcuts,
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_font, convert_height, convert_width, Element, PseudoClass},
+            defaults::{
+                DEFAULT_MIN_HEIGHT_EM, DEFAULT_MIN_WIDTH_EM, DEFAULT_PREFERRED_HEIGHT_EM,
+                DEFAULT_PREFERRED_WIDTH_EM,
+            },
+            get_style, Style,
+        },
+        system::{send_window_request, ReportError},
+        text_editor::Text,
+        types::{Axis, PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::{Action, Role},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    log::warn,
+    std::{
+        cmp::{max, min},
+        collections::BTreeSet,
+        fmt::Display,
+        ops::Range,
+        rc::Rc,
+    },
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key as KeyboardKey, ModifiersState, NamedKey},
+    },
+};
+
+/// Source of the rows displayed by a [ListView].
+///
+/// The list view only requests data for the rows that are currently visible, so the model
+/// can contain a large number of rows. When the data changes, the model must notify the list view
+/// using [ListView::handle_model_change] or the callback returned by [ListView::model_change_callback].
+pub trait ListModel {
+    fn row_count(&self) -> usize;
+    fn data(&self, row: usize) -> String;
+}
+
+impl<T: Display> ListModel for Vec<T> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn data(&self, row: usize) -> String {
+        self[row].to_string()
+    }
+}
+
+/// Description of a change in a [ListModel].
+///
+/// Ranges are specified in terms of row indexes.
+/// For [RowsRemoved](Self::RowsRemoved), indexes refer to the rows before the removal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListModelChange {
+    /// Any part of the model could have changed.
+    Reset,
+    RowsInserted(Range<usize>),
+    RowsRemoved(Range<usize>),
+    RowsChanged(Range<usize>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    None,
+    /// At most one row can be selected.
+    #[default]
+    Single,
+    /// Any number of rows can be selected using Ctrl and Shift modifiers.
+    Multi,
+}
+
+const VIEWPORT_KEY: u64 = 0;
+const SCROLL_BAR_KEY: u64 = 1;
+
+/// A scrollable list of text rows backed by a [ListModel].
+///
+/// Widgets are only created for the rows that are visible, and they are reused
+/// for other rows when the list is scrolled.
+pub struct ListView {
+    base: WidgetBaseOf<Self>,
+    model: Option<Rc<dyn ListModel>>,
+    selection_mode: SelectionMode,
+    current_row: Option<usize>,
+    selected_rows: BTreeSet<usize>,
+    // Starting row for range selection with Shift.
+    anchor_row: Option<usize>,
+    row_height: PhysicalPixels,
+    current_changed: Callbacks<Option<usize>>,
+    selection_changed: Callbacks<()>,
+    activated: CallbackVec<usize>,
+    style: Rc<ListViewStyle>,
+}
+
+#[impl_with]
+impl ListView {
+    fn viewport(&self) -> &ListViewport {
+        self.base.get_child::<ListViewport>(VIEWPORT_KEY).unwrap()
+    }
+
+    fn viewport_mut(&mut self) -> &mut ListViewport {
+        self.base
+            .get_child_mut::<ListViewport>(VIEWPORT_KEY)
+            .unwrap()
+    }
+
+    fn scroll_bar(&self) -> &ScrollBar {
+        self.base.get_child::<ScrollBar>(SCROLL_BAR_KEY).unwrap()
+    }
+
+    fn scroll_bar_mut(&mut self) -> &mut ScrollBar {
+        self.base
+            .get_child_mut::<ScrollBar>(SCROLL_BAR_KEY)
+            .unwrap()
+    }
+
+    pub fn set_model(&mut self, model: Rc<dyn ListModel>) -> &mut Self {
+        self.model = Some(model);
+        self.handle_model_change(ListModelChange::Reset);
+        self
+    }
+
+    pub fn model(&self) -> Option<&Rc<dyn ListModel>> {
+        self.model.as_ref()
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.model.as_ref().map_or(0, |model| model.row_count())
+    }
+
+    /// Updates the list view after the model has changed.
+    ///
+    /// Selected and current rows are adjusted to follow inserted and removed rows.
+    pub fn handle_model_change(&mut self, change: ListModelChange) {
+        let old_current = self.current_row;
+        let old_selection = self.selected_rows.clone();
+        match change {
+            ListModelChange::Reset => {
+                self.current_row = None;
+                self.anchor_row = None;
+                self.selected_rows.clear();
+            }
+            ListModelChange::RowsInserted(range) => {
+                let shift = |row: usize| {
+                    if row >= range.start {
+                        row + range.len()
+                    } else {
+                        row
+                    }
+                };
+                self.current_row = self.current_row.map(shift);
+                self.anchor_row = self.anchor_row.map(shift);
+                self.selected_rows = self.selected_rows.iter().copied().map(shift).collect();
+            }
+            ListModelChange::RowsRemoved(range) => {
+                let shift = |row: usize| {
+                    if range.contains(&row) {
+                        None
+                    } else if row >= range.end {
+                        Some(row - range.len())
+                    } else {
+                        Some(row)
+                    }
+                };
+                let row_count = self.row_count();
+                self.current_row = self.current_row.and_then(|row| {
+                    shift(row).or_else(|| {
+                        // Current row was removed: move to the row that took its place.
+                        (row_count > 0).then(|| min(range.start, row_count - 1))
+                    })
+                });
+                self.anchor_row = self.anchor_row.and_then(shift);
+                self.selected_rows = self
+                    .selected_rows
+                    .iter()
+                    .copied()
+                    .filter_map(shift)
+                    .collect();
+            }
+            ListModelChange::RowsChanged(_) => {}
+        }
+        if self.current_row != old_current {
+            self.current_changed.invoke(self.current_row, true);
+        }
+        if self.selected_rows != old_selection {
+            self.selection_changed.invoke((), true);
+        }
+        self.layout_rows(&[]).or_report_err();
+        self.base.update();
+    }
+
+    /// Returns a callback that calls [handle_model_change](Self::handle_model_change)
+    /// on this list view. It can be stored in the model.
+    pub fn model_change_callback(&self) -> Callback<ListModelChange> {
+        self.callback(|this, change| {
+            this.handle_model_change(change);
+            Ok(())
+        })
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) -> &mut Self {
+        self.selection_mode = mode;
+        let new_selection = match mode {
+            SelectionMode::None => BTreeSet::new(),
+            SelectionMode::Single => self.selected_rows.first().copied().into_iter().collect(),
+            SelectionMode::Multi => self.selected_rows.clone(),
+        };
+        self.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// Sets the row that has keyboard focus within the list and scrolls to it.
+    ///
+    /// Selection is not changed.
+    pub fn set_current_row(&mut self, row: Option<usize>) -> &mut Self {
+        self.set_current_row_internal(row, true);
+        self
+    }
+
+    pub fn current_row(&self) -> Option<usize> {
+        self.current_row
+    }
+
+    pub fn selected_rows(&self) -> &BTreeSet<usize> {
+        &self.selected_rows
+    }
+
+    pub fn is_row_selected(&self, row: usize) -> bool {
+        self.selected_rows.contains(&row)
+    }
+
+    pub fn set_row_selected(&mut self, row: usize, selected: bool) -> &mut Self {
+        if row >= self.row_count() {
+            warn!("row index out of bounds");
+            return self;
+        }
+        let mut new_selection = match self.selection_mode {
+            SelectionMode::None => {
+                warn!("cannot select rows when selection mode is None");
+                return self;
+            }
+            SelectionMode::Single => BTreeSet::new(),
+            SelectionMode::Multi => self.selected_rows.clone(),
+        };
+        if selected {
+            new_selection.insert(row);
+        } else {
+            new_selection.remove(&row);
+        }
+        self.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.set_selection(BTreeSet::new(), true);
+        self
+    }
+
+    /// Selects all rows. Has no effect unless selection mode is [Multi](SelectionMode::Multi).
+    pub fn select_all(&mut self) -> &mut Self {
+        self.select_all_internal(true);
+        self
+    }
+
+    pub fn on_current_changed(&mut self, callback: Callback<Option<usize>>) -> &mut Self {
+        self.current_changed.add(callback);
+        self
+    }
+
+    pub fn on_selection_changed(&mut self, callback: Callback<()>) -> &mut Self {
+        self.selection_changed.add(callback);
+        self
+    }
+
+    /// Called when a row is double-clicked or Enter is pressed on the current row.
+    pub fn on_activated(&mut self, callback: Callback<usize>) -> &mut Self {
+        self.activated.push(callback);
+        self
+    }
+
+    /// Scrolls the list so that `row` is visible.
+    pub fn scroll_to_row(&mut self, row: usize) -> &mut Self {
+        if row >= self.row_count() {
+            warn!("row index out of bounds");
+            return self;
+        }
+        let Some(viewport_rect) = self.viewport().base.rect_in_parent() else {
+            return self;
+        };
+        let offset = self.scroll_bar().value();
+        let top = (self.row_height.to_i32()).saturating_mul(row as i32);
+        let bottom = top.saturating_add(self.row_height.to_i32());
+        let new_offset = if top < offset {
+            top
+        } else if bottom > offset + viewport_rect.size_y().to_i32() {
+            min(top, bottom - viewport_rect.size_y().to_i32())
+        } else {
+            offset
+        };
+        if new_offset != offset {
+            self.scroll_bar_mut().set_value(new_offset);
+            self.layout_rows(&[]).or_report_err();
+        }
+
+        // Let outer scroll areas reveal the row as well.
+        if let Some(window_id) = self.base.window_id() {
+            let rect = Rect::from_xywh(
+                viewport_rect.left(),
+                viewport_rect.top() + PhysicalPixels::from_i32(top - new_offset),
+                viewport_rect.size_x(),
+                self.row_height,
+            );
+            send_window_request(
+                window_id,
+                ScrollToRectRequest {
+                    widget_id: self.base.id().into(),
+                    rect,
+                },
+            );
+        }
+        self
+    }
+
+    fn set_current_row_internal(&mut self, row: Option<usize>, from_setter: bool) {
+        if row.is_some_and(|row| row >= self.row_count()) {
+            warn!("row index out of bounds");
+            return;
+        }
+        if let Some(row) = row {
+            self.scroll_to_row(row);
+        }
+        if self.current_row == row {
+            return;
+        }
+        self.current_row = row;
+        self.current_changed.invoke(row, from_setter);
+        self.update_slots();
+    }
+
+    fn set_selection(&mut self, selection: BTreeSet<usize>, from_setter: bool) {
+        if self.selected_rows == selection {
+            return;
+        }
+        self.selected_rows = selection;
+        self.selection_changed.invoke((), from_setter);
+        self.update_slots();
+    }
+
+    fn select_all_internal(&mut self, from_setter: bool) {
+        if self.selection_mode != SelectionMode::Multi {
+            return;
+        }
+        self.set_selection((0..self.row_count()).collect(), from_setter);
+    }
+
+    // Changes current row and selection in response to a mouse click or a key press.
+    fn select_by_user(&mut self, row: usize, modifiers: ModifiersState, toggle_with_ctrl: bool) {
+        let shift = modifiers.shift_key();
+        let ctrl = modifiers.control_key() || modifiers.super_key();
+        match self.selection_mode {
+            SelectionMode::None => {}
+            SelectionMode::Single => {
+                self.set_selection([row].into(), false);
+            }
+            SelectionMode::Multi => {
+                if shift {
+                    let anchor = self.anchor_row.unwrap_or(row);
+                    let mut new_selection = if ctrl {
+                        self.selected_rows.clone()
+                    } else {
+                        BTreeSet::new()
+                    };
+                    new_selection.extend(min(anchor, row)..=max(anchor, row));
+                    self.anchor_row = Some(anchor);
+                    self.set_selection(new_selection, false);
+                } else if ctrl {
+                    if toggle_with_ctrl {
+                        self.toggle_row_selected(row);
+                    }
+                    self.anchor_row = Some(row);
+                } else {
+                    self.anchor_row = Some(row);
+                    self.set_selection([row].into(), false);
+                }
+            }
+        }
+        self.set_current_row_internal(Some(row), false);
+    }
+
+    fn toggle_row_selected(&mut self, row: usize) {
+        let mut new_selection = self.selected_rows.clone();
+        if !new_selection.remove(&row) {
+            new_selection.insert(row);
+        }
+        self.set_selection(new_selection, false);
+    }
+
+    fn row_at(&self, y: PhysicalPixels) -> Option<usize> {
+        let viewport_rect = self.viewport().base.rect_in_parent()?;
+        if y < viewport_rect.top() || y >= viewport_rect.bottom() {
+            return None;
+        }
+        let offset = (y - viewport_rect.top()).to_i32() + self.scroll_bar().value();
+        let row = (offset / max(1, self.row_height.to_i32())) as usize;
+        (row < self.row_count()).then_some(row)
+    }
+
+    fn rows_per_page(&self) -> usize {
+        let height = self
+            .viewport()
+            .base
+            .size()
+            .map_or(0, |size| size.y().to_i32());
+        max(1, height / max(1, self.row_height.to_i32())) as usize
+    }
+
+    fn focus(&mut self) -> Result<()> {
+        if !self.base.is_focused() && self.base.is_focusable() {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn row_data(&self, row: usize) -> String {
+        self.model
+            .as_ref()
+            .map(|model| model.data(row))
+            .unwrap_or_default()
+    }
+
+    // Returns the height of a row, creating the first slot if necessary.
+    fn measure_row_height(&mut self, width: PhysicalPixels) -> PhysicalPixels {
+        let viewport = self.viewport_mut();
+        if !viewport.base.has_child(0u64) {
+            viewport.base.add_child_with_key::<ListRow>(0u64);
+        }
+        let slot = viewport.base.get_dyn_child_mut(0u64).unwrap();
+        max(1.ppx(), slot.size_hint_y(width).preferred)
+    }
+
+    fn layout_rows(&mut self, changed_size_hints: &[WidgetAddress]) -> Result<()> {
+        let Some(viewport_geometry) = self.viewport().base.geometry.clone() else {
+            for slot in self.viewport_mut().base.children.values_mut() {
+                slot.set_geometry(None, changed_size_hints);
+            }
+            return Ok(());
+        };
+        let viewport_size = viewport_geometry.size();
+        let row_count = self.row_count();
+        self.row_height = self.measure_row_height(viewport_size.x());
+        let row_height = self.row_height.to_i32();
+
+        let content_height = row_height.saturating_mul(row_count.try_into().unwrap_or(i32::MAX));
+        let max_offset = max(0, content_height - viewport_size.y().to_i32());
+        let scroll_bar = self.scroll_bar_mut();
+        scroll_bar
+            .set_value_range(0..=max_offset)
+            .set_step(row_height);
+        let offset = scroll_bar.value();
+
+        // Each visible row is displayed by the slot `row % num_slots`, so only the slots
+        // of the rows that became visible need to be updated when the list is scrolled.
+        let num_slots = min(
+            row_count,
+            (viewport_size.y().to_i32() / row_height) as usize + 2,
+        );
+        let first_row = (offset / row_height) as usize;
+        let visible_rows = first_row..min(row_count, first_row + num_slots);
+
+        let viewport = self.viewport_mut();
+        let num_existing = viewport.base.children.len();
+        for slot in max(1, num_slots)..num_existing {
+            viewport.base.remove_child(slot)?;
+        }
+        for slot in num_existing..num_slots {
+            viewport.base.add_child_with_key::<ListRow>(slot);
+        }
+        let mut used_slots = vec![false; max(1, num_slots)];
+        for row in visible_rows {
+            let slot = row % num_slots;
+            used_slots[slot] = true;
+            let data = self.row_data(row);
+            let is_selected = self.selected_rows.contains(&row);
+            let is_current = self.current_row == Some(row);
+            let rect = Rect::from_xywh(
+                0.ppx(),
+                PhysicalPixels::from_i32(row_height * row as i32 - offset),
+                viewport_size.x(),
+                self.row_height,
+            );
+            let list_row = self.viewport_mut().slot_mut(slot)?;
+            list_row
+                .set_row(row, row_count, data)
+                .set_selected(is_selected)
+                .set_current(is_current)
+                .set_visible(true);
+            list_row.set_geometry(
+                Some(WidgetGeometry::new(&viewport_geometry, rect)),
+                changed_size_hints,
+            );
+        }
+        for (slot, is_used) in used_slots.into_iter().enumerate() {
+            if !is_used {
+                let list_row = self.viewport_mut().slot_mut(slot)?;
+                list_row.set_visible(false);
+                list_row.set_geometry(None, changed_size_hints);
+            }
+        }
+        Ok(())
+    }
+
+    // Updates selected and current state of the displayed rows.
+    fn update_slots(&mut self) {
+        let selected_rows = self.selected_rows.clone();
+        let current_row = self.current_row;
+        for slot in self.viewport_mut().base.children.values_mut() {
+            let Some(list_row) = slot.downcast_mut::<ListRow>() else {
+                continue;
+            };
+            let Some(row) = list_row.row else {
+                continue;
+            };
+            list_row
+                .set_selected(selected_rows.contains(&row))
+                .set_current(current_row == Some(row));
+        }
+        self.base.update();
+    }
+}
+
+impl Widget for ListView {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child_with_key::<ListViewport>(VIEWPORT_KEY)
+            .set_column(0)
+            .set_row(0);
+        let relayout = base.callback(|this: &mut Self, _| this.layout_rows(&[]));
+        base.add_child_with_key::<ScrollBar>(SCROLL_BAR_KEY)
+            .set_column(1)
+            .set_row(0)
+            .set_axis(Axis::Y)
+            .on_value_changed(relayout);
+        Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            model: None,
+            selection_mode: SelectionMode::default(),
+            current_row: None,
+            selected_rows: BTreeSet::new(),
+            anchor_row: None,
+            row_height: 1.ppx(),
+            current_changed: Callbacks::default(),
+            selection_changed: Callbacks::default(),
+            activated: CallbackVec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        self.layout_rows(&event.changed_size_hints)
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.style = get_style(self.base().style_element(), self.base().scale());
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_width,
+            preferred: self.style.preferred_width,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_height,
+            preferred: self.style.preferred_height,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button != MouseButton::Left || !event.state.is_pressed() {
+            return Ok(true);
+        }
+        self.focus()?;
+        let Some(row) = self.row_at(event.pos.y()) else {
+            return Ok(true);
+        };
+        if event.num_clicks >= 2 {
+            self.activated.invoke(row);
+            return Ok(true);
+        }
+        let modifiers = self.base.window_or_err()?.modifiers();
+        self.select_by_user(row, modifiers, true);
+        Ok(true)
+    }
+
+    fn handle_mouse_scroll(&mut self, event: MouseScrollEvent) -> Result<bool> {
+        let delta = event.unified_delta(&self.base);
+        let scroll_bar = self.scroll_bar_mut();
+        let range = scroll_bar.value_range();
+        let new_value =
+            (scroll_bar.value() - delta.y.round() as i32).clamp(*range.start(), *range.end());
+        scroll_bar.set_value(new_value);
+        self.layout_rows(&[])?;
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() || !self.base.is_enabled() {
+            return Ok(false);
+        }
+        if standard_shortcuts().select_all.matches(&event) {
+            self.select_all_internal(false);
+            return Ok(true);
+        }
+        let row_count = self.row_count();
+        if row_count == 0 {
+            return Ok(false);
+        }
+        let current = self.current_row;
+        let last = row_count - 1;
+        let new_row = match &event.info.logical_key {
+            KeyboardKey::Named(NamedKey::ArrowUp) => current.map_or(0, |row| row.saturating_sub(1)),
+            KeyboardKey::Named(NamedKey::ArrowDown) => current.map_or(0, |row| min(row + 1, last)),
+            KeyboardKey::Named(NamedKey::PageUp) => {
+                current.map_or(0, |row| row.saturating_sub(self.rows_per_page()))
+            }
+            KeyboardKey::Named(NamedKey::PageDown) => {
+                current.map_or(0, |row| min(row + self.rows_per_page(), last))
+            }
+            KeyboardKey::Named(NamedKey::Home) => 0,
+            KeyboardKey::Named(NamedKey::End) => last,
+            KeyboardKey::Named(NamedKey::Space) => {
+                if let Some(row) = current {
+                    if self.selection_mode == SelectionMode::Multi {
+                        self.anchor_row = Some(row);
+                        self.toggle_row_selected(row);
+                    } else {
+                        self.select_by_user(row, ModifiersState::empty(), false);
+                    }
+                }
+                return Ok(true);
+            }
+            KeyboardKey::Named(NamedKey::Enter) => {
+                if let Some(row) = current {
+                    self.activated.invoke(row);
+                }
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+        self.select_by_user(new_row, event.modifiers, false);
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        if event.action == Action::Focus {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    // TODO: separate reason?
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::ListBox);
+        node.set_size_of_set(self.row_count());
+        if self.selection_mode == SelectionMode::Multi {
+            node.set_multiselectable();
+        }
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListViewStyle {
+    pub min_width: PhysicalPixels,
+    pub preferred_width: PhysicalPixels,
+    pub min_height: PhysicalPixels,
+    pub preferred_height: PhysicalPixels,
+}
+
+impl ComputedElementStyle for ListViewStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> ListViewStyle {
+        let element_min = element
+            .clone()
+            .with_pseudo_class(PseudoClass::Custom("min".into()));
+
+        let properties = style.find_rules(|s| element.matches(s));
+        let font = convert_font(&properties, Some(&style.root_font_style()));
+        let preferred_width = convert_width(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in list view css");
+                (font.font_size * DEFAULT_PREFERRED_WIDTH_EM).to_physical(scale)
+            });
+        let preferred_height = convert_height(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in list view css");
+                (font.font_size * DEFAULT_PREFERRED_HEIGHT_EM).to_physical(scale)
+            });
+
+        let min_properties = style.find_rules(|s| element_min.matches(s));
+        let min_width = convert_width(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in list view min css");
+                (font.font_size * DEFAULT_MIN_WIDTH_EM).to_physical(scale)
+            });
+        let min_height = convert_height(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in list view min css");
+                (font.font_size * DEFAULT_MIN_HEIGHT_EM).to_physical(scale)
+            });
+
+        Self {
+            min_width,
+            preferred_width,
+            min_height,
+            preferred_height,
+        }
+    }
+}
+
+/// Container of the row widgets. Positions of the rows are set by [ListView].
+struct ListViewport {
+    base: WidgetBaseOf<Self>,
+}
+
+impl ListViewport {
+    fn slot_mut(&mut self, slot: usize) -> Result<&mut ListRow> {
+        self.base.get_child_mut::<ListRow>(slot)
+    }
+}
+
+impl Widget for ListViewport {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_layout(&mut self, _event: LayoutEvent) -> Result<()> {
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: 0.ppx(),
+            preferred: 0.ppx(),
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: 0.ppx(),
+            preferred: 0.ppx(),
+            is_fixed: false,
+        })
+    }
+}
+
+const TEXT_KEY: u64 = 0;
+
+/// Widget that displays one row of a [ListView]. It's reused for different rows on scroll.
+struct ListRow {
+    base: WidgetBaseOf<Self>,
+    row: Option<usize>,
+    row_count: usize,
+    is_selected: bool,
+    data: String,
+}
+
+impl ListRow {
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(TEXT_KEY).unwrap()
+    }
+
+    fn set_row(&mut self, row: usize, row_count: usize, data: String) -> &mut Self {
+        if self.row == Some(row) && self.row_count == row_count && self.data == data {
+            return self;
+        }
+        if self.data != data {
+            self.text_widget_mut().set_text(&data, Attrs::new());
+            self.data = data;
+        }
+        self.row = Some(row);
+        self.row_count = row_count;
+        self.base.update();
+        self
+    }
+
+    fn set_selected(&mut self, value: bool) -> &mut Self {
+        if self.is_selected != value {
+            self.is_selected = value;
+            self.base.update();
+        }
+        self.set_pseudo_class(PseudoClass::Custom("selected".into()), value);
+        self
+    }
+
+    fn set_current(&mut self, value: bool) -> &mut Self {
+        self.set_pseudo_class(PseudoClass::Custom("current".into()), value);
+        self
+    }
+}
+
+impl Widget for ListRow {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Text>(TEXT_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        Self {
+            base,
+            row: None,
+            row_count: 0,
+            is_selected: false,
+            data: String::new(),
+        }
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let Some(row) = self.row else {
+            return Ok(None);
+        };
+        let mut node = accesskit::Node::new(Role::ListBoxOption);
+        node.set_label(self.data.clone());
+        node.set_position_in_set(row + 1);
+        node.set_size_of_set(self.row_count);
+        node.set_selected(self.is_selected);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
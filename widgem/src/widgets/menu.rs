@@ -1,31 +1,961 @@
 use {
-    super::{label::Label, window::Window, Widget, WidgetBaseOf, WidgetExt},
-    crate::{impl_widget_base, shared_window::X11WindowType},
-    winit::window::WindowLevel,
+    super::{image::Image, Widget, WidgetBaseOf, WidgetExt, WidgetId},
+    crate::{
+        callback::{Callback, CallbackVec},
+        draw::DrawEvent,
+        event::{
+            AccessibilityActionEvent, KeyboardInputEvent, LayoutEvent, MouseInputEvent,
+            MouseLeaveEvent, MouseMoveEvent, StyleChangeEvent, WindowFocusChangeEvent,
+        },
+        impl_widget_base,
+        layout::{
+            grid::{self, grid_layout},
+            SizeHints,
+        },
+        shared_window::{SharedWindow, X11WindowType},
+        shortcut::KeyCombinations,
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_content_url, convert_zoom, Element, PseudoClass},
+            get_style, Style,
+        },
+        text_editor::Text,
+        types::{Axis, LpxSuffix, PhysicalPixels, Point, PpxSuffix, Rect, Size},
+    },
+    accesskit::{Action, HasPopup, Role, Toggled},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    log::warn,
+    std::{
+        cmp::{max, min},
+        fmt::Display,
+        rc::Rc,
+    },
+    tiny_skia::Pixmap,
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key, NamedKey},
+        window::WindowLevel,
+    },
 };
 
+/// A popup menu displayed in a separate undecorated window.
+///
+/// The menu is shown as soon as it's created. By default, it's positioned at the mouse pointer.
+/// Use [set_anchor_rect](Self::set_anchor_rect) to attach it to a widget instead.
+/// The menu is hidden when an item is triggered, when Escape is pressed or when the user clicks outside of it.
+/// A hidden menu can be shown again with [popup](Self::popup).
 pub struct Menu {
     base: WidgetBaseOf<Self>,
+    num_entries: usize,
+    min_width: PhysicalPixels,
+    current: Option<usize>,
+    is_open: bool,
+    anchor_window: Option<SharedWindow>,
+    anchor_rect: Option<Rect>,
+    cursor_anchor: Option<Rect>,
+    on_closed: CallbackVec<()>,
+    // Present if this is a submenu.
+    close_parent: Option<Callback<()>>,
+    parent_focus_lost: Option<Callback<()>>,
 }
 
-impl Menu {}
+impl Menu {
+    pub fn add_item(&mut self) -> &mut MenuItem {
+        let index = self.num_entries;
+        self.num_entries += 1;
+        let activate = self.callback(move |this, _| this.activate(index));
+        let item = self
+            .base
+            .add_child_with_key::<MenuItem>(index)
+            .set_column(0)
+            .set_row(index as i32);
+        item.activate = Some(activate);
+        item
+    }
+
+    pub fn add_separator(&mut self) -> &mut MenuSeparator {
+        let index = self.num_entries;
+        self.num_entries += 1;
+        self.base
+            .add_child_with_key::<MenuSeparator>(index)
+            .set_column(0)
+            .set_row(index as i32)
+    }
+
+    /// Returns the item at `index`, or `None` if there is no item at `index`.
+    ///
+    /// Items and separators share the same index space.
+    pub fn item(&self, index: usize) -> Option<&MenuItem> {
+        self.base.get_child::<MenuItem>(index).ok()
+    }
+
+    pub fn item_mut(&mut self, index: usize) -> Option<&mut MenuItem> {
+        self.base.get_child_mut::<MenuItem>(index).ok()
+    }
+
+    /// Number of items and separators in the menu.
+    pub fn len(&self) -> usize {
+        self.num_entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_entries == 0
+    }
+
+    /// Removes all items and separators.
+    pub fn clear(&mut self) {
+        self.current = None;
+        for index in 0..self.num_entries {
+            let _ = self.base.remove_child(index);
+        }
+        self.num_entries = 0;
+    }
+
+    /// Attach the menu to an area of the window that contains the parent widget of the menu.
+    ///
+    /// The menu will be displayed below the area if there is enough space on the screen, and above it otherwise.
+    /// Submenus are displayed to the right or to the left of their item.
+    pub fn set_anchor_rect(&mut self, rect: Rect) -> &mut Self {
+        self.anchor_rect = Some(rect);
+        self.update_position();
+        self
+    }
+
+    /// Shows the menu if it's hidden.
+    pub fn popup(&mut self) -> &mut Self {
+        if self.is_open {
+            return self;
+        }
+        self.is_open = true;
+        self.set_current(None);
+        self.capture_cursor();
+        self.update_position();
+        if let Some(window) = &self.base.window {
+            window.set_visible(true);
+            window.focus_window();
+        }
+        self.base.update();
+        self
+    }
+
+    /// Hides the menu and all its open submenus.
+    pub fn close(&mut self) {
+        if !self.is_open {
+            return;
+        }
+        self.is_open = false;
+        self.close_submenus();
+        self.set_current(None);
+        if let Some(window) = &self.base.window {
+            window.set_visible(false);
+        }
+        self.on_closed.invoke(());
+        self.base.update();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Index of the highlighted item.
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Highlights the item at `index`.
+    ///
+    /// Separators and disabled items can't be highlighted.
+    pub fn set_current_index(&mut self, index: Option<usize>) -> &mut Self {
+        if index.is_none_or(|index| self.is_selectable(index)) {
+            self.set_current(index);
+        }
+        self
+    }
+
+    /// Sets the minimal width of the menu's window.
+    pub fn set_min_width(&mut self, width: PhysicalPixels) -> &mut Self {
+        if self.min_width != width {
+            self.min_width = width;
+            self.base.size_hint_changed();
+        }
+        self
+    }
+
+    pub fn on_closed(&mut self, callback: Callback<()>) -> &mut Self {
+        self.on_closed.push(callback);
+        self
+    }
+
+    fn set_parent_menu(&mut self, parent: WidgetId<Menu>) {
+        self.close_parent = Some(parent.callback(|parent, _| {
+            parent.close_all();
+            Ok(())
+        }));
+        self.parent_focus_lost = Some(parent.callback(|parent, _| {
+            parent.submenu_focus_lost();
+            Ok(())
+        }));
+    }
+
+    fn is_submenu(&self) -> bool {
+        self.close_parent.is_some()
+    }
+
+    // Closes this menu and all its parent menus.
+    fn close_all(&mut self) {
+        self.close();
+        if let Some(close_parent) = &self.close_parent {
+            close_parent.invoke(());
+        }
+    }
+
+    // Closes this submenu and returns focus to the parent menu.
+    fn close_to_parent(&mut self) {
+        self.close();
+        if let Some(window) = &self.anchor_window {
+            window.focus_window();
+        }
+    }
+
+    fn close_submenus(&mut self) {
+        for index in 0..self.num_entries {
+            if let Some(submenu) = self.item_mut(index).and_then(|item| item.submenu_mut()) {
+                submenu.close();
+            }
+        }
+    }
+
+    // Called when the window of this menu loses focus.
+    fn focus_lost(&mut self) {
+        // Opening a submenu moves focus to it, and closing a submenu
+        // with the keyboard returns focus to its parent.
+        if !self.is_open || self.current_has_open_submenu() {
+            return;
+        }
+        self.close();
+        if let Some(parent_focus_lost) = &self.parent_focus_lost {
+            parent_focus_lost.invoke(());
+        }
+    }
+
+    // Called when an open submenu of this menu has been closed because its window lost focus.
+    fn submenu_focus_lost(&mut self) {
+        // If the pointer is over this menu, the focus was taken by a click on this menu.
+        // Otherwise the user clicked outside of the menus.
+        let is_pointer_inside = self
+            .base
+            .window
+            .as_ref()
+            .is_some_and(|window| window.cursor_position().is_some());
+        if !self.is_open || is_pointer_inside {
+            return;
+        }
+        self.close();
+        if let Some(parent_focus_lost) = &self.parent_focus_lost {
+            parent_focus_lost.invoke(());
+        }
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        self.item(index)
+            .is_some_and(|item| item.base.is_enabled() && item.base.is_self_visible())
+    }
+
+    fn set_current(&mut self, index: Option<usize>) {
+        if self.current == index {
+            return;
+        }
+        let mut submenu_was_focused = false;
+        if let Some(old_item) = self.current.and_then(|i| self.item_mut(i)) {
+            old_item.set_current(false);
+            if let Some(submenu) = old_item.submenu_mut() {
+                submenu_was_focused = submenu.is_open && submenu.base.is_window_focused();
+                submenu.close();
+            }
+        }
+        // Keep the focus within the menus so that a click outside can still be detected.
+        if submenu_was_focused {
+            if let Some(window) = &self.base.window {
+                window.focus_window();
+            }
+        }
+        self.current = index;
+        if let Some(item) = index.and_then(|i| self.item_mut(i)) {
+            item.set_current(true);
+        }
+        self.base.update();
+    }
+
+    fn move_current(&mut self, forward: bool) {
+        let len = self.num_entries;
+        let mut index = self.current;
+        for _ in 0..len {
+            let next = match index {
+                None if forward => 0,
+                None => len - 1,
+                Some(i) if forward => (i + 1) % len,
+                Some(i) => (i + len - 1) % len,
+            };
+            if self.is_selectable(next) {
+                self.set_current(Some(next));
+                return;
+            }
+            index = Some(next);
+        }
+    }
+
+    fn current_has_open_submenu(&self) -> bool {
+        self.current
+            .and_then(|i| self.item(i))
+            .and_then(|item| item.submenu())
+            .is_some_and(|submenu| submenu.is_open)
+    }
+
+    fn open_submenu(&mut self, index: usize, select_first: bool) {
+        let Some(item) = self.item_mut(index) else {
+            return;
+        };
+        let Some(rect) = item.base.rect_in_window() else {
+            return;
+        };
+        let Some(submenu) = item.submenu_mut() else {
+            return;
+        };
+        submenu.set_anchor_rect(rect);
+        submenu.popup();
+        if select_first {
+            submenu.move_current(true);
+        }
+    }
+
+    fn activate(&mut self, index: usize) -> Result<()> {
+        if !self.is_selectable(index) {
+            return Ok(());
+        }
+        self.set_current(Some(index));
+        let Some(item) = self.item_mut(index) else {
+            return Ok(());
+        };
+        if item.submenu().is_some() {
+            self.open_submenu(index, true);
+            return Ok(());
+        }
+        item.trigger();
+        if item.is_exclusive() {
+            self.uncheck_exclusive_siblings(index);
+        }
+        self.close_all();
+        Ok(())
+    }
+
+    // Exclusive items between two separators form a group.
+    fn uncheck_exclusive_siblings(&mut self, index: usize) {
+        let before = (0..index).rev();
+        let after = index + 1..self.num_entries;
+        for range in [before.collect::<Vec<_>>(), after.collect()] {
+            for i in range {
+                let Some(item) = self.item_mut(i) else {
+                    break;
+                };
+                if item.is_exclusive() && item.is_checked() {
+                    item.set_checked_internal(false, true);
+                }
+            }
+        }
+    }
+
+    fn entry_at(&self, pos: Point) -> Option<usize> {
+        (0..self.num_entries).find(|index| {
+            self.base
+                .get_dyn_child(*index)
+                .ok()
+                .and_then(|entry| entry.base().rect_in_parent())
+                .is_some_and(|rect| rect.contains(pos))
+        })
+    }
+
+    fn capture_cursor(&mut self) {
+        self.cursor_anchor = self
+            .anchor_window
+            .as_ref()
+            .and_then(|window| window.cursor_position())
+            .map(|pos| Rect::from_pos_size(pos, Size::default()));
+    }
+
+    fn update_position(&mut self) {
+        let Some(anchor) = self.anchor_rect.or(self.cursor_anchor) else {
+            return;
+        };
+        let Some(window) = self.base.window.clone() else {
+            return;
+        };
+        let (offset, monitor) = match &self.anchor_window {
+            Some(anchor_window) => {
+                let Some(offset) = anchor_window.inner_position() else {
+                    return;
+                };
+                (offset, anchor_window.monitor_rect())
+            }
+            None => (Point::default(), None),
+        };
+        let size = match self.base.size() {
+            Some(size) => size,
+            None => {
+                let hints_x = self.size_hint_x();
+                Size::new(
+                    hints_x.preferred,
+                    self.size_hint_y(hints_x.preferred).preferred,
+                )
+            }
+        };
+        let axis = if self.is_submenu() { Axis::X } else { Axis::Y };
+        window.set_outer_position(popup_position(
+            anchor.translate(offset),
+            size,
+            monitor,
+            axis,
+        ));
+    }
+}
+
+// Returns position of a popup of `size` attached to `anchor` along `axis`,
+// keeping the popup within `screen` if possible.
+pub(crate) fn popup_position(anchor: Rect, size: Size, screen: Option<Rect>, axis: Axis) -> Point {
+    let (mut x, mut y) = match axis {
+        Axis::X => (anchor.right(), anchor.top()),
+        Axis::Y => (anchor.left(), anchor.bottom()),
+    };
+    if let Some(screen) = screen {
+        match axis {
+            Axis::X => {
+                if x + size.x() > screen.right() {
+                    x = anchor.left() - size.x();
+                }
+            }
+            Axis::Y => {
+                if y + size.y() > screen.bottom() {
+                    y = anchor.top() - size.y();
+                }
+            }
+        }
+        x = max(min(x, screen.right() - size.x()), screen.left());
+        y = max(min(y, screen.bottom() - size.y()), screen.top());
+    }
+    Point::new(x, y)
+}
 
 impl Widget for Menu {
     impl_widget_base!();
 
+    fn is_window_root_type() -> bool {
+        true
+    }
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        let window = base.window.clone().unwrap();
+        window.set_decorations(false);
+        window.set_window_level(WindowLevel::AlwaysOnTop);
+        window.set_x11_window_type(vec![X11WindowType::Menu]);
+        window.set_skip_windows_taskbar(true);
+        let mut this = Self {
+            anchor_window: base.parent_window(),
+            base,
+            num_entries: 0,
+            min_width: 0.ppx(),
+            current: None,
+            is_open: true,
+            anchor_rect: None,
+            cursor_anchor: None,
+            on_closed: CallbackVec::new(),
+            close_parent: None,
+            parent_focus_lost: None,
+        };
+        this.capture_cursor();
+        this.update_position();
+        this
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let options = self.base.common_style.grid.clone();
+        let mut hints = grid::size_hint_x(&mut self.base.children, &options);
+        hints.min = max(hints.min, self.min_width);
+        hints.preferred = max(hints.preferred, self.min_width);
+        Ok(hints)
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        self.update_position();
+        Ok(())
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        let index = self
+            .entry_at(event.pos)
+            .filter(|index| self.is_selectable(*index));
+        match index {
+            Some(index) => {
+                if self.current != Some(index) {
+                    self.set_current(Some(index));
+                    self.open_submenu(index, false);
+                }
+            }
+            None => {
+                if !self.current_has_open_submenu() {
+                    self.set_current(None);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_leave(&mut self, _event: MouseLeaveEvent) -> Result<()> {
+        if !self.current_has_open_submenu() {
+            self.set_current(None);
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if event.button == MouseButton::Left && !event.state.is_pressed() {
+            if let Some(index) = self.entry_at(event.pos) {
+                self.activate(index)?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() {
+            return Ok(false);
+        }
+        match &event.info.logical_key {
+            Key::Named(NamedKey::ArrowDown) => self.move_current(true),
+            Key::Named(NamedKey::ArrowUp) => self.move_current(false),
+            Key::Named(NamedKey::Home) => {
+                self.set_current(None);
+                self.move_current(true);
+            }
+            Key::Named(NamedKey::End) => {
+                self.set_current(None);
+                self.move_current(false);
+            }
+            Key::Named(NamedKey::Enter) | Key::Named(NamedKey::Space) => {
+                if let Some(index) = self.current {
+                    self.activate(index)?;
+                }
+            }
+            Key::Named(NamedKey::ArrowRight) => {
+                if let Some(index) = self.current {
+                    self.open_submenu(index, true);
+                }
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                if self.is_submenu() {
+                    self.close_to_parent();
+                }
+            }
+            Key::Named(NamedKey::Escape) => {
+                if self.is_submenu() {
+                    self.close_to_parent();
+                } else {
+                    self.close();
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_window_focus_change(&mut self, event: WindowFocusChangeEvent) -> Result<()> {
+        if !event.is_window_focused {
+            self.focus_lost();
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::Menu)))
+    }
+}
+
+impl Drop for Menu {
+    fn drop(&mut self) {
+        self.base.window.as_ref().unwrap().deregister();
+    }
+}
+
+const INDICATOR_KEY: u64 = 0;
+const ICON_KEY: u64 = 1;
+const TEXT_KEY: u64 = 2;
+const SHORTCUT_KEY: u64 = 3;
+const ARROW_KEY: u64 = 4;
+const SUBMENU_KEY: u64 = 5;
+
+/// An item of a [Menu].
+///
+/// Create items with [Menu::add_item].
+pub struct MenuItem {
+    base: WidgetBaseOf<Self>,
+    is_checkable: bool,
+    is_exclusive: bool,
+    is_checked: bool,
+    shortcut: Option<KeyCombinations>,
+    on_triggered: CallbackVec<()>,
+    on_toggled: CallbackVec<bool>,
+    activate: Option<Callback<()>>,
+    style: Rc<ComputedMenuItemStyle>,
+}
+
+#[impl_with]
+impl MenuItem {
+    fn text_widget(&self) -> &Text {
+        self.base.get_child::<Text>(TEXT_KEY).unwrap()
+    }
+
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(TEXT_KEY).unwrap()
+    }
+
+    pub fn set_text(&mut self, text: impl Display) -> &mut Self {
+        self.text_widget_mut().set_text(text, Attrs::new());
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.text_widget().text()
+    }
+
+    pub fn set_icon(&mut self, icon: Option<Rc<Pixmap>>) -> &mut Self {
+        let image = self.base.get_child_mut::<Image>(ICON_KEY).unwrap();
+        image.set_visible(icon.is_some());
+        image.set_pixmap(icon);
+        self
+    }
+
+    /// Displays the key combination next to the item's text.
+    ///
+    /// This is only a hint for the user. Register a [Shortcut](crate::shortcut::Shortcut)
+    /// to make the key combination trigger the corresponding action.
+    pub fn set_shortcut_hint(&mut self, shortcut: Option<KeyCombinations>) -> &mut Self {
+        let text = shortcut
+            .as_ref()
+            .and_then(|s| s.0.first())
+            .map(|s| s.to_string());
+        let text_widget = self.base.get_child_mut::<Text>(SHORTCUT_KEY).unwrap();
+        text_widget.set_visible(text.is_some());
+        text_widget.set_text(text.unwrap_or_default(), Attrs::new());
+        self.shortcut = shortcut;
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn shortcut_hint(&self) -> Option<&KeyCombinations> {
+        self.shortcut.as_ref()
+    }
+
+    /// Makes the item toggle its checked state when it's triggered.
+    pub fn set_checkable(&mut self, value: bool) -> &mut Self {
+        self.is_checkable = value;
+        if !value {
+            self.is_exclusive = false;
+            self.set_checked(false);
+        }
+        self.set_class("checkable".into(), value && !self.is_exclusive);
+        self.set_class("exclusive".into(), self.is_exclusive);
+        self.base.update();
+        self
+    }
+
+    pub fn is_checkable(&self) -> bool {
+        self.is_checkable
+    }
+
+    /// Makes the item behave like a radio button.
+    ///
+    /// Exclusive items that are not separated by a [MenuSeparator] form a group.
+    /// Triggering an exclusive item checks it and unchecks other items in the group.
+    /// Setting this to `true` implies [`set_checkable(true)`](Self::set_checkable).
+    pub fn set_exclusive(&mut self, value: bool) -> &mut Self {
+        self.is_exclusive = value;
+        self.set_checkable(self.is_checkable || value);
+        self
+    }
+
+    pub fn is_exclusive(&self) -> bool {
+        self.is_exclusive
+    }
+
+    pub fn set_checked(&mut self, value: bool) -> &mut Self {
+        self.set_checked_internal(value, false);
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+
+    fn set_checked_internal(&mut self, value: bool, notify: bool) {
+        if self.is_checked == value {
+            return;
+        }
+        if value && !self.is_checkable {
+            warn!("cannot check a menu item that is not checkable");
+            return;
+        }
+        self.is_checked = value;
+        self.set_pseudo_class(PseudoClass::Checked, value);
+        if notify {
+            self.on_toggled.invoke(value);
+        }
+        self.base.update();
+    }
+
+    /// Adds a submenu that is displayed when the item is activated or hovered.
+    ///
+    /// If the item already has a submenu, it's returned instead.
+    pub fn add_submenu(&mut self) -> &mut Menu {
+        if self.base.has_child(SUBMENU_KEY) {
+            return self.base.get_child_mut::<Menu>(SUBMENU_KEY).unwrap();
+        }
+        self.add_class("submenu".into());
+        let parent = self.base.parent_id().map(WidgetId::<Menu>::new);
+        let submenu = self.base.add_child_with_key::<Menu>(SUBMENU_KEY);
+        if let Some(parent) = parent {
+            submenu.set_parent_menu(parent);
+        }
+        submenu.close();
+        submenu
+    }
+
+    pub fn submenu(&self) -> Option<&Menu> {
+        self.base.get_child::<Menu>(SUBMENU_KEY).ok()
+    }
+
+    pub fn submenu_mut(&mut self) -> Option<&mut Menu> {
+        self.base.get_child_mut::<Menu>(SUBMENU_KEY).ok()
+    }
+
+    pub fn on_triggered(&mut self, callback: Callback<()>) -> &mut Self {
+        self.on_triggered.push(callback);
+        self
+    }
+
+    /// The callback is invoked when the checked state of the item is changed by the user.
+    pub fn on_toggled(&mut self, callback: Callback<bool>) -> &mut Self {
+        self.on_toggled.push(callback);
+        self
+    }
+
+    pub fn trigger(&mut self) {
+        if self.is_exclusive {
+            self.set_checked_internal(true, true);
+        } else if self.is_checkable {
+            self.set_checked_internal(!self.is_checked, true);
+        }
+        self.on_triggered.invoke(());
+    }
+
+    fn set_current(&mut self, value: bool) {
+        self.set_class("current".into(), value);
+    }
+
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        let (target_key, other_key) = if self.base.has_child(SUBMENU_KEY) {
+            (ARROW_KEY, INDICATOR_KEY)
+        } else {
+            (INDICATOR_KEY, ARROW_KEY)
+        };
+        let other = self.base.get_child_mut::<Image>(other_key).unwrap();
+        other.set_visible(false);
+        other.set_pixmap(None);
+        let target = self.base.get_child_mut::<Image>(target_key).unwrap();
+        target.set_visible(icon.is_some());
+        target.set_prescaled(true);
+        target.set_pixmap(icon);
+    }
+}
+
+impl Widget for MenuItem {
+    impl_widget_base!();
+
     fn new(mut base: WidgetBaseOf<Self>) -> Self {
-        let window = base
-            .add_child::<Window>()
-            .set_decorations(false)
-            .set_window_level(WindowLevel::AlwaysOnTop)
-            .set_x11_window_type(vec![X11WindowType::Menu])
-            .set_skip_windows_taskbar(true);
-        window
-            .base_mut()
-            .add_child::<Label>()
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Image>(INDICATOR_KEY)
             .set_column(0)
             .set_row(0)
-            .set_text("menu content 1\nmenu content 2\nmenu content 3");
+            .set_visible(false);
+        base.add_child_with_key::<Image>(ICON_KEY)
+            .set_column(1)
+            .set_row(0)
+            .set_visible(false);
+        base.add_child_with_key::<Text>(TEXT_KEY)
+            .set_column(2)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element.clone());
+        base.add_child_with_key::<Text>(SHORTCUT_KEY)
+            .set_column(3)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element)
+            .set_visible(false);
+        base.add_child_with_key::<Image>(ARROW_KEY)
+            .set_column(4)
+            .set_row(0)
+            .set_visible(false);
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            is_checkable: false,
+            is_exclusive: false,
+            is_checked: false,
+            shortcut: None,
+            on_triggered: CallbackVec::new(),
+            on_toggled: CallbackVec::new(),
+            activate: None,
+        };
+        this.refresh_style();
+        this
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        if event.action == Action::Click {
+            if let Some(activate) = &self.activate {
+                activate.invoke(());
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let role = if self.is_exclusive {
+            Role::MenuItemRadio
+        } else if self.is_checkable {
+            Role::MenuItemCheckBox
+        } else {
+            Role::MenuItem
+        };
+        let mut node = accesskit::Node::new(role);
+        node.set_label(self.text());
+        if self.is_checkable {
+            node.set_toggled(if self.is_checked {
+                Toggled::True
+            } else {
+                Toggled::False
+            });
+        }
+        if let Some(submenu) = self.submenu() {
+            node.set_has_popup(HasPopup::Menu);
+            node.set_expanded(submenu.is_open());
+        }
+        if let Some(shortcut) = &self.shortcut {
+            node.set_keyboard_shortcut(shortcut.to_string());
+        }
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Click);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        for key in [TEXT_KEY, SHORTCUT_KEY] {
+            self.base
+                .get_child_mut::<Text>(key)?
+                .set_host_style_element(element.clone());
+        }
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+/// A horizontal line that separates groups of items in a [Menu].
+///
+/// Create separators with [Menu::add_separator].
+pub struct MenuSeparator {
+    base: WidgetBaseOf<Self>,
+}
+
+impl MenuSeparator {
+    fn line_width(&self) -> PhysicalPixels {
+        1.0.lpx().to_physical(self.base.scale())
+    }
+}
+
+impl Widget for MenuSeparator {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
         Self { base }
     }
+
+    fn handle_draw(&mut self, event: DrawEvent) -> Result<()> {
+        let size = self.base.size_or_err()?;
+        let padding_x = self.base.common_style.grid.x.preferred_padding;
+        let line_width = self.line_width();
+        event.fill_rect(
+            Rect::from_xywh(
+                padding_x.start,
+                (size.y() - line_width) / 2,
+                size.x() - padding_x.sum(),
+                line_width,
+            ),
+            self.base.common_style.text_color,
+        );
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.x;
+        Ok(SizeHints {
+            min: grid.min_padding.sum(),
+            preferred: grid.preferred_padding.sum(),
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.y;
+        let line_width = self.line_width();
+        Ok(SizeHints {
+            min: grid.min_padding.sum() + line_width,
+            preferred: grid.preferred_padding.sum() + line_width,
+            is_fixed: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ComputedMenuItemStyle {
+    // Check mark for checked items or an arrow for items with a submenu.
+    icon: Option<Rc<Pixmap>>,
+}
+
+impl ComputedElementStyle for ComputedMenuItemStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> ComputedMenuItemStyle {
+        let properties = style.find_rules(|s| element.matches(s));
+
+        let scale = scale * convert_zoom(&properties);
+        let mut icon = None;
+        if let Some(url) = convert_content_url(&properties) {
+            match style.load_pixmap(&url, scale) {
+                Ok(pixmap) => icon = Some(pixmap),
+                Err(err) => warn!("failed to load icon: {err:?}"),
+            }
+        }
+        Self { icon }
+    }
 }
@@ -0,0 +1,360 @@
+use {
+    super::{
+        button::Button,
+        check_box::ComputedIndicatorStyle,
+        dialog::{add_dialog_shortcuts, init_dialog_window, Modality},
+        image::Image,
+        label::Label,
+        row::Row,
+        Widget, WidgetBaseOf, WidgetExt,
+    },
+    crate::{
+        callback::{Callback, CallbackVec},
+        event::{FocusReason, LayoutEvent, StyleChangeEvent},
+        impl_widget_base,
+        layout::grid::grid_layout,
+        rich_text::RichText,
+        shared_window::SetFocusRequest,
+        style::{css::PseudoClass, get_style},
+        system::send_window_request,
+    },
+    accesskit::Role,
+    anyhow::Result,
+    std::{fmt::Display, rc::Rc},
+    widgem_macros::impl_with,
+};
+
+/// A button of a [MessageBox].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardButton {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+impl StandardButton {
+    pub fn text(self) -> &'static str {
+        match self {
+            StandardButton::Ok => "OK",
+            StandardButton::Cancel => "Cancel",
+            StandardButton::Yes => "Yes",
+            StandardButton::No => "No",
+        }
+    }
+}
+
+/// A set of buttons displayed in a [MessageBox].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StandardButtons {
+    #[default]
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl StandardButtons {
+    pub fn buttons(self) -> &'static [StandardButton] {
+        match self {
+            StandardButtons::Ok => &[StandardButton::Ok],
+            StandardButtons::OkCancel => &[StandardButton::Ok, StandardButton::Cancel],
+            StandardButtons::YesNo => &[StandardButton::Yes, StandardButton::No],
+            StandardButtons::YesNoCancel => &[
+                StandardButton::Yes,
+                StandardButton::No,
+                StandardButton::Cancel,
+            ],
+        }
+    }
+
+    /// The button triggered by Enter.
+    pub fn default_button(self) -> StandardButton {
+        self.buttons()[0]
+    }
+
+    /// The button triggered by Escape or by closing the window.
+    pub fn escape_button(self) -> StandardButton {
+        *self.buttons().last().unwrap()
+    }
+}
+
+/// An icon displayed in a [MessageBox]. The images are specified in CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MessageBoxIcon {
+    #[default]
+    None,
+    Information,
+    Warning,
+    Error,
+    Question,
+}
+
+impl MessageBoxIcon {
+    fn pseudo_class(self) -> Option<&'static str> {
+        match self {
+            MessageBoxIcon::None => None,
+            MessageBoxIcon::Information => Some("information"),
+            MessageBoxIcon::Warning => Some("warning"),
+            MessageBoxIcon::Error => Some("error"),
+            MessageBoxIcon::Question => Some("question"),
+        }
+    }
+}
+
+const ICON_KEY: u64 = 0;
+const TEXT_KEY: u64 = 1;
+const BUTTONS_KEY: u64 = 2;
+
+/// A modal dialog that displays a message with an icon and a standard set of buttons.
+///
+/// The message box is opened as soon as it's created. See [Dialog](super::dialog::Dialog)
+/// for details about modality.
+pub struct MessageBox {
+    base: WidgetBaseOf<Self>,
+    modality: Modality,
+    icon: MessageBoxIcon,
+    buttons: StandardButtons,
+    result: Option<StandardButton>,
+    on_finished: CallbackVec<StandardButton>,
+}
+
+#[impl_with]
+impl MessageBox {
+    fn icon_widget_mut(&mut self) -> &mut MessageIcon {
+        self.base.get_child_mut::<MessageIcon>(ICON_KEY).unwrap()
+    }
+
+    fn buttons_row_mut(&mut self) -> &mut Row {
+        self.base.get_child_mut::<Row>(BUTTONS_KEY).unwrap()
+    }
+
+    pub fn set_title(&mut self, title: impl Display) -> &mut Self {
+        self.base.window.as_ref().unwrap().set_title(title);
+        self
+    }
+
+    /// Sets the message. Unlike [Label::set_text], ampersands are displayed as is.
+    pub fn set_text(&mut self, text: impl Display) -> &mut Self {
+        self.base
+            .get_child_mut::<Label>(TEXT_KEY)
+            .unwrap()
+            .set_rich_text(RichText::from(text.to_string()));
+        self
+    }
+
+    pub fn set_icon(&mut self, icon: MessageBoxIcon) -> &mut Self {
+        if self.icon == icon {
+            return self;
+        }
+        let old = self.icon;
+        self.icon = icon;
+        let widget = self.icon_widget_mut();
+        if let Some(class) = old.pseudo_class() {
+            widget.set_pseudo_class(PseudoClass::Custom(class.into()), false);
+        }
+        if let Some(class) = icon.pseudo_class() {
+            widget.set_pseudo_class(PseudoClass::Custom(class.into()), true);
+        }
+        widget.set_visible(icon != MessageBoxIcon::None);
+        self
+    }
+
+    pub fn icon(&self) -> MessageBoxIcon {
+        self.icon
+    }
+
+    pub fn set_buttons(&mut self, buttons: StandardButtons) -> &mut Self {
+        self.buttons = buttons;
+        let row = self.buttons_row_mut();
+        for index in 0..row.base().children.len() {
+            row.base_mut().remove_child(index as u64).ok();
+        }
+        for (index, button) in buttons.buttons().iter().copied().enumerate() {
+            let pressed = self.callback(move |this, ()| {
+                this.done(button);
+                Ok(())
+            });
+            self.buttons_row_mut()
+                .base_mut()
+                .add_child_with_key::<Button>(index as u64)
+                .set_column(index as i32)
+                .set_row(0)
+                .set_text(button.text())
+                .set_default(button == buttons.default_button())
+                .on_triggered(pressed);
+        }
+        self.focus_default_button();
+        self
+    }
+
+    pub fn buttons(&self) -> StandardButtons {
+        self.buttons
+    }
+
+    /// Shows the message box if it's hidden.
+    pub fn open(&mut self) -> &mut Self {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.open(&window, self.base.size()) {
+            self.result = None;
+            self.focus_default_button();
+            self.base.update();
+        }
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.modality.is_open()
+    }
+
+    /// Closes the message box as if `button` was pressed.
+    pub fn done(&mut self, button: StandardButton) {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.close(&window) {
+            self.result = Some(button);
+            self.on_finished.invoke(button);
+            self.base.update();
+        }
+    }
+
+    /// The button that finished the message box the last time.
+    pub fn result(&self) -> Option<StandardButton> {
+        self.result
+    }
+
+    /// Called with the pressed button when the message box is finished.
+    pub fn on_finished(&mut self, callback: Callback<StandardButton>) -> &mut Self {
+        self.on_finished.push(callback);
+        self
+    }
+
+    fn focus_default_button(&mut self) {
+        let window_id = self.base.window_id();
+        // The default button is always the first one.
+        let Ok(button) = self.buttons_row_mut().base().get_dyn_child(0u64) else {
+            return;
+        };
+        let widget_id = button.base().id();
+        if let Some(window_id) = window_id {
+            send_window_request(
+                window_id,
+                SetFocusRequest {
+                    widget_id,
+                    reason: FocusReason::Auto,
+                },
+            );
+        }
+    }
+}
+
+impl Widget for MessageBox {
+    impl_widget_base!();
+
+    fn is_window_root_type() -> bool {
+        true
+    }
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.window.clone().unwrap();
+        init_dialog_window(&window);
+        let id = base.id();
+        add_dialog_shortcuts(
+            &mut base,
+            id.callback(|this, ()| {
+                this.done(this.buttons.default_button());
+                Ok(())
+            }),
+            id.callback(|this, ()| {
+                this.done(this.buttons.escape_button());
+                Ok(())
+            }),
+        );
+        base.add_child_with_key::<MessageIcon>(ICON_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        base.add_child_with_key::<Label>(TEXT_KEY)
+            .set_column(1)
+            .set_row(0);
+        base.add_child_with_key::<Row>(BUTTONS_KEY)
+            .set_column(1)
+            .set_row(1);
+        let mut this = Self {
+            modality: Modality::new(base.parent_window()),
+            base,
+            icon: MessageBoxIcon::None,
+            buttons: StandardButtons::Ok,
+            result: None,
+            on_finished: CallbackVec::new(),
+        };
+        this.set_buttons(StandardButtons::Ok);
+        this.open();
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        if let Some(size) = self.base.size() {
+            let window = self.base.window.clone().unwrap();
+            self.modality.center(&window, size);
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::AlertDialog);
+        node.set_modal();
+        Ok(Some(node))
+    }
+}
+
+impl Drop for MessageBox {
+    fn drop(&mut self) {
+        let window = self.base.window.clone().unwrap();
+        if self.modality.close(&window) {
+            self.on_finished.invoke(self.buttons.escape_button());
+        }
+        window.deregister();
+    }
+}
+
+/// Icon of a [MessageBox]. The image is specified by the `content` property in CSS.
+struct MessageIcon {
+    base: WidgetBaseOf<Self>,
+    style: Rc<ComputedIndicatorStyle>,
+}
+
+impl MessageIcon {
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        let image = self.base.get_child_mut::<Image>(0).unwrap();
+        image.set_visible(icon.is_some());
+        image.set_prescaled(true);
+        image.set_pixmap(icon);
+    }
+}
+
+impl Widget for MessageIcon {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<Image>(0)
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+        };
+        this.refresh_style();
+        this
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
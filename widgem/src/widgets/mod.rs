@@ -2,20 +2,40 @@ use {crate::system::address, anyhow::Result, log::warn, std::fmt::Debug, thiserr
 
 mod address;
 pub mod button;
+pub mod check_box;
 pub mod column;
+pub mod combo_box;
 mod common;
+pub mod dialog;
 mod ext;
+pub mod frame;
+pub mod group_box;
 mod id;
 pub mod image;
 pub mod label;
+pub mod list_view;
 pub mod menu;
+pub mod message_box;
 pub mod padding_box;
+pub mod progress_bar;
+pub mod radio_button;
+mod range_value;
 pub mod root;
 pub mod row;
 pub mod scroll_area;
 pub mod scroll_bar;
+pub mod slider;
+pub mod spin_box;
+pub mod splitter;
 pub mod stack;
+pub mod status_bar;
+pub mod tab_widget;
+pub mod table_view;
+pub mod text_area;
 pub mod text_input;
+pub mod tool_bar;
+pub mod tooltip;
+pub mod tree_view;
 mod widget_trait;
 pub mod window;
 
@@ -0,0 +1,410 @@
+use {
+    super::{
+        range_value::RangeValue, Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        event::LayoutEvent,
+        impl_widget_base,
+        layout::SizeHints,
+        style::css::PseudoClass,
+        system::add_interval,
+        text_editor::Text,
+        timer::TimerId,
+        types::{Axis, LpxSuffix, PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::{Orientation, Role},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    std::{cmp::max, ops::RangeInclusive, time::Duration},
+    widgem_macros::impl_with,
+};
+
+const CHUNK_KEY: u64 = 0;
+const TEXT_KEY: u64 = 1;
+
+const PREFERRED_LENGTH: f32 = 200.0;
+const MIN_LENGTH: f32 = 40.0;
+const MIN_THICKNESS: f32 = 6.0;
+
+const BUSY_INTERVAL: Duration = Duration::from_millis(40);
+// Number of animation steps for the chunk to travel from one end of the bar to the other.
+const BUSY_STEPS: u32 = 50;
+// Length of the chunk in busy mode relative to the length of the bar.
+const BUSY_CHUNK_RATIO: f32 = 0.25;
+
+/// A bar that displays progress of an operation.
+///
+/// In indeterminate mode, the bar displays a moving chunk instead of the current value.
+/// It's useful when the duration of the operation is unknown.
+pub struct ProgressBar {
+    base: WidgetBaseOf<Self>,
+    axis: Axis,
+    value: RangeValue,
+    format: String,
+    is_text_visible: bool,
+    is_indeterminate: bool,
+    busy_step: u32,
+    busy_timer: Option<TimerId>,
+}
+
+#[impl_with]
+impl ProgressBar {
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(TEXT_KEY).unwrap()
+    }
+
+    fn chunk_mut(&mut self) -> &mut ProgressChunk {
+        self.base.get_child_mut::<ProgressChunk>(CHUNK_KEY).unwrap()
+    }
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Sets orientation of the bar. A vertical bar is filled from the bottom.
+    pub fn set_axis(&mut self, axis: Axis) -> &mut Self {
+        if self.axis == axis {
+            return self;
+        }
+        self.axis = axis;
+        self.set_style_attribute("axis".into(), Some(axis.name().into()));
+        self.base.size_hint_changed();
+        self
+    }
+
+    pub fn set_value_range(&mut self, range: RangeInclusive<i32>) -> &mut Self {
+        if self.value.set_range(range) {
+            self.value_changed();
+        }
+        self
+    }
+
+    pub fn value_range(&self) -> RangeInclusive<i32> {
+        self.value.range()
+    }
+
+    pub fn set_value(&mut self, value: i32) -> &mut Self {
+        if self.value.set_value(value, true) {
+            self.value_changed();
+        }
+        self
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value.value()
+    }
+
+    /// Sets the text displayed over the bar.
+    ///
+    /// `{value}`, `{min}`, `{max}` and `{percent}` are replaced with the corresponding values.
+    /// The default format is `{percent}%`.
+    pub fn set_format(&mut self, format: impl Into<String>) -> &mut Self {
+        self.format = format.into();
+        self.update_text();
+        self
+    }
+
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    /// Returns the text currently displayed over the bar.
+    pub fn text(&self) -> String {
+        let percent = (self.value.ratio() * 100.0).round() as i32;
+        self.format
+            .replace("{value}", &self.value.value().to_string())
+            .replace("{min}", &self.value.min().to_string())
+            .replace("{max}", &self.value.max().to_string())
+            .replace("{percent}", &percent.to_string())
+    }
+
+    pub fn set_text_visible(&mut self, value: bool) -> &mut Self {
+        if self.is_text_visible == value {
+            return self;
+        }
+        self.is_text_visible = value;
+        self.update_text_visibility();
+        self.base.size_hint_changed();
+        self
+    }
+
+    pub fn is_text_visible(&self) -> bool {
+        self.is_text_visible
+    }
+
+    /// Enables or disables indeterminate (busy) mode.
+    ///
+    /// In this mode, the value and the text are not displayed.
+    pub fn set_indeterminate(&mut self, value: bool) -> &mut Self {
+        if self.is_indeterminate == value {
+            return self;
+        }
+        self.is_indeterminate = value;
+        self.set_pseudo_class(PseudoClass::Indeterminate, value);
+        self.chunk_mut()
+            .set_pseudo_class(PseudoClass::Indeterminate, value);
+        if value {
+            self.busy_step = 0;
+            let id = add_interval(
+                BUSY_INTERVAL,
+                self.callback(|this, _| {
+                    this.advance_busy_animation();
+                    Ok(())
+                }),
+            );
+            self.busy_timer = Some(id);
+        } else if let Some(id) = self.busy_timer.take() {
+            id.cancel();
+        }
+        self.update_text_visibility();
+        self.layout_chunk(&[]);
+        self.base.update();
+        self
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.is_indeterminate
+    }
+
+    fn value_changed(&mut self) {
+        self.update_text();
+        self.layout_chunk(&[]);
+        self.base.update();
+    }
+
+    fn update_text(&mut self) {
+        let text = self.text();
+        self.text_widget_mut().set_text(text, Attrs::new());
+    }
+
+    fn update_text_visibility(&mut self) {
+        let visible = self.is_text_visible && !self.is_indeterminate;
+        self.text_widget_mut().set_visible(visible);
+    }
+
+    fn advance_busy_animation(&mut self) {
+        if !self.base.is_visible() {
+            return;
+        }
+        self.busy_step = (self.busy_step + 1) % (2 * BUSY_STEPS);
+        self.layout_chunk(&[]);
+    }
+
+    // Returns the rect inside the padding of the bar.
+    fn content_rect(&self) -> Option<Rect> {
+        let size = self.base.size()?;
+        let grid = &self.base.common_style.grid;
+        Some(Rect::from_xywh(
+            grid.x.preferred_padding.start,
+            grid.y.preferred_padding.start,
+            max(0.ppx(), size.x() - grid.x.preferred_padding.sum()),
+            max(0.ppx(), size.y() - grid.y.preferred_padding.sum()),
+        ))
+    }
+
+    // Returns the start and the length of the chunk along the axis, starting from the beginning
+    // of the bar (the left side for horizontal bars, the bottom side for vertical bars).
+    fn chunk_range(&self, length: PhysicalPixels) -> (PhysicalPixels, PhysicalPixels) {
+        let length_f32 = length.to_i32() as f32;
+        if self.is_indeterminate {
+            let chunk_length = (length_f32 * BUSY_CHUNK_RATIO).round() as i32;
+            // The chunk moves forward and then backward.
+            let step = if self.busy_step < BUSY_STEPS {
+                self.busy_step
+            } else {
+                2 * BUSY_STEPS - self.busy_step
+            };
+            let pos = ((length.to_i32() - chunk_length) as f32 * step as f32 / BUSY_STEPS as f32)
+                .round() as i32;
+            (
+                PhysicalPixels::from_i32(pos),
+                PhysicalPixels::from_i32(chunk_length),
+            )
+        } else {
+            let chunk_length = (length_f32 * self.value.ratio()).round() as i32;
+            (0.ppx(), PhysicalPixels::from_i32(chunk_length))
+        }
+    }
+
+    fn layout_chunk(&mut self, changed_size_hints: &[WidgetAddress]) {
+        let (Some(geometry), Some(content)) = (self.base.geometry.clone(), self.content_rect())
+        else {
+            self.chunk_mut().set_geometry(None, changed_size_hints);
+            return;
+        };
+        let rect = match self.axis {
+            Axis::X => {
+                let (start, length) = self.chunk_range(content.size_x());
+                Rect::from_xywh(
+                    content.left() + start,
+                    content.top(),
+                    length,
+                    content.size_y(),
+                )
+            }
+            Axis::Y => {
+                let (start, length) = self.chunk_range(content.size_y());
+                Rect::from_xywh(
+                    content.left(),
+                    content.bottom() - start - length,
+                    content.size_x(),
+                    length,
+                )
+            }
+        };
+        let chunk = self.chunk_mut();
+        if rect.is_empty() {
+            chunk.set_visible(false);
+            chunk.set_geometry(None, changed_size_hints);
+        } else {
+            chunk.set_visible(true);
+            chunk.set_geometry(
+                Some(WidgetGeometry::new(&geometry, rect)),
+                changed_size_hints,
+            );
+        }
+    }
+
+    fn text_size_hints(&mut self) -> (SizeHints, SizeHints) {
+        let text = self.text_widget_mut();
+        let x = text.size_hint_x();
+        let y = text.size_hint_y(x.preferred);
+        (x, y)
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        if let Some(id) = self.busy_timer.take() {
+            id.cancel();
+        }
+    }
+}
+
+impl Widget for ProgressBar {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<ProgressChunk>(CHUNK_KEY);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Text>(TEXT_KEY)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        let mut this = Self {
+            base,
+            axis: Axis::X,
+            value: RangeValue::new(0..=100, 1),
+            format: "{percent}%".into(),
+            is_text_visible: true,
+            is_indeterminate: false,
+            busy_step: 0,
+            busy_timer: None,
+        };
+        this.set_style_attribute("axis".into(), Some(this.axis.name().into()));
+        this.update_text();
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let changed = &event.changed_size_hints;
+        self.layout_chunk(changed);
+        let (Some(geometry), Some(content)) = (self.base.geometry.clone(), self.content_rect())
+        else {
+            self.text_widget_mut().set_geometry(None, changed);
+            return Ok(());
+        };
+        // The text is centered over the whole bar.
+        let (hints_x, hints_y) = self.text_size_hints();
+        let text_rect = Rect::from_xywh(
+            content.left() + max(0.ppx(), content.size_x() - hints_x.preferred) / 2,
+            content.top() + max(0.ppx(), content.size_y() - hints_y.preferred) / 2,
+            hints_x.preferred.min(content.size_x()),
+            hints_y.preferred.min(content.size_y()),
+        );
+        self.text_widget_mut()
+            .set_geometry(Some(WidgetGeometry::new(&geometry, text_rect)), changed);
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let scale = self.base.scale();
+        let padding = self.base.common_style.grid.x.preferred_padding.sum();
+        Ok(match self.axis {
+            Axis::X => SizeHints {
+                min: MIN_LENGTH.lpx().to_physical(scale),
+                preferred: PREFERRED_LENGTH.lpx().to_physical(scale),
+                is_fixed: false,
+            },
+            Axis::Y => {
+                let text = if self.is_text_visible {
+                    self.text_size_hints().0.preferred
+                } else {
+                    0.ppx()
+                };
+                let size = max(text, MIN_THICKNESS.lpx().to_physical(scale)) + padding;
+                SizeHints {
+                    min: size,
+                    preferred: size,
+                    is_fixed: true,
+                }
+            }
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let scale = self.base.scale();
+        let padding = self.base.common_style.grid.y.preferred_padding.sum();
+        Ok(match self.axis {
+            Axis::X => {
+                let text = if self.is_text_visible {
+                    self.text_size_hints().1.preferred
+                } else {
+                    0.ppx()
+                };
+                let size = max(text, MIN_THICKNESS.lpx().to_physical(scale)) + padding;
+                SizeHints {
+                    min: size,
+                    preferred: size,
+                    is_fixed: true,
+                }
+            }
+            Axis::Y => SizeHints {
+                min: MIN_LENGTH.lpx().to_physical(scale),
+                preferred: PREFERRED_LENGTH.lpx().to_physical(scale),
+                is_fixed: false,
+            },
+        })
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::ProgressIndicator);
+        if !self.is_indeterminate {
+            node.set_numeric_value(self.value.value().into());
+            node.set_min_numeric_value(self.value.min().into());
+            node.set_max_numeric_value(self.value.max().into());
+            node.set_value(self.text());
+        }
+        node.set_orientation(match self.axis {
+            Axis::X => Orientation::Horizontal,
+            Axis::Y => Orientation::Vertical,
+        });
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        Ok(Some(node))
+    }
+}
+
+/// The filled part of a [ProgressBar].
+struct ProgressChunk {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for ProgressChunk {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+}
@@ -0,0 +1,350 @@
+use {
+    super::{
+        check_box::ComputedIndicatorStyle, image::Image, RawWidgetId, Widget, WidgetBaseOf,
+        WidgetExt,
+    },
+    crate::{
+        callback::{Callback, CallbackVec},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, MouseInputEvent,
+            MouseMoveEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        shared_window::SetFocusRequest,
+        style::{css::PseudoClass, get_style},
+        system::send_window_request,
+        text_editor::Text,
+    },
+    accesskit::{Action, Role, Toggled},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    std::{cell::RefCell, fmt::Display, rc::Rc},
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key, KeyCode, NamedKey, PhysicalKey},
+    },
+};
+
+/// A set of mutually exclusive radio buttons.
+///
+/// Checking a button of the group unchecks the other buttons of the same group.
+/// The group is a shared handle: clone it and pass it to
+/// [RadioButton::set_group] for every button that should belong to the group.
+#[derive(Clone, Default)]
+pub struct RadioGroup(Rc<RefCell<RadioGroupInner>>);
+
+struct RadioGroupInner {
+    buttons: Vec<(RawWidgetId, Callback<bool>)>,
+    checked: Option<RawWidgetId>,
+    on_changed: CallbackVec<Option<RawWidgetId>>,
+}
+
+impl Default for RadioGroupInner {
+    fn default() -> Self {
+        Self {
+            buttons: Vec::new(),
+            checked: None,
+            on_changed: CallbackVec::new(),
+        }
+    }
+}
+
+impl RadioGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ID of the checked button of the group.
+    pub fn checked_button(&self) -> Option<RawWidgetId> {
+        self.0.borrow().checked
+    }
+
+    /// The callback is invoked when another button of the group is checked by the user.
+    pub fn on_changed(&self, callback: Callback<Option<RawWidgetId>>) {
+        self.0.borrow_mut().on_changed.push(callback);
+    }
+
+    // `uncheck` is invoked with the `notify` argument of `set_checked`.
+    fn add(&self, id: RawWidgetId, uncheck: Callback<bool>) {
+        self.0.borrow_mut().buttons.push((id, uncheck));
+    }
+
+    fn remove(&self, id: RawWidgetId) {
+        let mut inner = self.0.borrow_mut();
+        inner.buttons.retain(|(button_id, _)| *button_id != id);
+        if inner.checked == Some(id) {
+            inner.checked = None;
+        }
+    }
+
+    fn set_checked(&self, id: RawWidgetId, checked: bool, notify: bool) {
+        let mut inner = self.0.borrow_mut();
+        let new_checked = if checked {
+            Some(id)
+        } else if inner.checked == Some(id) {
+            None
+        } else {
+            return;
+        };
+        if inner.checked == new_checked {
+            return;
+        }
+        if let Some(old_id) = inner.checked {
+            if let Some((_, uncheck)) = inner.buttons.iter().find(|(id, _)| *id == old_id) {
+                uncheck.invoke(notify);
+            }
+        }
+        inner.checked = new_checked;
+        if notify {
+            inner.on_changed.invoke(new_checked);
+        }
+    }
+}
+
+pub struct RadioButton {
+    base: WidgetBaseOf<Self>,
+    is_checked: bool,
+    is_pressed: bool,
+    group: Option<RadioGroup>,
+    on_toggled: CallbackVec<bool>,
+    style: Rc<ComputedIndicatorStyle>,
+}
+
+#[impl_with]
+impl RadioButton {
+    fn image_widget_mut(&mut self) -> &mut Image {
+        self.base.get_child_mut::<Image>(0).unwrap()
+    }
+
+    fn text_widget(&self) -> &Text {
+        self.base.get_child::<Text>(1).unwrap()
+    }
+
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(1).unwrap()
+    }
+
+    pub fn set_text(&mut self, text: impl Display) -> &mut Self {
+        self.text_widget_mut().set_text(text, Attrs::new());
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.text_widget().text()
+    }
+
+    /// Adds the button to `group`, removing it from its previous group.
+    pub fn set_group(&mut self, group: &RadioGroup) -> &mut Self {
+        let id = self.base.id().raw();
+        if let Some(old_group) = self.group.take() {
+            old_group.remove(id);
+        }
+        group.add(
+            id,
+            self.callback(|this, notify| {
+                this.set_checked_internal(false, notify);
+                Ok(())
+            }),
+        );
+        if self.is_checked {
+            group.set_checked(id, true, false);
+        }
+        self.group = Some(group.clone());
+        self
+    }
+
+    pub fn group(&self) -> Option<&RadioGroup> {
+        self.group.as_ref()
+    }
+
+    pub fn set_checked(&mut self, checked: bool) -> &mut Self {
+        self.set_checked_internal(checked, false);
+        if let Some(group) = &self.group {
+            group.set_checked(self.base.id().raw(), checked, false);
+        }
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+
+    /// The callback is invoked when the button is checked by the user or
+    /// unchecked because another button of its group was checked by the user.
+    pub fn on_toggled(&mut self, callback: Callback<bool>) -> &mut Self {
+        self.on_toggled.push(callback);
+        self
+    }
+
+    /// Checks the button as if it was clicked.
+    pub fn trigger(&mut self) {
+        if self.is_checked {
+            return;
+        }
+        self.set_checked_internal(true, true);
+        if let Some(group) = &self.group {
+            group.set_checked(self.base.id().raw(), true, true);
+        }
+    }
+
+    fn set_checked_internal(&mut self, checked: bool, notify: bool) {
+        if self.is_checked == checked {
+            return;
+        }
+        self.is_checked = checked;
+        self.set_pseudo_class(PseudoClass::Checked, checked);
+        if notify {
+            self.on_toggled.invoke(checked);
+        }
+        self.base.update();
+    }
+
+    fn set_pressed(&mut self, value: bool) {
+        if self.is_pressed == value {
+            return;
+        }
+        self.is_pressed = value;
+        self.set_pseudo_class(PseudoClass::Active, value);
+        self.base.update();
+    }
+
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        self.image_widget_mut().set_visible(icon.is_some());
+        self.image_widget_mut().set_prescaled(true);
+        self.image_widget_mut().set_pixmap(icon);
+    }
+}
+
+impl Widget for RadioButton {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child::<Image>()
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child::<Text>()
+            .set_column(1)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            is_checked: false,
+            is_pressed: false,
+            group: None,
+            on_toggled: CallbackVec::new(),
+        };
+        this.refresh_style();
+        this
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if self.is_pressed {
+            let rect = self.base.rect_in_self_or_err()?;
+            self.set_pseudo_class(PseudoClass::Active, rect.contains(event.pos));
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button == MouseButton::Left {
+            if event.state.is_pressed() {
+                self.set_pressed(true);
+                if !self.base.is_focused() && self.base.is_focusable() {
+                    send_window_request(
+                        self.base.window_or_err()?.id(),
+                        SetFocusRequest {
+                            widget_id: self.base.id().into(),
+                            reason: FocusReason::Mouse,
+                        },
+                    );
+                }
+            } else if self.is_pressed {
+                self.set_pressed(false);
+                if self.base.rect_in_self_or_err()?.contains(event.pos) {
+                    self.trigger();
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if event.info.physical_key == PhysicalKey::Code(KeyCode::Space)
+            || event.info.logical_key == Key::Named(NamedKey::Space)
+        {
+            if event.info.state.is_pressed() {
+                self.set_pressed(true);
+            } else if self.is_pressed {
+                self.set_pressed(false);
+                self.trigger();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Click => self.trigger(),
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::RadioButton);
+        node.set_label(self.text());
+        node.set_toggled(if self.is_checked {
+            Toggled::True
+        } else {
+            Toggled::False
+        });
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Click);
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+impl Drop for RadioButton {
+    fn drop(&mut self) {
+        if let Some(group) = &self.group {
+            group.remove(self.base.id().raw());
+        }
+    }
+}
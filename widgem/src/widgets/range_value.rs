@@ -0,0 +1,109 @@
+use {
+    crate::callback::{Callback, Callbacks},
+    log::warn,
+    std::ops::RangeInclusive,
+};
+
+/// Integer value constrained to a range, with the associated change notifications.
+///
+/// This is the value model shared by [ScrollBar](super::scroll_bar::ScrollBar)
+/// and [Slider](super::slider::Slider).
+pub(crate) struct RangeValue {
+    range: RangeInclusive<i32>,
+    value: i32,
+    step: i32,
+    value_changed: Callbacks<i32>,
+}
+
+impl RangeValue {
+    pub fn new(range: RangeInclusive<i32>, step: i32) -> Self {
+        Self {
+            value: *range.start(),
+            range,
+            step,
+            value_changed: Default::default(),
+        }
+    }
+
+    pub fn range(&self) -> RangeInclusive<i32> {
+        self.range.clone()
+    }
+
+    pub fn min(&self) -> i32 {
+        *self.range.start()
+    }
+
+    pub fn max(&self) -> i32 {
+        *self.range.end()
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn step(&self) -> i32 {
+        self.step
+    }
+
+    pub fn set_step(&mut self, step: i32) {
+        self.step = step;
+    }
+
+    pub fn on_value_changed(&mut self, callback: Callback<i32>) {
+        self.value_changed.add(callback);
+    }
+
+    /// Returns `true` if the range has changed.
+    ///
+    /// The value is clamped to the new range.
+    pub fn set_range(&mut self, mut range: RangeInclusive<i32>) -> bool {
+        if range.end() < range.start() {
+            warn!("invalid value range");
+            range = *range.start()..=*range.start();
+        }
+        if self.range == range {
+            return false;
+        }
+        self.range = range;
+        self.set_value(self.value.clamp(self.min(), self.max()), true);
+        true
+    }
+
+    /// Returns `true` if the value has changed.
+    ///
+    /// `from_setter` should be `false` if the value is changed by the user.
+    pub fn set_value(&mut self, mut value: i32, from_setter: bool) -> bool {
+        if value < self.min() || value > self.max() {
+            warn!("value out of bounds");
+            value = value.clamp(self.min(), self.max());
+        }
+        if self.value == value {
+            return false;
+        }
+        self.value = value;
+        self.value_changed.invoke(value, from_setter);
+        true
+    }
+
+    /// Changes the value by `delta`, stopping at the bounds of the range.
+    pub fn add(&mut self, delta: i32, from_setter: bool) -> bool {
+        let value = self
+            .value
+            .saturating_add(delta)
+            .clamp(self.min(), self.max());
+        self.set_value(value, from_setter)
+    }
+
+    /// Position of the value within the range, from 0.0 to 1.0.
+    pub fn ratio(&self) -> f32 {
+        if self.min() == self.max() {
+            return 0.0;
+        }
+        (self.value - self.min()) as f32 / (self.max() - self.min()) as f32
+    }
+
+    /// Returns the value at `ratio` (from 0.0 to 1.0) within the range.
+    pub fn value_at_ratio(&self, ratio: f32) -> i32 {
+        (ratio.clamp(0.0, 1.0) * (self.max() - self.min()) as f32).round() as i32 + self.min()
+    }
+}
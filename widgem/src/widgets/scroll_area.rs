@@ -3,13 +3,13 @@ use {
         scroll_bar::ScrollBar, Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry,
     },
     crate::{
-        event::{LayoutEvent, MouseScrollEvent},
+        event::{LayoutEvent, MouseScrollEvent, ScrollToRectRequest},
         impl_widget_base,
         layout::{grid::grid_layout, SizeHints},
-        types::{Axis, PhysicalPixels, PpxSuffix, Rect},
+        types::{Axis, PhysicalPixels, Point, PpxSuffix, Rect, Size},
     },
     anyhow::Result,
-    std::cmp::max,
+    std::cmp::{max, min},
     widgem_macros::impl_with,
 };
 
@@ -43,6 +43,33 @@ impl ScrollArea {
             .add_child_with_key::<T>(KEY_CONTENT_IN_VIEWPORT)
     }
 
+    pub fn content<T: Widget>(&self) -> Option<&T> {
+        self.base
+            .get_dyn_child(INDEX_VIEWPORT)
+            .unwrap()
+            .base()
+            .get_child::<T>(KEY_CONTENT_IN_VIEWPORT)
+            .ok()
+    }
+
+    pub fn content_mut<T: Widget>(&mut self) -> Option<&mut T> {
+        self.base
+            .get_dyn_child_mut(INDEX_VIEWPORT)
+            .unwrap()
+            .base_mut()
+            .get_child_mut::<T>(KEY_CONTENT_IN_VIEWPORT)
+            .ok()
+    }
+
+    /// Size of the visible area of the content.
+    pub fn viewport_size(&self) -> Option<Size> {
+        self.base
+            .get_dyn_child(INDEX_VIEWPORT)
+            .unwrap()
+            .base()
+            .size()
+    }
+
     // pub fn set_content(&mut self, content: Box<dyn Widget>) {
     //     if self.has_content() {
     //         self.common.children[INDEX_VIEWPORT]
@@ -90,6 +117,21 @@ impl ScrollArea {
     //     }
     // }
 
+    // Returns the offset of the widget at `address` relative to the scroll area.
+    fn offset_of(&self, address: &WidgetAddress) -> Option<Point> {
+        let mut offset = Point::default();
+        let mut widget: &dyn Widget = self;
+        for index in self.base.address().len()..address.len() {
+            let (key, id) = address.item_at(index)?;
+            widget = widget.base().children.get(key)?.as_ref();
+            if widget.base().id() != *id {
+                return None;
+            }
+            offset = offset + widget.base().rect_in_parent()?.top_left();
+        }
+        Some(offset)
+    }
+
     fn relayout(&mut self, changed_size_hints: &[WidgetAddress]) -> Result<()> {
         let geometry = self.base.geometry_or_err()?.clone();
         grid_layout(self, changed_size_hints);
@@ -191,6 +233,68 @@ impl Widget for ScrollArea {
         self.relayout(&event.changed_size_hints)
     }
 
+    fn handle_scroll_to_rect_request(&mut self, request: ScrollToRectRequest) -> Result<bool> {
+        let viewport_key = INDEX_VIEWPORT.into();
+        if request
+            .address
+            .item_at(self.base.address().len())
+            .is_none_or(|(key, _)| *key != viewport_key)
+        {
+            return Ok(false);
+        }
+        let Some(offset) = self.offset_of(&request.address) else {
+            return Ok(false);
+        };
+        let Some(viewport_rect) = self
+            .base
+            .get_dyn_child(INDEX_VIEWPORT)
+            .unwrap()
+            .base()
+            .rect_in_parent()
+        else {
+            return Ok(false);
+        };
+        let rect = request.rect.translate(offset);
+        let mut scrolled = false;
+        for (index, delta) in [
+            (
+                INDEX_SCROLL_BAR_X,
+                scroll_delta(
+                    rect.left(),
+                    rect.right(),
+                    viewport_rect.left(),
+                    viewport_rect.right(),
+                ),
+            ),
+            (
+                INDEX_SCROLL_BAR_Y,
+                scroll_delta(
+                    rect.top(),
+                    rect.bottom(),
+                    viewport_rect.top(),
+                    viewport_rect.bottom(),
+                ),
+            ),
+        ] {
+            if delta == 0.ppx() {
+                continue;
+            }
+            let scroll_bar = self.base.get_child_mut::<ScrollBar>(index).unwrap();
+            let new_value = scroll_bar.value() + delta.to_i32();
+            scroll_bar.set_value(new_value.clamp(
+                *scroll_bar.value_range().start(),
+                *scroll_bar.value_range().end(),
+            ));
+            scrolled = true;
+        }
+        if scrolled {
+            // Value change callbacks are not invoked for setter calls.
+            self.relayout(&[])?;
+        }
+        // Let nested scrollable widgets (e.g. text inputs) adjust their scroll as well.
+        Ok(false)
+    }
+
     fn handle_mouse_scroll(&mut self, event: MouseScrollEvent) -> Result<bool> {
         let delta = event.unified_delta(&self.base);
 
@@ -217,6 +321,22 @@ impl Widget for ScrollArea {
     }
 }
 
+// Returns the minimal scroll change that makes `start..end` visible within `visible_start..visible_end`.
+fn scroll_delta(
+    start: PhysicalPixels,
+    end: PhysicalPixels,
+    visible_start: PhysicalPixels,
+    visible_end: PhysicalPixels,
+) -> PhysicalPixels {
+    if start < visible_start {
+        start - visible_start
+    } else if end > visible_end {
+        min(end - visible_end, start - visible_start)
+    } else {
+        0.ppx()
+    }
+}
+
 pub struct Viewport {
     base: WidgetBaseOf<Self>,
 }
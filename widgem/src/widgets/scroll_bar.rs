@@ -1,7 +1,10 @@
 use {
-    super::{button::Button, Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry},
+    super::{
+        button::Button, range_value::RangeValue, Widget, WidgetAddress, WidgetBaseOf, WidgetExt,
+        WidgetGeometry,
+    },
     crate::{
-        callback::{Callback, Callbacks},
+        callback::Callback,
         event::{
             Event, FocusInEvent, FocusOutEvent, KeyboardInputEvent, LayoutEvent, MouseScrollEvent,
         },
@@ -11,12 +14,8 @@ use {
         types::{Axis, PhysicalPixels, Point, PpxSuffix, Rect, Size},
     },
     anyhow::Result,
-    log::warn,
     ordered_float::NotNan,
-    std::{
-        cmp::{max, min},
-        ops::RangeInclusive,
-    },
+    std::{cmp::max, ops::RangeInclusive},
     widgem_macros::impl_with,
     winit::{
         event::{ElementState, MouseButton},
@@ -30,11 +29,8 @@ pub struct ScrollBar {
     current_grip_pos: PhysicalPixels,
     max_slider_pos: PhysicalPixels,
     grip_size: Size,
-    value_range: RangeInclusive<i32>,
-    current_value: i32,
-    step: i32,
+    value: RangeValue,
     slider_grab_pos: Option<(Point, PhysicalPixels)>,
-    value_changed: Callbacks<i32>,
     pager_direction: i32,
     pager_mouse_pos_in_window: Point,
 }
@@ -63,10 +59,8 @@ impl ScrollBar {
     }
 
     fn increase_internal(&mut self, from_setter: bool) {
-        self.set_value_internal(
-            min(*self.value_range().end(), self.value() + self.step),
-            from_setter,
-        );
+        let step = self.value.step();
+        self.add_value(step, from_setter);
     }
 
     pub fn decrease(&mut self) {
@@ -74,10 +68,8 @@ impl ScrollBar {
     }
 
     fn decrease_internal(&mut self, from_setter: bool) {
-        self.set_value_internal(
-            min(*self.value_range().end(), self.value() - self.step),
-            from_setter,
-        );
+        let step = self.value.step();
+        self.add_value(-step, from_setter);
     }
 
     pub fn axis(&mut self) -> Axis {
@@ -89,6 +81,7 @@ impl ScrollBar {
             return self;
         }
         self.axis = axis;
+        self.set_style_attribute("axis".into(), Some(axis.name().into()));
         match axis {
             Axis::X => {
                 let decrease = self.base.get_child_mut::<Button>(INDEX_DECREASE).unwrap();
@@ -164,7 +157,7 @@ impl ScrollBar {
     }
 
     pub fn on_value_changed(&mut self, callback: Callback<i32>) -> &mut Self {
-        self.value_changed.add(callback);
+        self.value.on_value_changed(callback);
         self
     }
 
@@ -182,37 +175,19 @@ impl ScrollBar {
 
     fn slider_moved(&mut self, pos_in_window: Point) -> Result<()> {
         if let Some((start_mouse_pos, start_slider_pos)) = self.slider_grab_pos {
-            match self.axis {
-                Axis::X => {
-                    let new_pos = start_slider_pos - start_mouse_pos.x() + pos_in_window.x();
-                    self.current_grip_pos = new_pos.clamp(0.ppx(), self.max_slider_pos);
-                    let new_value = if self.max_slider_pos == 0.ppx() {
-                        *self.value_range.start()
-                    } else {
-                        ((self.current_grip_pos.to_i32() as f32)
-                            / (self.max_slider_pos.to_i32() as f32)
-                            * (*self.value_range.end() - *self.value_range.start()) as f32)
-                            .round() as i32
-                            + self.value_range.start()
-                    };
-                    self.set_value_internal(new_value, false);
-                }
-                Axis::Y => {
-                    // TODO: deduplicate
-                    let new_pos = start_slider_pos - start_mouse_pos.y() + pos_in_window.y();
-                    self.current_grip_pos = new_pos.clamp(0.ppx(), self.max_slider_pos);
-                    let new_value = if self.max_slider_pos == 0.ppx() {
-                        *self.value_range.start()
-                    } else {
-                        ((self.current_grip_pos.to_i32() as f32)
-                            / (self.max_slider_pos.to_i32() as f32)
-                            * (*self.value_range.end() - *self.value_range.start()) as f32)
-                            .round() as i32
-                            + *self.value_range.start()
-                    };
-                    self.set_value_internal(new_value, false);
-                }
-            }
+            let new_pos = match self.axis {
+                Axis::X => start_slider_pos - start_mouse_pos.x() + pos_in_window.x(),
+                Axis::Y => start_slider_pos - start_mouse_pos.y() + pos_in_window.y(),
+            };
+            self.current_grip_pos = new_pos.clamp(0.ppx(), self.max_slider_pos);
+            let new_value = if self.max_slider_pos == 0.ppx() {
+                self.value.min()
+            } else {
+                self.value.value_at_ratio(
+                    (self.current_grip_pos.to_i32() as f32) / (self.max_slider_pos.to_i32() as f32),
+                )
+            };
+            self.set_value_internal(new_value, false);
         }
         Ok(())
     }
@@ -304,13 +279,8 @@ impl ScrollBar {
     }
 
     pub fn page_forward_internal(&mut self, from_setter: bool) {
-        self.set_value_internal(
-            min(
-                *self.value_range.end(),
-                self.current_value + self.page_step(),
-            ),
-            from_setter,
-        );
+        let page_step = self.page_step();
+        self.add_value(page_step, from_setter);
     }
 
     pub fn page_back(&mut self) {
@@ -318,73 +288,56 @@ impl ScrollBar {
     }
 
     pub fn page_back_internal(&mut self, from_setter: bool) {
-        self.set_value_internal(
-            max(
-                *self.value_range.start(),
-                self.current_value - self.page_step(),
-            ),
-            from_setter,
-        );
+        let page_step = self.page_step();
+        self.add_value(-page_step, from_setter);
     }
 
-    pub fn set_value_range(&mut self, mut range: RangeInclusive<i32>) -> &mut Self {
-        if range.end() < range.start() {
-            warn!("invalid scroll bar range");
-            range = *range.start()..=*range.start();
-        }
-        if self.value_range == range {
+    pub fn set_value_range(&mut self, range: RangeInclusive<i32>) -> &mut Self {
+        if !self.value.set_range(range) {
             return self;
         }
-        self.value_range = range;
         self.update_grip_size(&[]).or_report_err();
-        if self.current_value < *self.value_range.start()
-            || self.current_value > *self.value_range.end()
-        {
-            self.set_value_internal(
-                self.current_value
-                    .clamp(*self.value_range.start(), *self.value_range.end()),
-                true,
-            );
-        } else {
-            self.update_grip_pos(&[]);
-        }
+        self.update_grip_pos(&[]);
         self.update_decrease_increase();
         self
     }
 
     pub fn value_range(&self) -> RangeInclusive<i32> {
-        self.value_range.clone()
+        self.value.range()
     }
 
     pub fn set_step(&mut self, step: i32) {
-        self.step = step;
+        self.value.set_step(step);
     }
 
     pub fn set_value(&mut self, value: i32) -> &mut Self {
         self.set_value_internal(value, true)
     }
 
-    fn set_value_internal(&mut self, mut value: i32, from_setter: bool) -> &mut Self {
-        if value < *self.value_range.start() || value > *self.value_range.end() {
-            warn!("scroll bar value out of bounds");
-            value = value.clamp(*self.value_range.start(), *self.value_range.end());
+    fn set_value_internal(&mut self, value: i32, from_setter: bool) -> &mut Self {
+        if self.value.set_value(value, from_setter) {
+            self.value_changed();
         }
-        if self.current_value == value {
-            return self;
+        self
+    }
+
+    fn add_value(&mut self, delta: i32, from_setter: bool) {
+        if self.value.add(delta, from_setter) {
+            self.value_changed();
         }
-        self.current_value = value;
-        self.value_changed.invoke(self.current_value, from_setter);
+    }
+
+    fn value_changed(&mut self) {
         self.update_grip_pos(&[]);
         self.update_decrease_increase();
-        self
     }
 
     fn update_decrease_increase(&mut self) {
         let decrease = self.base.get_child_mut::<Button>(INDEX_DECREASE).unwrap();
-        decrease.set_enabled(self.current_value > *self.value_range.start());
+        decrease.set_enabled(self.value.value() > self.value.min());
 
         let increase = self.base.get_child_mut::<Button>(INDEX_INCREASE).unwrap();
-        increase.set_enabled(self.current_value < *self.value_range.end());
+        increase.set_enabled(self.value.value() < self.value.max());
     }
 
     fn update_grip_pos(&mut self, changed_size_hints: &[WidgetAddress]) {
@@ -393,7 +346,7 @@ impl ScrollBar {
             Axis::X => Point::new(self.current_grip_pos, 0.ppx()),
             Axis::Y => Point::new(0.ppx(), self.current_grip_pos),
         };
-        let can_scroll = self.value_range.start() != self.value_range.end();
+        let can_scroll = self.value.min() != self.value.max();
         let rect = if can_scroll {
             Some(Rect::from_pos_size(shift, self.grip_size))
         } else {
@@ -432,21 +385,11 @@ impl ScrollBar {
     }
 
     pub fn value(&self) -> i32 {
-        self.current_value
+        self.value.value()
     }
 
     fn value_to_slider_pos(&self) -> PhysicalPixels {
-        if *self.value_range.start() > *self.value_range.end() {
-            warn!("invalid scroll bar range");
-            return 0.ppx();
-        }
-        if *self.value_range.start() == *self.value_range.end() {
-            return 0.ppx();
-        }
-        let pos = (self.current_value - *self.value_range.start()) as f32
-            / (*self.value_range.end() - *self.value_range.start()) as f32
-            * self.max_slider_pos.to_i32() as f32;
-        PhysicalPixels::from_i32(pos.round() as i32)
+        self.max_slider_pos.mul_f32_round(self.value.ratio())
     }
 
     fn update_grip_size(&mut self, changed_size_hints: &[WidgetAddress]) -> Result<()> {
@@ -492,8 +435,7 @@ impl ScrollBar {
         // visible_ratio = viewport_size / (max_value + viewport_size);
         // we may consider adding a page_step property instead of always using size_along_axis.
         let size_plus_range =
-            PhysicalPixels::from_i32(self.value_range.end() - self.value_range.start())
-                + size_along_axis;
+            PhysicalPixels::from_i32(self.value.max() - self.value.min()) + size_along_axis;
         let visible_ratio = if size_plus_range == 0.ppx() {
             0.0
         } else {
@@ -584,14 +526,12 @@ impl Widget for ScrollBar {
             current_grip_pos: 0.ppx(),
             max_slider_pos: 0.ppx(),
             grip_size: Size::default(),
-            value_range: 0..=100,
-            current_value: 0,
-            step: 5,
+            value: RangeValue::new(0..=100, 5),
             slider_grab_pos: None,
-            value_changed: Default::default(),
             pager_direction: 0,
             pager_mouse_pos_in_window: Point::default(),
         };
+        this.set_style_attribute("axis".into(), Some(axis.name().into()));
 
         let slider_pressed = this.callback(Self::slider_pressed);
         let slider_moved = this.callback(Self::slider_moved);
@@ -674,11 +614,7 @@ impl Widget for ScrollBar {
         } else {
             delta.y
         };
-        let new_value = self.value() - max_delta.round() as i32;
-        self.set_value_internal(
-            new_value.clamp(*self.value_range.start(), *self.value_range.end()),
-            false,
-        );
+        self.add_value(-max_delta.round() as i32, false);
         Ok(true)
     }
 
@@ -705,11 +641,11 @@ impl Widget for ScrollBar {
                     Ok(true)
                 }
                 NamedKey::End => {
-                    self.set_value_internal(*self.value_range().end(), false);
+                    self.set_value_internal(self.value.max(), false);
                     Ok(true)
                 }
                 NamedKey::Home => {
-                    self.set_value_internal(*self.value_range().start(), false);
+                    self.set_value_internal(self.value.min(), false);
                     Ok(true)
                 }
                 NamedKey::PageDown => {
@@ -0,0 +1,611 @@
+use {
+    super::{
+        button::Button, range_value::RangeValue, Widget, WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        callback::Callback,
+        draw::DrawEvent,
+        event::{
+            AccessibilityActionEvent, Event, FocusInEvent, FocusOutEvent, FocusReason,
+            KeyboardInputEvent, LayoutEvent, MouseInputEvent, MouseScrollEvent,
+        },
+        impl_widget_base,
+        layout::SizeHints,
+        shared_window::SetFocusRequest,
+        system::send_window_request,
+        types::{Axis, LpxSuffix, PhysicalPixels, Point, PpxSuffix, Rect, Size},
+    },
+    accesskit::{Action, ActionData, Orientation, Role},
+    anyhow::Result,
+    ordered_float::NotNan,
+    std::ops::RangeInclusive,
+    widgem_macros::impl_with,
+    winit::{
+        event::{ElementState, MouseButton},
+        keyboard::{Key, NamedKey},
+    },
+};
+
+/// Placement of the tick marks of a [Slider].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickPosition {
+    #[default]
+    None,
+    /// Above a horizontal slider or to the left of a vertical slider.
+    Before,
+    /// Below a horizontal slider or to the right of a vertical slider.
+    After,
+    Both,
+}
+
+/// A handle that can be moved along a track to select a value from a range.
+///
+/// A vertical slider displays the minimum value at the bottom.
+pub struct Slider {
+    base: WidgetBaseOf<Self>,
+    axis: Axis,
+    value: RangeValue,
+    page_step: i32,
+    tick_position: TickPosition,
+    tick_interval: i32,
+    handle_size: Size,
+    max_handle_pos: PhysicalPixels,
+    handle_grab_pos: Option<(Point, PhysicalPixels)>,
+}
+
+const INDEX_HANDLE: u64 = 0;
+
+const TRACK_WIDTH: f32 = 4.0;
+const TICK_LENGTH: f32 = 4.0;
+const PREFERRED_LENGTH_MULTIPLIER: i32 = 8;
+const MIN_LENGTH_MULTIPLIER: i32 = 2;
+
+#[impl_with]
+impl Slider {
+    fn handle(&mut self) -> &mut Button {
+        self.base.get_child_mut::<Button>(INDEX_HANDLE).unwrap()
+    }
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    pub fn set_axis(&mut self, axis: Axis) -> &mut Self {
+        if self.axis == axis {
+            return self;
+        }
+        self.axis = axis;
+        self.set_style_attribute("axis".into(), Some(axis.name().into()));
+        let handle = self.handle();
+        match axis {
+            Axis::X => {
+                handle.add_class("slider_handle_x".into());
+                handle.remove_class("slider_handle_y".into());
+            }
+            Axis::Y => {
+                handle.remove_class("slider_handle_x".into());
+                handle.add_class("slider_handle_y".into());
+            }
+        }
+        self.base.size_hint_changed();
+        self
+    }
+
+    pub fn set_value_range(&mut self, range: RangeInclusive<i32>) -> &mut Self {
+        if self.value.set_range(range) {
+            self.value_changed();
+        }
+        self
+    }
+
+    pub fn value_range(&self) -> RangeInclusive<i32> {
+        self.value.range()
+    }
+
+    pub fn set_value(&mut self, value: i32) -> &mut Self {
+        self.set_value_internal(value, true);
+        self
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value.value()
+    }
+
+    /// Sets the amount by which the value is changed by the arrow keys and the mouse wheel.
+    pub fn set_step(&mut self, step: i32) -> &mut Self {
+        self.value.set_step(step);
+        self
+    }
+
+    pub fn step(&self) -> i32 {
+        self.value.step()
+    }
+
+    /// Sets the amount by which the value is changed by Page Up and Page Down keys
+    /// and by clicking on the track.
+    pub fn set_page_step(&mut self, page_step: i32) -> &mut Self {
+        self.page_step = page_step;
+        self
+    }
+
+    pub fn page_step(&self) -> i32 {
+        self.page_step
+    }
+
+    pub fn set_tick_position(&mut self, position: TickPosition) -> &mut Self {
+        self.tick_position = position;
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    pub fn tick_position(&self) -> TickPosition {
+        self.tick_position
+    }
+
+    /// Sets the distance between tick marks in value units.
+    ///
+    /// If the interval is 0 (the default), page step is used as the interval.
+    pub fn set_tick_interval(&mut self, interval: i32) -> &mut Self {
+        self.tick_interval = interval;
+        self.base.update();
+        self
+    }
+
+    pub fn tick_interval(&self) -> i32 {
+        self.tick_interval
+    }
+
+    /// The callback is invoked when the value is changed by the user.
+    ///
+    /// Changes made by calling a setter are only reported if the callback was created
+    /// with `with_send_signals_on_setter_calls(true)`.
+    pub fn on_value_changed(&mut self, callback: Callback<i32>) -> &mut Self {
+        self.value.on_value_changed(callback);
+        self
+    }
+
+    pub fn increase(&mut self) {
+        self.add_value(self.value.step(), true);
+    }
+
+    pub fn decrease(&mut self) {
+        self.add_value(-self.value.step(), true);
+    }
+
+    fn set_value_internal(&mut self, value: i32, from_setter: bool) {
+        if self.value.set_value(value, from_setter) {
+            self.value_changed();
+        }
+    }
+
+    fn add_value(&mut self, delta: i32, from_setter: bool) {
+        if self.value.add(delta, from_setter) {
+            self.value_changed();
+        }
+    }
+
+    fn value_changed(&mut self) {
+        self.update_handle_geometry();
+        self.base.update();
+    }
+
+    fn focus(&mut self) -> Result<()> {
+        if !self.base.is_focused() && self.base.is_focusable() {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn tick_length(&self) -> PhysicalPixels {
+        TICK_LENGTH.lpx().to_physical(self.base.scale())
+    }
+
+    fn tick_length_before(&self) -> PhysicalPixels {
+        match self.tick_position {
+            TickPosition::Before | TickPosition::Both => self.tick_length(),
+            TickPosition::None | TickPosition::After => 0.ppx(),
+        }
+    }
+
+    fn tick_length_after(&self) -> PhysicalPixels {
+        match self.tick_position {
+            TickPosition::After | TickPosition::Both => self.tick_length(),
+            TickPosition::None | TickPosition::Before => 0.ppx(),
+        }
+    }
+
+    fn handle_size_along_axis(&self) -> PhysicalPixels {
+        match self.axis {
+            Axis::X => self.handle_size.x(),
+            Axis::Y => self.handle_size.y(),
+        }
+    }
+
+    /// Position of the handle's start along the axis, from 0 to `max_handle_pos`.
+    fn value_to_handle_pos(&self, value: i32) -> PhysicalPixels {
+        let mut ratio = if self.value.min() == self.value.max() {
+            0.0
+        } else {
+            (value - self.value.min()) as f32 / (self.value.max() - self.value.min()) as f32
+        };
+        if self.axis == Axis::Y {
+            ratio = 1.0 - ratio;
+        }
+        self.max_handle_pos.mul_f32_round(ratio)
+    }
+
+    fn handle_pos_to_value(&self, pos: PhysicalPixels) -> i32 {
+        if self.max_handle_pos <= 0.ppx() {
+            return self.value.min();
+        }
+        let mut ratio = pos.to_i32() as f32 / self.max_handle_pos.to_i32() as f32;
+        if self.axis == Axis::Y {
+            ratio = 1.0 - ratio;
+        }
+        self.value.value_at_ratio(ratio)
+    }
+
+    fn update_handle_size(&mut self) {
+        let Some(size) = self.base.size() else {
+            return;
+        };
+        let axis = self.axis;
+        let handle = self.handle();
+        let size_x = handle.size_hint_x().preferred;
+        let size_y = handle.size_hint_y(size_x).preferred;
+        self.handle_size = Size::new(size_x, size_y);
+        let size_along_axis = match axis {
+            Axis::X => size.x(),
+            Axis::Y => size.y(),
+        };
+        self.max_handle_pos = (size_along_axis - self.handle_size_along_axis()).max(0.ppx());
+    }
+
+    fn handle_rect(&self) -> Option<Rect> {
+        let size = self.base.size()?;
+        let pos = self.value_to_handle_pos(self.value.value());
+        let top_left = match self.axis {
+            Axis::X => Point::new(pos, (size.y() - self.handle_size.y()) / 2),
+            Axis::Y => Point::new((size.x() - self.handle_size.x()) / 2, pos),
+        };
+        Some(Rect::from_pos_size(top_left, self.handle_size))
+    }
+
+    fn update_handle_geometry(&mut self) {
+        let Some(geometry) = self.base.geometry.clone() else {
+            return;
+        };
+        let rect = self.handle_rect();
+        self.handle()
+            .set_geometry(rect.map(|rect| WidgetGeometry::new(&geometry, rect)), &[]);
+    }
+
+    fn handle_pressed(&mut self, (pos_in_window, state): (Point, ElementState)) -> Result<()> {
+        match state {
+            ElementState::Pressed => {
+                if self.base.is_enabled() {
+                    let pos = self.value_to_handle_pos(self.value.value());
+                    self.handle_grab_pos = Some((pos_in_window, pos));
+                    self.focus()?;
+                }
+            }
+            ElementState::Released => {
+                self.handle_grab_pos = None;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_moved(&mut self, pos_in_window: Point) -> Result<()> {
+        if let Some((start_mouse_pos, start_handle_pos)) = self.handle_grab_pos {
+            let new_pos = match self.axis {
+                Axis::X => start_handle_pos - start_mouse_pos.x() + pos_in_window.x(),
+                Axis::Y => start_handle_pos - start_mouse_pos.y() + pos_in_window.y(),
+            };
+            let value = self.handle_pos_to_value(new_pos.clamp(0.ppx(), self.max_handle_pos));
+            self.set_value_internal(value, false);
+        }
+        Ok(())
+    }
+
+    fn draw_ticks(&self, event: &DrawEvent, size: Size) {
+        let interval = if self.tick_interval > 0 {
+            self.tick_interval
+        } else {
+            self.page_step
+        };
+        if interval <= 0 || self.tick_position == TickPosition::None {
+            return;
+        }
+        let color = self.base.common_style.text_color;
+        let line_width = 1.0.lpx().to_physical(self.base.scale());
+        let tick_length = self.tick_length();
+        let size_across_axis = match self.axis {
+            Axis::X => size.y(),
+            Axis::Y => size.x(),
+        };
+        let handle_size_across_axis = match self.axis {
+            Axis::X => self.handle_size.y(),
+            Axis::Y => self.handle_size.x(),
+        };
+        let handle_start = (size_across_axis - handle_size_across_axis) / 2;
+        let mut tick_starts = Vec::new();
+        if self.tick_length_before() > 0.ppx() {
+            tick_starts.push(handle_start - tick_length);
+        }
+        if self.tick_length_after() > 0.ppx() {
+            tick_starts.push(handle_start + handle_size_across_axis);
+        }
+
+        let mut value = self.value.min();
+        loop {
+            let pos = self.value_to_handle_pos(value) + self.handle_size_along_axis() / 2
+                - line_width / 2;
+            for &start in &tick_starts {
+                let rect = match self.axis {
+                    Axis::X => Rect::from_xywh(pos, start, line_width, tick_length),
+                    Axis::Y => Rect::from_xywh(start, pos, tick_length, line_width),
+                };
+                event.fill_rect(rect, color);
+            }
+            if value >= self.value.max() {
+                break;
+            }
+            value = value.saturating_add(interval).min(self.value.max());
+        }
+    }
+}
+
+impl Widget for Slider {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child_with_key::<Button>(INDEX_HANDLE)
+            .set_text("slider handle")
+            .set_accessibility_node_enabled(false)
+            .set_focusable(false)
+            .add_class("slider_handle_x".into())
+            .set_text_visible(false)
+            .set_mouse_leave_sensitive(false);
+
+        let mut this = Self {
+            base,
+            axis: Axis::X,
+            value: RangeValue::new(0..=100, 1),
+            page_step: 10,
+            tick_position: TickPosition::None,
+            tick_interval: 0,
+            handle_size: Size::default(),
+            max_handle_pos: 0.ppx(),
+            handle_grab_pos: None,
+        };
+        this.set_style_attribute("axis".into(), Some(this.axis.name().into()));
+
+        let handle_pressed = this.callback(Self::handle_pressed);
+        let handle_moved = this.callback(Self::handle_moved);
+        this.handle().base_mut().event_filter = Some(Box::new(move |event| {
+            match event {
+                Event::MouseInput(e) => {
+                    if e.button == MouseButton::Left {
+                        handle_pressed.invoke((e.pos_in_window, e.state));
+                    }
+                }
+                Event::MouseMove(e) => handle_moved.invoke(e.pos_in_window),
+                _ => {}
+            }
+            Ok(false)
+        }));
+        this
+    }
+
+    fn handle_layout(&mut self, _event: LayoutEvent) -> Result<()> {
+        self.update_handle_size();
+        self.update_handle_geometry();
+        Ok(())
+    }
+
+    fn handle_draw(&mut self, event: DrawEvent) -> Result<()> {
+        let size = self.base.size_or_err()?;
+        let track_width = TRACK_WIDTH.lpx().to_physical(self.base.scale());
+        let handle_len = self.handle_size_along_axis();
+        let track_rect = match self.axis {
+            Axis::X => Rect::from_xywh(
+                handle_len / 2,
+                (size.y() - track_width) / 2,
+                size.x() - handle_len,
+                track_width,
+            ),
+            Axis::Y => Rect::from_xywh(
+                (size.x() - track_width) / 2,
+                handle_len / 2,
+                track_width,
+                size.y() - handle_len,
+            ),
+        };
+        event.fill_rect(track_rect, self.base.common_style.text_color);
+        self.draw_ticks(&event, size);
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let handle_hint = self.handle().size_hint_x();
+        Ok(match self.axis {
+            Axis::X => SizeHints {
+                min: handle_hint.min * MIN_LENGTH_MULTIPLIER,
+                preferred: handle_hint.preferred * PREFERRED_LENGTH_MULTIPLIER,
+                is_fixed: false,
+            },
+            Axis::Y => {
+                let ticks = self.tick_length_before() + self.tick_length_after();
+                SizeHints {
+                    min: handle_hint.min + ticks,
+                    preferred: handle_hint.preferred + ticks,
+                    is_fixed: true,
+                }
+            }
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        let handle_hint = self.handle().size_hint_y(size_x);
+        Ok(match self.axis {
+            Axis::X => {
+                let ticks = self.tick_length_before() + self.tick_length_after();
+                SizeHints {
+                    min: handle_hint.min + ticks,
+                    preferred: handle_hint.preferred + ticks,
+                    is_fixed: true,
+                }
+            }
+            Axis::Y => SizeHints {
+                min: handle_hint.min * MIN_LENGTH_MULTIPLIER,
+                preferred: handle_hint.preferred * PREFERRED_LENGTH_MULTIPLIER,
+                is_fixed: false,
+            },
+        })
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button == MouseButton::Left && event.state.is_pressed() {
+            self.focus()?;
+            let Some(handle_rect) = self.handle_rect() else {
+                return Ok(true);
+            };
+            let is_before_handle = match self.axis {
+                Axis::X => event.pos.x() < handle_rect.left(),
+                Axis::Y => event.pos.y() > handle_rect.bottom(),
+            };
+            let is_after_handle = match self.axis {
+                Axis::X => event.pos.x() > handle_rect.right(),
+                Axis::Y => event.pos.y() < handle_rect.top(),
+            };
+            if is_before_handle {
+                self.add_value(-self.page_step, false);
+            } else if is_after_handle {
+                self.add_value(self.page_step, false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_scroll(&mut self, event: MouseScrollEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        let delta = event.unified_delta(&self.base);
+        let max_delta = if NotNan::new(delta.x.abs())? > NotNan::new(delta.y.abs())? {
+            delta.x
+        } else {
+            delta.y
+        };
+        if max_delta > 0.0 {
+            self.add_value(self.value.step(), false);
+        } else if max_delta < 0.0 {
+            self.add_value(-self.value.step(), false);
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() {
+            return Ok(false);
+        }
+        let Key::Named(key) = event.info.logical_key else {
+            return Ok(false);
+        };
+        match key {
+            NamedKey::ArrowUp | NamedKey::ArrowRight => {
+                self.add_value(self.value.step(), false);
+            }
+            NamedKey::ArrowDown | NamedKey::ArrowLeft => {
+                self.add_value(-self.value.step(), false);
+            }
+            NamedKey::PageUp => {
+                self.add_value(self.page_step, false);
+            }
+            NamedKey::PageDown => {
+                self.add_value(-self.page_step, false);
+            }
+            NamedKey::Home => {
+                self.set_value_internal(self.value.min(), false);
+            }
+            NamedKey::End => {
+                self.set_value_internal(self.value.max(), false);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_focus_in(&mut self, _event: FocusInEvent) -> Result<()> {
+        self.handle().add_class("slider_focused".into());
+        Ok(())
+    }
+
+    fn handle_focus_out(&mut self, _event: FocusOutEvent) -> Result<()> {
+        self.handle().remove_class("slider_focused".into());
+        Ok(())
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Increment => self.add_value(self.value.step(), false),
+            Action::Decrement => self.add_value(-self.value.step(), false),
+            Action::SetValue => match event.data {
+                Some(ActionData::NumericValue(value)) => {
+                    let value = (value.round() as i32).clamp(self.value.min(), self.value.max());
+                    self.set_value_internal(value, false);
+                }
+                Some(ActionData::Value(value)) => {
+                    let value = value.trim().parse::<i32>()?;
+                    self.set_value_internal(value.clamp(self.value.min(), self.value.max()), false);
+                }
+                _ => {}
+            },
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Slider);
+        node.set_numeric_value(self.value.value().into());
+        node.set_min_numeric_value(self.value.min().into());
+        node.set_max_numeric_value(self.value.max().into());
+        node.set_numeric_value_step(self.value.step().into());
+        node.set_numeric_value_jump(self.page_step.into());
+        node.set_orientation(match self.axis {
+            Axis::X => Orientation::Horizontal,
+            Axis::Y => Orientation::Vertical,
+        });
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::SetValue);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+}
@@ -0,0 +1,443 @@
+use {
+    super::{
+        button::Button, column::Column, text_input::TextInput, Widget, WidgetBaseOf, WidgetExt,
+    },
+    crate::{
+        callback::{Callback, Callbacks},
+        event::{AccessibilityActionEvent, Event, MouseScrollEvent},
+        impl_widget_base,
+        style::css::PseudoClass,
+    },
+    accesskit::{Action, ActionData, Role},
+    anyhow::Result,
+    log::warn,
+    std::fmt::Display,
+    widgem_macros::impl_with,
+    winit::keyboard::{Key, NamedKey},
+};
+
+const INPUT_KEY: u64 = 0;
+const BUTTONS_KEY: u64 = 1;
+
+const UP_KEY: u64 = 0;
+const DOWN_KEY: u64 = 1;
+
+// Page Up and Page Down keys change the value by this number of steps.
+const STEPS_PER_PAGE: f64 = 10.0;
+
+/// A field for entering a number that can also be changed with up/down buttons,
+/// arrow keys and the mouse wheel.
+///
+/// With 0 decimals (the default), the spin box edits integer values.
+/// The text can only be edited into a number, optionally surrounded by
+/// the prefix and the suffix. The value is committed when Enter is pressed or
+/// when the field loses focus.
+pub struct SpinBox {
+    base: WidgetBaseOf<Self>,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    decimals: usize,
+    prefix: String,
+    suffix: String,
+    // Last text in the field that passed validation.
+    last_valid_text: String,
+    value_changed: Callbacks<f64>,
+}
+
+#[impl_with]
+impl SpinBox {
+    fn input(&self) -> &TextInput {
+        self.base.get_child::<TextInput>(INPUT_KEY).unwrap()
+    }
+
+    fn input_mut(&mut self) -> &mut TextInput {
+        self.base.get_child_mut::<TextInput>(INPUT_KEY).unwrap()
+    }
+
+    fn button_mut(&mut self, key: u64) -> &mut Button {
+        self.base
+            .get_dyn_child_mut(BUTTONS_KEY)
+            .unwrap()
+            .base_mut()
+            .get_child_mut::<Button>(key)
+            .unwrap()
+    }
+
+    pub fn set_value(&mut self, value: f64) -> &mut Self {
+        self.set_value_internal(value, true);
+        self
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the minimum and the maximum value. The current value is clamped to the new range.
+    pub fn set_range(&mut self, mut min: f64, mut max: f64) -> &mut Self {
+        if max < min {
+            warn!("invalid spin box range");
+            max = min;
+        }
+        if min.is_nan() || max.is_nan() {
+            warn!("invalid spin box range");
+            (min, max) = (0.0, 0.0);
+        }
+        self.min = min;
+        self.max = max;
+        self.set_value_internal(self.value, true);
+        self
+    }
+
+    pub fn set_minimum(&mut self, min: f64) -> &mut Self {
+        self.set_range(min, self.max.max(min))
+    }
+
+    pub fn minimum(&self) -> f64 {
+        self.min
+    }
+
+    pub fn set_maximum(&mut self, max: f64) -> &mut Self {
+        self.set_range(self.min.min(max), max)
+    }
+
+    pub fn maximum(&self) -> f64 {
+        self.max
+    }
+
+    /// Sets the amount by which the value is changed by the buttons, the arrow keys and the mouse wheel.
+    pub fn set_step(&mut self, step: f64) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Sets the number of digits displayed after the decimal point.
+    ///
+    /// The value is rounded to this precision.
+    pub fn set_decimals(&mut self, decimals: usize) -> &mut Self {
+        self.decimals = decimals;
+        self.set_value_internal(self.value, true);
+        self
+    }
+
+    pub fn decimals(&self) -> usize {
+        self.decimals
+    }
+
+    /// Sets the text displayed before the value, e.g. a currency sign.
+    pub fn set_prefix(&mut self, prefix: impl Display) -> &mut Self {
+        self.prefix = prefix.to_string();
+        self.update_text();
+        self
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Sets the text displayed after the value, e.g. a unit of measurement.
+    pub fn set_suffix(&mut self, suffix: impl Display) -> &mut Self {
+        self.suffix = suffix.to_string();
+        self.update_text();
+        self
+    }
+
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns the text displayed in the field, including the prefix and the suffix.
+    pub fn text(&self) -> String {
+        self.input().text()
+    }
+
+    /// The callback is invoked when a new value is committed.
+    ///
+    /// Editing the text doesn't invoke the callback until the edit is committed
+    /// by pressing Enter or moving focus away from the field.
+    pub fn on_value_changed(&mut self, callback: Callback<f64>) -> &mut Self {
+        self.value_changed.add(callback);
+        self
+    }
+
+    pub fn step_up(&mut self) {
+        self.step_by_internal(1.0, true);
+    }
+
+    pub fn step_down(&mut self) {
+        self.step_by_internal(-1.0, true);
+    }
+
+    fn step_by_internal(&mut self, steps: f64, from_setter: bool) {
+        self.commit();
+        self.set_value_internal(self.value + steps * self.step, from_setter);
+    }
+
+    fn set_value_internal(&mut self, value: f64, from_setter: bool) {
+        if value.is_nan() {
+            warn!("spin box value is NaN");
+            return;
+        }
+        let factor = 10f64.powi(self.decimals as i32);
+        let value = ((value * factor).round() / factor).clamp(self.min, self.max);
+        if value != self.value {
+            self.value = value;
+            self.value_changed.invoke(value, from_setter);
+            self.base.update();
+        }
+        self.update_text();
+    }
+
+    fn format_value(&self) -> String {
+        format!(
+            "{}{:.*}{}",
+            self.prefix, self.decimals, self.value, self.suffix
+        )
+    }
+
+    fn update_text(&mut self) {
+        let text = self.format_value();
+        self.last_valid_text = text.clone();
+        if self.input().text() != text {
+            self.input_mut().set_text(&text);
+        }
+        self.set_valid(true);
+        let value = self.value;
+        let (min, max) = (self.min, self.max);
+        self.button_mut(UP_KEY).set_enabled(value < max);
+        self.button_mut(DOWN_KEY).set_enabled(value > min);
+    }
+
+    /// Returns the number without the prefix and the suffix,
+    /// or `None` if the text can't be edited into a valid value.
+    fn number_text<'a>(&self, text: &'a str) -> Option<&'a str> {
+        let number = text
+            .strip_prefix(self.prefix.as_str())?
+            .strip_suffix(self.suffix.as_str())?
+            .trim();
+        let digits = number.strip_prefix('-').unwrap_or(number);
+        if digits.len() != number.len() && self.min >= 0.0 {
+            return None;
+        }
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => {
+                if self.decimals == 0 || fraction.len() > self.decimals {
+                    return None;
+                }
+                (integer, fraction)
+            }
+            None => (digits, ""),
+        };
+        if integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            Some(number)
+        } else {
+            None
+        }
+    }
+
+    // Sets `:valid` or `:invalid` pseudo-class on the spin box and its text input.
+    fn set_valid(&mut self, valid: bool) {
+        self.set_pseudo_class(PseudoClass::Valid, valid)
+            .set_pseudo_class(PseudoClass::Invalid, !valid);
+        self.input_mut()
+            .set_pseudo_class(PseudoClass::Valid, valid)
+            .set_pseudo_class(PseudoClass::Invalid, !valid);
+    }
+
+    fn text_edited(&mut self, text: String) {
+        if let Some(number) = self.number_text(&text) {
+            // The text can be incomplete or out of range while it's being edited.
+            let is_valid = number
+                .parse::<f64>()
+                .is_ok_and(|value| value >= self.min && value <= self.max);
+            self.last_valid_text = text;
+            self.set_valid(is_valid);
+        } else {
+            let valid_text = self.last_valid_text.clone();
+            let cursor = valid_text.len() - self.suffix.len();
+            let input = self.input_mut();
+            input.set_text(&valid_text);
+            input.set_selection(cursor..cursor);
+        }
+    }
+
+    /// Parses the text in the field and makes it the current value.
+    ///
+    /// If the text is incomplete (e.g. empty), the text of the current value is restored.
+    fn commit(&mut self) {
+        let text = self.input().text();
+        match self
+            .number_text(&text)
+            .and_then(|number| number.parse::<f64>().ok())
+        {
+            Some(value) => self.set_value_internal(value, false),
+            None => self.update_text(),
+        }
+    }
+
+    fn handle_input_action(&mut self, action: InputAction) -> Result<()> {
+        match action {
+            InputAction::Step(steps) => self.step_by_internal(steps, false),
+            InputAction::Commit => self.commit(),
+        }
+        Ok(())
+    }
+}
+
+/// Action triggered by an event intercepted from the text input.
+#[derive(Debug, Clone, Copy)]
+enum InputAction {
+    Step(f64),
+    Commit,
+}
+
+impl Widget for SpinBox {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<TextInput>(INPUT_KEY)
+            .set_column(0)
+            .set_row(0);
+        let buttons = base
+            .add_child_with_key::<Column>(BUTTONS_KEY)
+            .set_column(1)
+            .set_row(0)
+            .add_class("spin_box_buttons".into());
+        // TODO: localized name
+        buttons
+            .base_mut()
+            .add_child_with_key::<Button>(UP_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_text("step up")
+            .set_accessibility_node_enabled(false)
+            .set_focusable(false)
+            .add_class("spin_up".into())
+            .set_text_visible(false)
+            .set_auto_repeat(true)
+            .set_trigger_on_press(true);
+        buttons
+            .base_mut()
+            .add_child_with_key::<Button>(DOWN_KEY)
+            .set_column(0)
+            .set_row(1)
+            .set_text("step down")
+            .set_accessibility_node_enabled(false)
+            .set_focusable(false)
+            .add_class("spin_down".into())
+            .set_text_visible(false)
+            .set_auto_repeat(true)
+            .set_trigger_on_press(true);
+
+        let mut this = Self {
+            base,
+            value: 0.0,
+            min: 0.0,
+            max: 99.0,
+            step: 1.0,
+            decimals: 0,
+            prefix: String::new(),
+            suffix: String::new(),
+            last_valid_text: String::new(),
+            value_changed: Default::default(),
+        };
+
+        let text_edited = this.callback(|this, text| {
+            this.text_edited(text);
+            Ok(())
+        });
+        let input_action = this.callback(Self::handle_input_action);
+        let input = this.input_mut();
+        input.on_text_edited(text_edited);
+        input.base_mut().event_filter = Some(Box::new(move |event| match event {
+            Event::KeyboardInput(event) => {
+                let action = match event.info.logical_key {
+                    Key::Named(NamedKey::ArrowUp) => InputAction::Step(1.0),
+                    Key::Named(NamedKey::ArrowDown) => InputAction::Step(-1.0),
+                    Key::Named(NamedKey::PageUp) => InputAction::Step(STEPS_PER_PAGE),
+                    Key::Named(NamedKey::PageDown) => InputAction::Step(-STEPS_PER_PAGE),
+                    Key::Named(NamedKey::Enter) => InputAction::Commit,
+                    _ => return Ok(false),
+                };
+                if event.info.state.is_pressed() {
+                    input_action.invoke(action);
+                }
+                Ok(true)
+            }
+            Event::FocusOut(_) => {
+                input_action.invoke(InputAction::Commit);
+                Ok(false)
+            }
+            _ => Ok(false),
+        }));
+
+        let step_up = this.callback(|this, _| {
+            this.step_by_internal(1.0, false);
+            Ok(())
+        });
+        this.button_mut(UP_KEY).on_triggered(step_up);
+        let step_down = this.callback(|this, _| {
+            this.step_by_internal(-1.0, false);
+            Ok(())
+        });
+        this.button_mut(DOWN_KEY).on_triggered(step_down);
+
+        this.update_text();
+        this
+    }
+
+    fn handle_mouse_scroll(&mut self, event: MouseScrollEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        let delta = event.unified_delta(&self.base);
+        if delta.y > 0.0 {
+            self.step_by_internal(1.0, false);
+        } else if delta.y < 0.0 {
+            self.step_by_internal(-1.0, false);
+        }
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Increment => self.step_by_internal(1.0, false),
+            Action::Decrement => self.step_by_internal(-1.0, false),
+            Action::SetValue => match event.data {
+                Some(ActionData::NumericValue(value)) => self.set_value_internal(value, false),
+                Some(ActionData::Value(value)) => {
+                    let value = value.trim().parse::<f64>()?;
+                    self.set_value_internal(value, false);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::SpinButton);
+        node.set_numeric_value(self.value);
+        node.set_min_numeric_value(self.min);
+        node.set_max_numeric_value(self.max);
+        node.set_numeric_value_step(self.step);
+        node.set_value(self.format_value());
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::SetValue);
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        Ok(Some(node))
+    }
+}
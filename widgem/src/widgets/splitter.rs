@@ -0,0 +1,747 @@
+use {
+    super::{Widget, WidgetBaseOf, WidgetExt, WidgetGeometry},
+    crate::{
+        callback::{Callback, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, MouseMoveEvent,
+        },
+        impl_widget_base,
+        key::Key,
+        layout::{
+            grid::GridAxisOptions, solve_layout, Alignment, AxisPadding, LayoutItem, SizeHints,
+        },
+        shared_window::SetFocusRequest,
+        system::{send_window_request, ReportError},
+        types::{Axis, LpxSuffix, PhysicalPixels, Point, PpxSuffix, Rect},
+    },
+    accesskit::{Action, Orientation, Role},
+    anyhow::{Context, Result},
+    log::warn,
+    std::cmp::{max, min},
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key as KeyboardKey, NamedKey},
+        window::CursorIcon,
+    },
+};
+
+const PANES_KEY: u64 = 0;
+
+const HANDLE_WIDTH: f32 = 6.0;
+const KEYBOARD_STEP: f32 = 10.0;
+
+/// A container that arranges panes along an axis and allows the user to resize them
+/// by dragging the handles between them.
+///
+/// Panes are not shrunk below their minimal size. Collapsible panes can be hidden completely
+/// by dragging the handle over the half of their minimal size.
+pub struct Splitter {
+    base: WidgetBaseOf<Self>,
+    axis: Axis,
+    keys: Vec<Key>,
+    collapsible: Vec<bool>,
+    collapsed: Vec<bool>,
+    // Sizes set by the user or restored with `set_sizes`. If empty, sizes are calculated
+    // from the size hints of the panes.
+    requested_sizes: Vec<PhysicalPixels>,
+    // Sizes assigned by the last layout.
+    sizes: Vec<PhysicalPixels>,
+    drag_start_sizes: Option<Vec<PhysicalPixels>>,
+    sizes_changed: Callbacks<Vec<PhysicalPixels>>,
+}
+
+#[impl_with]
+impl Splitter {
+    fn panes(&self) -> &SplitterPanes {
+        self.base.get_child::<SplitterPanes>(PANES_KEY).unwrap()
+    }
+
+    fn panes_mut(&mut self) -> &mut SplitterPanes {
+        self.base.get_child_mut::<SplitterPanes>(PANES_KEY).unwrap()
+    }
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    pub fn set_axis(&mut self, axis: Axis) -> &mut Self {
+        if self.axis == axis {
+            return self;
+        }
+        self.axis = axis;
+        self.set_style_attribute("axis".into(), Some(axis.name().into()));
+        self.requested_sizes.clear();
+        for index in 0..self.keys.len().saturating_sub(1) {
+            self.handle_mut(index).set_axis(axis);
+        }
+        self.base.size_hint_changed();
+        self
+    }
+
+    /// Adds a pane after all existing panes and returns a reference to the pane widget.
+    pub fn add_pane<T: Widget>(&mut self, key: impl Into<Key>) -> &mut T {
+        let index = self.len();
+        self.insert_pane(index, key)
+    }
+
+    /// Inserts a pane at `index` and returns a reference to the pane widget.
+    ///
+    /// If a pane with the same key already exists, it's replaced.
+    /// Sizes previously set by the user or by [set_sizes](Self::set_sizes) are discarded.
+    pub fn insert_pane<T: Widget>(&mut self, index: usize, key: impl Into<Key>) -> &mut T {
+        let key = key.into();
+        if self.index_of(key.clone()).is_some() {
+            warn!("replacing existing splitter pane: {key:?}");
+            self.remove_pane(key.clone()).or_report_err();
+        }
+        let index = index.min(self.len());
+        self.keys.insert(index, key.clone());
+        self.collapsible.insert(index, false);
+        self.collapsed.insert(index, false);
+        self.requested_sizes.clear();
+        self.update_handles();
+        self.base.size_hint_changed();
+        self.panes_mut().base.add_child_with_key::<T>(key)
+    }
+
+    /// Removes the pane with the specified key.
+    pub fn remove_pane(&mut self, key: impl Into<Key>) -> Result<()> {
+        let key = key.into();
+        let index = self
+            .index_of(key.clone())
+            .with_context(|| format!("no splitter pane with key {key:?}"))?;
+        self.panes_mut().base.remove_child(key)?;
+        self.keys.remove(index);
+        self.collapsible.remove(index);
+        self.collapsed.remove(index);
+        self.requested_sizes.clear();
+        self.update_handles();
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    /// Returns a reference to the pane widget with the specified key.
+    pub fn pane<T: Widget>(&self, key: impl Into<Key>) -> Result<&T> {
+        self.panes().base.get_child::<T>(key)
+    }
+
+    /// Returns a mutable reference to the pane widget with the specified key.
+    pub fn pane_mut<T: Widget>(&mut self, key: impl Into<Key>) -> Result<&mut T> {
+        self.panes_mut().base.get_child_mut::<T>(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the keys of all panes in the order of their display.
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    pub fn index_of(&self, key: impl Into<Key>) -> Option<usize> {
+        let key = key.into();
+        self.keys.iter().position(|k| *k == key)
+    }
+
+    /// Allows the user to collapse the pane at `index` by dragging a handle.
+    pub fn set_collapsible(&mut self, index: usize, value: bool) -> &mut Self {
+        let Some(collapsible) = self.collapsible.get_mut(index) else {
+            warn!("splitter pane index out of bounds: {index}");
+            return self;
+        };
+        *collapsible = value;
+        if !value {
+            self.set_collapsed(index, false);
+        }
+        self
+    }
+
+    pub fn is_collapsible(&self, index: usize) -> bool {
+        self.collapsible.get(index).copied().unwrap_or(false)
+    }
+
+    /// Hides or shows the pane at `index`. The pane must be collapsible.
+    pub fn set_collapsed(&mut self, index: usize, value: bool) -> &mut Self {
+        if value && !self.is_collapsible(index) {
+            warn!("cannot collapse a splitter pane that is not collapsible");
+            return self;
+        }
+        let Some(collapsed) = self.collapsed.get_mut(index) else {
+            warn!("splitter pane index out of bounds: {index}");
+            return self;
+        };
+        if *collapsed == value {
+            return self;
+        }
+        *collapsed = value;
+        self.base.size_hint_changed();
+        self
+    }
+
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.collapsed.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns current sizes of the panes along the axis of the splitter.
+    ///
+    /// Collapsed panes have zero size. The returned value can be saved and restored later
+    /// with [set_sizes](Self::set_sizes).
+    pub fn sizes(&self) -> &[PhysicalPixels] {
+        &self.sizes
+    }
+
+    /// Sets sizes of the panes along the axis of the splitter.
+    ///
+    /// If the total size doesn't match the available space, sizes are scaled proportionally.
+    /// Collapsible panes with zero size are collapsed.
+    pub fn set_sizes(&mut self, sizes: Vec<PhysicalPixels>) -> &mut Self {
+        self.set_sizes_internal(sizes, true);
+        self
+    }
+
+    fn set_sizes_internal(&mut self, sizes: Vec<PhysicalPixels>, from_setter: bool) {
+        if sizes.len() != self.len() {
+            warn!(
+                "expected {} splitter pane sizes, got {}",
+                self.len(),
+                sizes.len()
+            );
+            return;
+        }
+        for (index, size) in sizes.iter().enumerate() {
+            self.collapsed[index] = self.collapsible[index] && *size <= 0.ppx();
+        }
+        self.requested_sizes = sizes.clone();
+        self.sizes_changed.invoke(sizes, from_setter);
+        self.base.size_hint_changed();
+    }
+
+    /// Called with new sizes of the panes when the user moves a handle.
+    pub fn on_sizes_changed(&mut self, callback: Callback<Vec<PhysicalPixels>>) -> &mut Self {
+        self.sizes_changed.add(callback);
+        self
+    }
+
+    fn handle_mut(&mut self, index: usize) -> &mut SplitterHandle {
+        self.base
+            .get_child_mut::<SplitterHandle>(handle_key(index))
+            .unwrap()
+    }
+
+    fn update_handles(&mut self) {
+        let count = self.keys.len().saturating_sub(1);
+        let existing = (0..)
+            .take_while(|index| self.base.has_child(handle_key(*index)))
+            .count();
+        for index in count..existing {
+            self.base.remove_child(handle_key(index)).or_report_err();
+        }
+        for index in existing..count {
+            let on_action = self.callback(|this, action| {
+                this.handle_action(action);
+                Ok(())
+            });
+            let axis = self.axis;
+            let handle = self
+                .base
+                .add_child_with_key::<SplitterHandle>(handle_key(index));
+            handle.index = index;
+            handle.on_action = Some(on_action);
+            handle.set_axis(axis);
+        }
+    }
+
+    fn handle_action(&mut self, action: HandleAction) {
+        match action {
+            HandleAction::DragStarted => {
+                self.drag_start_sizes = Some(self.sizes.clone());
+            }
+            HandleAction::Dragged { index, delta } => {
+                if let Some(start_sizes) = self.drag_start_sizes.clone() {
+                    self.move_handle(index, delta, start_sizes);
+                }
+            }
+            HandleAction::Moved { index, delta } => {
+                self.move_handle(index, delta, self.sizes.clone());
+            }
+        }
+    }
+
+    // Moves the handle between the panes `index` and `index + 1` by `delta` pixels
+    // relative to the specified sizes.
+    fn move_handle(&mut self, index: usize, delta: PhysicalPixels, mut sizes: Vec<PhysicalPixels>) {
+        if index + 1 >= sizes.len() || sizes.len() != self.len() {
+            return;
+        }
+        let min_sizes = self.min_sizes(self.cross_size());
+        let total = sizes[index] + sizes[index + 1];
+        let fit = |size: PhysicalPixels, min_size: PhysicalPixels, collapsible: bool| {
+            if collapsible && size * 2 < min_size {
+                0.ppx()
+            } else {
+                max(size, min(min_size, total))
+            }
+        };
+        let mut first = fit(
+            sizes[index] + delta,
+            min_sizes[index],
+            self.collapsible[index],
+        );
+        first = min(first, total);
+        let second = fit(
+            total - first,
+            min_sizes[index + 1],
+            self.collapsible[index + 1],
+        );
+        first = total - min(second, total);
+        sizes[index] = first;
+        sizes[index + 1] = total - first;
+        if sizes != self.sizes || self.requested_sizes.is_empty() {
+            self.set_sizes_internal(sizes, false);
+        }
+    }
+
+    fn cross_size(&self) -> PhysicalPixels {
+        let size = self.base.size().unwrap_or_default();
+        match self.axis {
+            Axis::X => size.y(),
+            Axis::Y => size.x(),
+        }
+    }
+
+    fn handle_width(&self) -> PhysicalPixels {
+        HANDLE_WIDTH.lpx().to_physical(self.base.scale())
+    }
+
+    fn pane_hints(&mut self, cross_size: PhysicalPixels) -> Vec<SizeHints> {
+        let axis = self.axis;
+        let keys = self.keys.clone();
+        let panes = self.panes_mut();
+        keys.iter()
+            .map(|key| {
+                let pane = panes.base.get_dyn_child_mut(key.clone()).unwrap();
+                match axis {
+                    Axis::X => pane.size_hint_x(),
+                    Axis::Y => pane.size_hint_y(cross_size),
+                }
+            })
+            .collect()
+    }
+
+    fn min_sizes(&mut self, cross_size: PhysicalPixels) -> Vec<PhysicalPixels> {
+        self.pane_hints(cross_size)
+            .iter()
+            .map(|hints| hints.min)
+            .collect()
+    }
+
+    // Calculates sizes of the panes for the specified size of the splitter.
+    fn calculate_sizes(
+        &mut self,
+        total: PhysicalPixels,
+        cross_size: PhysicalPixels,
+    ) -> Vec<PhysicalPixels> {
+        let hints = self.pane_hints(cross_size);
+        let available = max(
+            0.ppx(),
+            total - self.handle_width() * self.len().saturating_sub(1) as i32,
+        );
+        if self.requested_sizes.len() == self.len() {
+            let requested = self
+                .requested_sizes
+                .iter()
+                .zip(&self.collapsed)
+                .map(|(size, collapsed)| if *collapsed { 0.ppx() } else { *size })
+                .collect::<Vec<_>>();
+            let min_sizes = hints
+                .iter()
+                .zip(&self.collapsed)
+                .map(|(hints, collapsed)| if *collapsed { 0.ppx() } else { hints.min })
+                .collect::<Vec<_>>();
+            fit_sizes(&requested, &min_sizes, available)
+        } else {
+            let items = hints
+                .into_iter()
+                .zip(&self.collapsed)
+                .map(|(size_hints, collapsed)| LayoutItem {
+                    size_hints: if *collapsed {
+                        SizeHints {
+                            min: 0.ppx(),
+                            preferred: 0.ppx(),
+                            is_fixed: true,
+                        }
+                    } else {
+                        size_hints
+                    },
+                })
+                .collect::<Vec<_>>();
+            let options = GridAxisOptions {
+                min_padding: AxisPadding::ZERO,
+                min_spacing: 0.ppx(),
+                preferred_padding: AxisPadding::ZERO,
+                preferred_spacing: 0.ppx(),
+                border_collapse: 0.ppx(),
+                alignment: Alignment::Start,
+            };
+            solve_layout(&items, available, &options).sizes
+        }
+    }
+
+    fn along_axis_size_hint(&mut self, cross_size: PhysicalPixels) -> SizeHints {
+        let handles = self.handle_width() * self.len().saturating_sub(1) as i32;
+        let hints = self.pane_hints(cross_size);
+        let visible_hints = || {
+            hints
+                .iter()
+                .zip(&self.collapsed)
+                .filter(|(_, collapsed)| !**collapsed)
+                .map(|(hints, _)| hints)
+        };
+        SizeHints {
+            min: visible_hints()
+                .map(|hints| hints.min)
+                .sum::<PhysicalPixels>()
+                + handles,
+            preferred: visible_hints()
+                .map(|hints| hints.preferred)
+                .sum::<PhysicalPixels>()
+                + handles,
+            is_fixed: false,
+        }
+    }
+}
+
+fn handle_key(index: usize) -> u64 {
+    index as u64 + 1
+}
+
+// Scales sizes proportionally to fit into `available` while keeping them above the minimal sizes.
+fn fit_sizes(
+    requested: &[PhysicalPixels],
+    min_sizes: &[PhysicalPixels],
+    available: PhysicalPixels,
+) -> Vec<PhysicalPixels> {
+    let total_requested = requested.iter().copied().sum::<PhysicalPixels>().to_i32();
+    let mut sizes = if total_requested > 0 {
+        let factor = available.to_i32() as f32 / total_requested as f32;
+        let mut prev = 0;
+        let mut requested_sum = 0;
+        requested
+            .iter()
+            .map(|size| {
+                requested_sum += size.to_i32();
+                let next = (requested_sum as f32 * factor).round() as i32;
+                let size = PhysicalPixels::from_i32(next - prev);
+                prev = next;
+                size
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![0.ppx(); requested.len()]
+    };
+    // Take the space required by panes below their minimal size from the last panes.
+    let mut deficit = 0.ppx();
+    for (size, min_size) in sizes.iter_mut().zip(min_sizes) {
+        if *size < *min_size {
+            deficit += *min_size - *size;
+            *size = *min_size;
+        }
+    }
+    for (size, min_size) in sizes.iter_mut().zip(min_sizes).rev() {
+        if deficit <= 0.ppx() {
+            break;
+        }
+        let taken = min(deficit, max(0.ppx(), *size - *min_size));
+        *size -= taken;
+        deficit -= taken;
+    }
+    sizes
+}
+
+impl Widget for Splitter {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<SplitterPanes>(PANES_KEY);
+        let mut this = Self {
+            base,
+            axis: Axis::X,
+            keys: Vec::new(),
+            collapsible: Vec::new(),
+            collapsed: Vec::new(),
+            requested_sizes: Vec::new(),
+            sizes: Vec::new(),
+            drag_start_sizes: None,
+            sizes_changed: Callbacks::default(),
+        };
+        this.set_style_attribute("axis".into(), Some(this.axis.name().into()));
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let changed = &event.changed_size_hints;
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed);
+            }
+            let keys = self.keys.clone();
+            for key in keys {
+                self.panes_mut()
+                    .base
+                    .get_dyn_child_mut(key)?
+                    .set_geometry(None, changed);
+            }
+            return Ok(());
+        };
+        let size = geometry.size();
+        let (total, cross_size) = match self.axis {
+            Axis::X => (size.x(), size.y()),
+            Axis::Y => (size.y(), size.x()),
+        };
+        let sizes = self.calculate_sizes(total, cross_size);
+        let axis = self.axis;
+        let rect = |pos: PhysicalPixels, len: PhysicalPixels| match axis {
+            Axis::X => Rect::from_xywh(pos, 0.ppx(), len, cross_size),
+            Axis::Y => Rect::from_xywh(0.ppx(), pos, cross_size, len),
+        };
+
+        let panes_geometry =
+            WidgetGeometry::new(&geometry, Rect::from_pos_size(Point::default(), size));
+        self.panes_mut()
+            .set_geometry(Some(panes_geometry.clone()), changed);
+        let handle_width = self.handle_width();
+        let mut pos = 0.ppx();
+        for (index, key) in self.keys.clone().into_iter().enumerate() {
+            let pane_rect = rect(pos, sizes[index]);
+            let is_collapsed = self.collapsed[index];
+            let pane = self.panes_mut().base.get_dyn_child_mut(key)?;
+            if is_collapsed {
+                pane.set_geometry(None, changed);
+            } else {
+                pane.set_geometry(
+                    Some(WidgetGeometry::new(&panes_geometry, pane_rect)),
+                    changed,
+                );
+            }
+            pos += sizes[index];
+            if index + 1 < self.keys.len() {
+                let handle_rect = rect(pos, handle_width);
+                let handle = self.handle_mut(index);
+                handle.position = pos;
+                handle.total = total;
+                handle.set_geometry(Some(WidgetGeometry::new(&geometry, handle_rect)), changed);
+                pos += handle_width;
+            }
+        }
+        self.sizes = sizes;
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        match self.axis {
+            Axis::X => Ok(self.along_axis_size_hint(0.ppx())),
+            Axis::Y => {
+                let hints = self.pane_hints(0.ppx());
+                Ok(SizeHints {
+                    min: hints.iter().map(|h| h.min).max().unwrap_or_default(),
+                    preferred: hints.iter().map(|h| h.preferred).max().unwrap_or_default(),
+                    is_fixed: false,
+                })
+            }
+        }
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        match self.axis {
+            Axis::X => {
+                let sizes = self.calculate_sizes(size_x, 0.ppx());
+                let keys = self.keys.clone();
+                let collapsed = self.collapsed.clone();
+                let panes = self.panes_mut();
+                let mut result = SizeHints {
+                    min: 0.ppx(),
+                    preferred: 0.ppx(),
+                    is_fixed: false,
+                };
+                for ((key, size_x), collapsed) in keys.into_iter().zip(sizes).zip(collapsed) {
+                    if collapsed {
+                        continue;
+                    }
+                    let hints = panes.base.get_dyn_child_mut(key)?.size_hint_y(size_x);
+                    result.min = max(result.min, hints.min);
+                    result.preferred = max(result.preferred, hints.preferred);
+                }
+                Ok(result)
+            }
+            Axis::Y => Ok(self.along_axis_size_hint(size_x)),
+        }
+    }
+}
+
+/// Container of the panes of a [Splitter].
+struct SplitterPanes {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for SplitterPanes {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_layout(&mut self, _event: LayoutEvent) -> Result<()> {
+        // Panes are positioned by the splitter.
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HandleAction {
+    DragStarted,
+    Dragged { index: usize, delta: PhysicalPixels },
+    Moved { index: usize, delta: PhysicalPixels },
+}
+
+/// Draggable divider between two panes of a [Splitter].
+struct SplitterHandle {
+    base: WidgetBaseOf<Self>,
+    index: usize,
+    axis: Axis,
+    // Position of the handle and the total size of the splitter, used for accessibility.
+    position: PhysicalPixels,
+    total: PhysicalPixels,
+    on_action: Option<Callback<HandleAction>>,
+    press_pos_in_window: Option<Point>,
+}
+
+impl SplitterHandle {
+    fn set_axis(&mut self, axis: Axis) {
+        self.axis = axis;
+        self.base.set_cursor_icon(match axis {
+            Axis::X => CursorIcon::ColResize,
+            Axis::Y => CursorIcon::RowResize,
+        });
+    }
+
+    fn invoke(&self, action: HandleAction) {
+        if let Some(on_action) = &self.on_action {
+            on_action.invoke(action);
+        }
+    }
+
+    fn move_by_keyboard(&self, delta: PhysicalPixels) {
+        self.invoke(HandleAction::Moved {
+            index: self.index,
+            delta,
+        });
+    }
+}
+
+impl Widget for SplitterHandle {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        Self {
+            base,
+            index: 0,
+            axis: Axis::X,
+            position: 0.ppx(),
+            total: 0.ppx(),
+            on_action: None,
+            press_pos_in_window: None,
+        }
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if event.button != MouseButton::Left || !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.state.is_pressed() {
+            self.press_pos_in_window = Some(event.pos_in_window);
+            self.invoke(HandleAction::DragStarted);
+        } else {
+            self.press_pos_in_window = None;
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if let Some(press_pos) = self.press_pos_in_window {
+            let delta = match self.axis {
+                Axis::X => event.pos_in_window.x() - press_pos.x(),
+                Axis::Y => event.pos_in_window.y() - press_pos.y(),
+            };
+            self.invoke(HandleAction::Dragged {
+                index: self.index,
+                delta,
+            });
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() || !self.base.is_enabled() {
+            return Ok(false);
+        }
+        let step = KEYBOARD_STEP.lpx().to_physical(self.base.scale());
+        let delta = match (&event.info.logical_key, self.axis) {
+            (KeyboardKey::Named(NamedKey::ArrowLeft), Axis::X)
+            | (KeyboardKey::Named(NamedKey::ArrowUp), Axis::Y) => -step,
+            (KeyboardKey::Named(NamedKey::ArrowRight), Axis::X)
+            | (KeyboardKey::Named(NamedKey::ArrowDown), Axis::Y) => step,
+            (KeyboardKey::Named(NamedKey::Home), _) => -self.total,
+            (KeyboardKey::Named(NamedKey::End), _) => self.total,
+            _ => return Ok(false),
+        };
+        self.move_by_keyboard(delta);
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        let step = KEYBOARD_STEP.lpx().to_physical(self.base.scale());
+        match event.action {
+            Action::Increment => self.move_by_keyboard(step),
+            Action::Decrement => self.move_by_keyboard(-step),
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Splitter);
+        node.set_numeric_value(self.position.to_i32().into());
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(self.total.to_i32().into());
+        // Panes are arranged along the axis, so the divider has the opposite orientation.
+        node.set_orientation(match self.axis {
+            Axis::X => Orientation::Vertical,
+            Axis::Y => Orientation::Horizontal,
+        });
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+}
@@ -0,0 +1,117 @@
+use {
+    super::{label::Label, Widget, WidgetBaseOf, WidgetExt},
+    crate::{impl_widget_base, rich_text::RichText, system::add_timer, timer::TimerId},
+    accesskit::{Live, Role},
+    anyhow::Result,
+    std::{fmt::Display, time::Duration},
+    widgem_macros::impl_with,
+};
+
+const MESSAGE_KEY: u64 = 0;
+
+/// A horizontal bar that displays status information, usually at the bottom of a main window.
+///
+/// A temporary message is displayed on the left side of the bar. Permanent widgets
+/// (e.g. a progress bar or an indicator) are displayed on the right side.
+pub struct StatusBar {
+    base: WidgetBaseOf<Self>,
+    num_permanent_widgets: usize,
+    message: Option<String>,
+    message_timer: Option<TimerId>,
+}
+
+#[impl_with]
+impl StatusBar {
+    fn message_widget_mut(&mut self) -> &mut Label {
+        self.base.get_child_mut::<Label>(MESSAGE_KEY).unwrap()
+    }
+
+    /// Displays a message that replaces the previous message.
+    ///
+    /// If `timeout` is specified, the message is cleared after this time.
+    pub fn show_message(&mut self, message: impl Display, timeout: Option<Duration>) -> &mut Self {
+        if let Some(timer) = self.message_timer.take() {
+            timer.cancel();
+        }
+        let message = message.to_string();
+        self.message_widget_mut()
+            .set_rich_text(RichText::from(message.as_str()));
+        self.message = Some(message);
+        if let Some(timeout) = timeout {
+            let callback = self.callback(|this, _| {
+                this.message_timer = None;
+                this.clear_message();
+                Ok(())
+            });
+            self.message_timer = Some(add_timer(timeout, callback));
+        }
+        self.base.update();
+        self
+    }
+
+    pub fn clear_message(&mut self) -> &mut Self {
+        if let Some(timer) = self.message_timer.take() {
+            timer.cancel();
+        }
+        self.message_widget_mut().set_rich_text(RichText::new());
+        self.message = None;
+        self.base.update();
+        self
+    }
+
+    /// Returns the currently displayed message.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Adds a widget to the right side of the status bar, after the previously added widgets.
+    pub fn add_permanent_widget<T: Widget>(&mut self) -> &mut T {
+        self.num_permanent_widgets += 1;
+        let index = self.num_permanent_widgets;
+        self.base
+            .add_child_with_key::<T>(index)
+            .set_column(index as i32)
+            .set_row(0)
+    }
+
+    /// Returns the permanent widget at `index`.
+    pub fn permanent_widget<T: Widget>(&self, index: usize) -> Result<&T> {
+        self.base.get_child::<T>(index + 1)
+    }
+
+    pub fn permanent_widget_mut<T: Widget>(&mut self, index: usize) -> Result<&mut T> {
+        self.base.get_child_mut::<T>(index + 1)
+    }
+}
+
+impl Widget for StatusBar {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<Label>(MESSAGE_KEY)
+            .add_class("status_message".into())
+            .set_column(0)
+            .set_row(0)
+            .set_size_x_fixed(false);
+        Self {
+            base,
+            num_permanent_widgets: 0,
+            message: None,
+            message_timer: None,
+        }
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Status);
+        node.set_live(Live::Polite);
+        Ok(Some(node))
+    }
+}
+
+impl Drop for StatusBar {
+    fn drop(&mut self) {
+        if let Some(timer) = self.message_timer.take() {
+            timer.cancel();
+        }
+    }
+}
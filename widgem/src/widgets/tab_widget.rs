@@ -0,0 +1,888 @@
+use {
+    super::{button::Button, Widget, WidgetBaseOf, WidgetExt, WidgetGeometry},
+    crate::{
+        callback::{Callback, CallbackVec, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        key::Key,
+        layout::SizeHints,
+        shared_window::SetFocusRequest,
+        shortcut::{standard_shortcuts, Shortcut, ShortcutScope},
+        style::css::PseudoClass,
+        system::{send_window_request, ReportError},
+        text_editor::Text,
+        types::{PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::{Action, Role},
+    anyhow::{Context, Result},
+    cosmic_text::Attrs,
+    log::warn,
+    std::{cmp::max, fmt::Display},
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key as KeyboardKey, NamedKey},
+    },
+};
+
+const BAR_KEY: u64 = 0;
+const PANEL_KEY: u64 = 1;
+
+/// A set of pages with a tab bar that allows to switch between them.
+///
+/// Each page is a child widget identified by a key. Only the current page is visible.
+pub struct TabWidget {
+    base: WidgetBaseOf<Self>,
+    current_index: Option<usize>,
+    are_tabs_closable: bool,
+    current_changed: Callbacks<Option<usize>>,
+    on_page_closed: CallbackVec<Key>,
+}
+
+#[impl_with]
+impl TabWidget {
+    fn bar(&self) -> &TabBar {
+        self.base.get_child::<TabBar>(BAR_KEY).unwrap()
+    }
+
+    fn bar_mut(&mut self) -> &mut TabBar {
+        self.base.get_child_mut::<TabBar>(BAR_KEY).unwrap()
+    }
+
+    fn panel(&self) -> &TabPanel {
+        self.base.get_child::<TabPanel>(PANEL_KEY).unwrap()
+    }
+
+    fn panel_mut(&mut self) -> &mut TabPanel {
+        self.base.get_child_mut::<TabPanel>(PANEL_KEY).unwrap()
+    }
+
+    /// Adds a page after all existing pages and returns a reference to the page widget.
+    pub fn add_page<T: Widget>(&mut self, key: impl Into<Key>, title: impl Display) -> &mut T {
+        let index = self.len();
+        self.insert_page(index, key, title)
+    }
+
+    /// Inserts a page at `index` and returns a reference to the page widget.
+    ///
+    /// If a page with the same key already exists, it's replaced.
+    pub fn insert_page<T: Widget>(
+        &mut self,
+        index: usize,
+        key: impl Into<Key>,
+        title: impl Display,
+    ) -> &mut T {
+        let key = key.into();
+        if self.index_of(key.clone()).is_some() {
+            warn!("replacing existing tab page: {key:?}");
+            self.remove_page(key.clone()).or_report_err();
+        }
+        let index = index.min(self.len());
+
+        let pressed = self.callback({
+            let key = key.clone();
+            move |this, ()| {
+                if let Some(index) = this.index_of(key.clone()) {
+                    this.set_current_index_internal(Some(index), false);
+                }
+                Ok(())
+            }
+        });
+        let close_requested = self.callback({
+            let key = key.clone();
+            move |this, ()| this.close_page(key.clone())
+        });
+        let navigated = self.callback(|this, navigation| {
+            this.navigate(navigation);
+            Ok(())
+        });
+        let is_closable = self.are_tabs_closable;
+        self.bar_mut()
+            .insert_tab(index, key.clone(), title)
+            .set_closable(is_closable)
+            .on_pressed(pressed)
+            .on_close_requested(close_requested)
+            .on_navigation(navigated);
+
+        if let Some(current) = self.current_index {
+            if index <= current {
+                self.current_index = Some(current + 1);
+            }
+        }
+        self.panel_mut()
+            .base
+            .add_child_with_key::<T>(key.clone())
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        if self.current_index.is_none() {
+            self.set_current_index_internal(Some(index), true);
+        }
+        self.panel_mut().base.get_child_mut::<T>(key).unwrap()
+    }
+
+    /// Removes the page with the specified key.
+    pub fn remove_page(&mut self, key: impl Into<Key>) -> Result<()> {
+        self.remove_page_internal(key.into(), true)
+    }
+
+    fn remove_page_internal(&mut self, key: Key, from_setter: bool) -> Result<()> {
+        let index = self
+            .index_of(key.clone())
+            .with_context(|| format!("no tab page with key {key:?}"))?;
+        self.bar_mut().remove_tab(index);
+        self.panel_mut().base.remove_child(key)?;
+        match self.current_index {
+            Some(current) if current == index => {
+                self.current_index = None;
+                let new_index = if self.is_empty() {
+                    None
+                } else {
+                    Some(index.min(self.len() - 1))
+                };
+                self.set_current_index_internal(new_index, from_setter);
+                if new_index.is_none() {
+                    self.current_changed.invoke(None, from_setter);
+                }
+            }
+            Some(current) if current > index => {
+                self.current_index = Some(current - 1);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn close_page(&mut self, key: Key) -> Result<()> {
+        self.remove_page_internal(key.clone(), false)?;
+        self.on_page_closed.invoke(key);
+        Ok(())
+    }
+
+    /// Returns a reference to the page widget with the specified key.
+    pub fn page<T: Widget>(&self, key: impl Into<Key>) -> Result<&T> {
+        self.panel().base.get_child::<T>(key)
+    }
+
+    /// Returns a mutable reference to the page widget with the specified key.
+    pub fn page_mut<T: Widget>(&mut self, key: impl Into<Key>) -> Result<&mut T> {
+        self.panel_mut().base.get_child_mut::<T>(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bar().keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the keys of all pages in the order of their tabs.
+    pub fn keys(&self) -> &[Key] {
+        &self.bar().keys
+    }
+
+    pub fn index_of(&self, key: impl Into<Key>) -> Option<usize> {
+        let key = key.into();
+        self.bar().keys.iter().position(|k| *k == key)
+    }
+
+    pub fn set_tab_title(&mut self, key: impl Into<Key>, title: impl Display) -> Result<()> {
+        self.bar_mut().tab_mut(key)?.set_title(title);
+        Ok(())
+    }
+
+    pub fn tab_title(&self, key: impl Into<Key>) -> Result<String> {
+        Ok(self.bar().tab(key)?.title())
+    }
+
+    /// Shows a close button in every tab.
+    ///
+    /// Clicking the button removes the page and invokes the callbacks registered
+    /// with [on_page_closed](Self::on_page_closed).
+    pub fn set_tabs_closable(&mut self, value: bool) -> &mut Self {
+        self.are_tabs_closable = value;
+        let keys = self.bar().keys.clone();
+        for key in keys {
+            self.bar_mut().tab_mut(key).unwrap().set_closable(value);
+        }
+        self.bar_mut().base.size_hint_changed();
+        self
+    }
+
+    pub fn are_tabs_closable(&self) -> bool {
+        self.are_tabs_closable
+    }
+
+    pub fn set_current_index(&mut self, index: Option<usize>) -> &mut Self {
+        self.set_current_index_internal(index, true);
+        self
+    }
+
+    pub fn current_index(&self) -> Option<usize> {
+        self.current_index
+    }
+
+    pub fn current_key(&self) -> Option<&Key> {
+        self.current_index.map(|index| &self.bar().keys[index])
+    }
+
+    /// The callback is invoked when another page becomes current.
+    pub fn on_current_changed(&mut self, callback: Callback<Option<usize>>) -> &mut Self {
+        self.current_changed.add(callback);
+        self
+    }
+
+    /// The callback is invoked when a page is closed by the user with the tab's close button.
+    pub fn on_page_closed(&mut self, callback: Callback<Key>) -> &mut Self {
+        self.on_page_closed.push(callback);
+        self
+    }
+
+    /// Makes the next page current, wrapping around after the last page.
+    pub fn select_next(&mut self) {
+        self.select_relative(1, true);
+    }
+
+    /// Makes the previous page current, wrapping around before the first page.
+    pub fn select_previous(&mut self) {
+        self.select_relative(-1, true);
+    }
+
+    fn select_relative(&mut self, delta: isize, from_setter: bool) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        let current = self.current_index.unwrap_or(0) as isize;
+        let index = (current + delta).rem_euclid(len as isize) as usize;
+        self.set_current_index_internal(Some(index), from_setter);
+    }
+
+    fn navigate(&mut self, navigation: TabNavigation) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        match navigation {
+            TabNavigation::Previous => self.select_relative(-1, false),
+            TabNavigation::Next => self.select_relative(1, false),
+            TabNavigation::First => self.set_current_index_internal(Some(0), false),
+            TabNavigation::Last => self.set_current_index_internal(Some(len - 1), false),
+        }
+        self.focus_current_tab().or_report_err();
+    }
+
+    fn focus_current_tab(&mut self) -> Result<()> {
+        let Some(index) = self.current_index else {
+            return Ok(());
+        };
+        let key = self.bar().keys[index].clone();
+        let tab_id = self.bar().tab(key)?.base.id();
+        send_window_request(
+            self.base.window_or_err()?.id(),
+            SetFocusRequest {
+                widget_id: tab_id.into(),
+                reason: FocusReason::Tab,
+            },
+        );
+        Ok(())
+    }
+
+    fn set_current_index_internal(&mut self, index: Option<usize>, from_setter: bool) {
+        if let Some(index) = index {
+            if index >= self.len() {
+                warn!("tab index out of bounds");
+                return;
+            }
+        }
+        if self.current_index == index {
+            return;
+        }
+        if let Some(old_index) = self.current_index {
+            let key = self.bar().keys[old_index].clone();
+            self.bar_mut()
+                .tab_mut(key.clone())
+                .unwrap()
+                .set_current(false);
+            if let Ok(page) = self.panel_mut().base.get_dyn_child_mut(key) {
+                page.set_visible(false);
+            }
+        }
+        self.current_index = index;
+        if let Some(index) = index {
+            let key = self.bar().keys[index].clone();
+            self.bar_mut()
+                .tab_mut(key.clone())
+                .unwrap()
+                .set_current(true);
+            self.bar_mut().ensure_visible(index);
+            if let Ok(page) = self.panel_mut().base.get_dyn_child_mut(key) {
+                page.set_visible(true);
+            }
+        }
+        self.current_changed.invoke(index, from_setter);
+        self.base.update();
+    }
+
+    fn shortcut_triggered(&mut self, delta: isize) -> Result<()> {
+        // Shortcuts are registered for the whole window, so only the tab widget
+        // that contains the focused widget should react to them.
+        let has_focus = self
+            .base
+            .window_or_err()
+            .is_ok_and(|window| window.is_focus_within(self.base.id().raw()));
+        if has_focus && self.base.is_visible() && self.base.is_enabled() {
+            self.select_relative(delta, false);
+        }
+        Ok(())
+    }
+}
+
+impl Widget for TabWidget {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<TabBar>(BAR_KEY)
+            .set_column(0)
+            .set_row(0);
+        base.add_child_with_key::<TabPanel>(PANEL_KEY)
+            .set_column(0)
+            .set_row(1);
+        let shortcuts = standard_shortcuts();
+        let id = base.id();
+        base.add_shortcut(Shortcut::new(
+            shortcuts.next_child.clone(),
+            ShortcutScope::Window,
+            id.callback(|this, ()| this.shortcut_triggered(1)),
+        ));
+        base.add_shortcut(Shortcut::new(
+            shortcuts.previous_child.clone(),
+            ShortcutScope::Window,
+            id.callback(|this, ()| this.shortcut_triggered(-1)),
+        ));
+        Self {
+            base,
+            current_index: None,
+            are_tabs_closable: false,
+            current_changed: Default::default(),
+            on_page_closed: CallbackVec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabNavigation {
+    Previous,
+    Next,
+    First,
+    Last,
+}
+
+const TITLE_KEY: u64 = 0;
+const CLOSE_KEY: u64 = 1;
+
+/// A tab in the tab bar of a [TabWidget].
+struct Tab {
+    base: WidgetBaseOf<Self>,
+    is_current: bool,
+    on_pressed: CallbackVec<()>,
+    on_close_requested: CallbackVec<()>,
+    on_navigation: CallbackVec<TabNavigation>,
+}
+
+impl Tab {
+    fn title_widget(&self) -> &Text {
+        self.base.get_child::<Text>(TITLE_KEY).unwrap()
+    }
+
+    fn title_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(TITLE_KEY).unwrap()
+    }
+
+    fn set_title(&mut self, title: impl Display) -> &mut Self {
+        self.title_widget_mut().set_text(title, Attrs::new());
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    fn title(&self) -> String {
+        self.title_widget().text()
+    }
+
+    fn set_closable(&mut self, value: bool) -> &mut Self {
+        self.base
+            .get_dyn_child_mut(CLOSE_KEY)
+            .unwrap()
+            .set_visible(value);
+        self
+    }
+
+    fn set_current(&mut self, value: bool) -> &mut Self {
+        self.is_current = value;
+        // Only the current tab is reachable with Tab key. Other tabs are reachable with arrow keys.
+        self.set_focusable(value);
+        self.set_pseudo_class(PseudoClass::Custom("selected".into()), value);
+        self.base.update();
+        self
+    }
+
+    fn on_pressed(&mut self, callback: Callback<()>) -> &mut Self {
+        self.on_pressed.push(callback);
+        self
+    }
+
+    fn on_close_requested(&mut self, callback: Callback<()>) -> &mut Self {
+        self.base
+            .get_child_mut::<Button>(CLOSE_KEY)
+            .unwrap()
+            .on_triggered(callback.clone());
+        self.on_close_requested.push(callback);
+        self
+    }
+
+    fn on_navigation(&mut self, callback: Callback<TabNavigation>) -> &mut Self {
+        self.on_navigation.push(callback);
+        self
+    }
+}
+
+impl Widget for Tab {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Text>(TITLE_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        // TODO: localized name
+        base.add_child_with_key::<Button>(CLOSE_KEY)
+            .set_column(1)
+            .set_row(0)
+            .set_text("close tab")
+            .set_text_visible(false)
+            .set_accessibility_node_enabled(false)
+            .set_focusable(false)
+            .add_class("tab_close".into())
+            .set_visible(false);
+        let mut this = Self {
+            base,
+            is_current: false,
+            on_pressed: CallbackVec::new(),
+            on_close_requested: CallbackVec::new(),
+            on_navigation: CallbackVec::new(),
+        };
+        this.set_focusable(false);
+        this
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.state.is_pressed() {
+            match event.button {
+                MouseButton::Left => self.on_pressed.invoke(()),
+                MouseButton::Middle => {
+                    if self.base.get_dyn_child(CLOSE_KEY)?.base().is_self_visible() {
+                        self.on_close_requested.invoke(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() {
+            return Ok(false);
+        }
+        let navigation = match event.info.logical_key {
+            KeyboardKey::Named(NamedKey::ArrowLeft) => TabNavigation::Previous,
+            KeyboardKey::Named(NamedKey::ArrowRight) => TabNavigation::Next,
+            KeyboardKey::Named(NamedKey::Home) => TabNavigation::First,
+            KeyboardKey::Named(NamedKey::End) => TabNavigation::Last,
+            _ => return Ok(false),
+        };
+        self.on_navigation.invoke(navigation);
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        match event.action {
+            Action::Click => self.on_pressed.invoke(()),
+            Action::Focus => {
+                send_window_request(
+                    self.base.window_or_err()?.id(),
+                    SetFocusRequest {
+                        widget_id: self.base.id().into(),
+                        // TODO: separate reason?
+                        reason: FocusReason::Mouse,
+                    },
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Tab);
+        node.set_label(self.title());
+        node.set_selected(self.is_current);
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Click);
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.title_widget_mut().set_host_style_element(element);
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+const STRIP_KEY: u64 = 0;
+const SCROLL_LEFT_KEY: u64 = 1;
+const SCROLL_RIGHT_KEY: u64 = 2;
+
+/// Row of tabs with scroll buttons that are shown when the tabs don't fit.
+struct TabBar {
+    base: WidgetBaseOf<Self>,
+    keys: Vec<Key>,
+    first_visible: usize,
+    strip_width: PhysicalPixels,
+}
+
+impl TabBar {
+    fn strip(&self) -> &TabStrip {
+        self.base.get_child::<TabStrip>(STRIP_KEY).unwrap()
+    }
+
+    fn strip_mut(&mut self) -> &mut TabStrip {
+        self.base.get_child_mut::<TabStrip>(STRIP_KEY).unwrap()
+    }
+
+    fn tab(&self, key: impl Into<Key>) -> Result<&Tab> {
+        self.strip().base.get_child::<Tab>(key)
+    }
+
+    fn tab_mut(&mut self, key: impl Into<Key>) -> Result<&mut Tab> {
+        self.strip_mut().base.get_child_mut::<Tab>(key)
+    }
+
+    fn insert_tab(&mut self, index: usize, key: Key, title: impl Display) -> &mut Tab {
+        self.keys.insert(index, key.clone());
+        if index < self.first_visible {
+            self.first_visible += 1;
+        }
+        self.base.size_hint_changed();
+        self.strip_mut()
+            .base
+            .add_child_with_key::<Tab>(key.clone())
+            .set_title(title);
+        self.update_tab_columns();
+        self.tab_mut(key).unwrap()
+    }
+
+    fn remove_tab(&mut self, index: usize) {
+        let key = self.keys.remove(index);
+        if index < self.first_visible {
+            self.first_visible -= 1;
+        }
+        self.strip_mut().base.remove_child(key).or_report_err();
+        self.update_tab_columns();
+        self.base.size_hint_changed();
+    }
+
+    // The tabs are positioned by the tab bar, but their columns determine
+    // the order of the tabs for `:first-child` and similar selectors.
+    fn update_tab_columns(&mut self) {
+        for (index, key) in self.keys.clone().into_iter().enumerate() {
+            if let Ok(tab) = self.tab_mut(key) {
+                tab.set_column(index as i32).set_row(0);
+            }
+        }
+    }
+
+    fn tab_widths(&mut self) -> Vec<PhysicalPixels> {
+        let keys = self.keys.clone();
+        keys.into_iter()
+            .map(|key| {
+                self.tab_mut(key)
+                    .map(|tab| tab.size_hint_x().preferred)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn scroll_by(&mut self, delta: isize) {
+        let max_first_visible = self.max_first_visible();
+        self.first_visible = self
+            .first_visible
+            .saturating_add_signed(delta)
+            .min(max_first_visible);
+        self.base.size_hint_changed();
+    }
+
+    // Scrolling further than this would leave empty space after the last tab.
+    fn max_first_visible(&mut self) -> usize {
+        let widths = self.tab_widths();
+        let mut remaining = self.strip_width;
+        for (index, width) in widths.iter().enumerate().rev() {
+            remaining -= *width;
+            if remaining < 0.ppx() {
+                return index + 1;
+            }
+        }
+        0
+    }
+
+    fn ensure_visible(&mut self, index: usize) {
+        if index < self.first_visible {
+            self.first_visible = index;
+        } else if self.strip_width > 0.ppx() {
+            let widths = self.tab_widths();
+            while self.first_visible < index
+                && widths[self.first_visible..=index]
+                    .iter()
+                    .sum::<PhysicalPixels>()
+                    > self.strip_width
+            {
+                self.first_visible += 1;
+            }
+        }
+        self.base.size_hint_changed();
+    }
+
+    fn scroll_buttons_width(&mut self) -> PhysicalPixels {
+        let left = self
+            .base
+            .get_dyn_child_mut(SCROLL_LEFT_KEY)
+            .unwrap()
+            .size_hint_x()
+            .preferred;
+        let right = self
+            .base
+            .get_dyn_child_mut(SCROLL_RIGHT_KEY)
+            .unwrap()
+            .size_hint_x()
+            .preferred;
+        left + right
+    }
+}
+
+impl Widget for TabBar {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<TabStrip>(STRIP_KEY);
+        // TODO: localized name
+        for (key, text, class) in [
+            (SCROLL_LEFT_KEY, "scroll tabs left", "tab_scroll_left"),
+            (SCROLL_RIGHT_KEY, "scroll tabs right", "tab_scroll_right"),
+        ] {
+            base.add_child_with_key::<Button>(key)
+                .set_text(text)
+                .set_text_visible(false)
+                .set_accessibility_node_enabled(false)
+                .set_focusable(false)
+                .add_class(class.into())
+                .set_auto_repeat(true)
+                .set_trigger_on_press(true)
+                .set_visible(false);
+        }
+        let mut this = Self {
+            base,
+            keys: Vec::new(),
+            first_visible: 0,
+            strip_width: 0.ppx(),
+        };
+        let scroll_left = this.callback(|this, ()| {
+            this.scroll_by(-1);
+            Ok(())
+        });
+        this.base
+            .get_child_mut::<Button>(SCROLL_LEFT_KEY)
+            .unwrap()
+            .on_triggered(scroll_left);
+        let scroll_right = this.callback(|this, ()| {
+            this.scroll_by(1);
+            Ok(())
+        });
+        this.base
+            .get_child_mut::<Button>(SCROLL_RIGHT_KEY)
+            .unwrap()
+            .on_triggered(scroll_right);
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let changed = &event.changed_size_hints;
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed);
+            }
+            return Ok(());
+        };
+        let size = geometry.size();
+        let widths = self.tab_widths();
+        let total_width: PhysicalPixels = widths.iter().sum();
+        let overflow = total_width > size.x();
+
+        let mut strip_width = size.x();
+        for key in [SCROLL_LEFT_KEY, SCROLL_RIGHT_KEY] {
+            self.base
+                .get_dyn_child_mut(key)
+                .unwrap()
+                .set_visible(overflow);
+        }
+        if overflow {
+            strip_width -= self.scroll_buttons_width();
+            let mut x = strip_width;
+            for key in [SCROLL_LEFT_KEY, SCROLL_RIGHT_KEY] {
+                let button = self.base.get_dyn_child_mut(key).unwrap();
+                let width = button.size_hint_x().preferred;
+                button.set_geometry(
+                    Some(WidgetGeometry::new(
+                        &geometry,
+                        Rect::from_xywh(x, 0.ppx(), width, size.y()),
+                    )),
+                    changed,
+                );
+                x += width;
+            }
+        } else {
+            for key in [SCROLL_LEFT_KEY, SCROLL_RIGHT_KEY] {
+                self.base
+                    .get_dyn_child_mut(key)
+                    .unwrap()
+                    .set_geometry(None, changed);
+            }
+        }
+        self.strip_width = strip_width;
+        self.first_visible = self.first_visible.min(self.max_first_visible());
+
+        self.strip_mut().set_geometry(
+            Some(WidgetGeometry::new(
+                &geometry,
+                Rect::from_xywh(0.ppx(), 0.ppx(), strip_width, size.y()),
+            )),
+            changed,
+        );
+        let strip_geometry = self.strip().base.geometry.clone().unwrap();
+        let mut x = -widths[..self.first_visible].iter().sum::<PhysicalPixels>();
+        let keys = self.keys.clone();
+        for (key, width) in keys.into_iter().zip(widths) {
+            self.tab_mut(key)?.set_geometry(
+                Some(WidgetGeometry::new(
+                    &strip_geometry,
+                    Rect::from_xywh(x, 0.ppx(), width, size.y()),
+                )),
+                changed,
+            );
+            x += width;
+        }
+
+        let first_visible = self.first_visible;
+        let max_first_visible = self.max_first_visible();
+        self.base
+            .get_child_mut::<Button>(SCROLL_LEFT_KEY)
+            .unwrap()
+            .set_enabled(first_visible > 0);
+        self.base
+            .get_child_mut::<Button>(SCROLL_RIGHT_KEY)
+            .unwrap()
+            .set_enabled(first_visible < max_first_visible);
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let keys = self.keys.clone();
+        let mut min_tab = 0.ppx();
+        let mut preferred = 0.ppx();
+        for key in keys {
+            let hints = self.tab_mut(key)?.size_hint_x();
+            min_tab = max(min_tab, hints.min);
+            preferred += hints.preferred;
+        }
+        let min = min_tab + self.scroll_buttons_width();
+        Ok(SizeHints {
+            min: min.min(preferred),
+            preferred,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let keys = self.keys.clone();
+        let mut size = 0.ppx();
+        for key in keys {
+            let tab = self.tab_mut(key)?;
+            let size_x = tab.size_hint_x().preferred;
+            size = max(size, tab.size_hint_y(size_x).preferred);
+        }
+        for key in [SCROLL_LEFT_KEY, SCROLL_RIGHT_KEY] {
+            let button = self.base.get_dyn_child_mut(key).unwrap();
+            let size_x = button.size_hint_x().preferred;
+            size = max(size, button.size_hint_y(size_x).preferred);
+        }
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::TabList)))
+    }
+}
+
+/// Container of tabs. Positions of the tabs are set by [TabBar].
+struct TabStrip {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for TabStrip {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_layout(&mut self, _event: LayoutEvent) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Container of the pages of a [TabWidget].
+struct TabPanel {
+    base: WidgetBaseOf<Self>,
+}
+
+impl Widget for TabPanel {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::TabPanel)))
+    }
+}
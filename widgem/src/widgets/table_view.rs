@@ -0,0 +1,1958 @@
+use {
+    super::{
+        list_view::SelectionMode, scroll_area::ScrollArea, Widget, WidgetAddress, WidgetBase,
+        WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        callback::{Callback, CallbackVec, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, MouseMoveEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        key::Key,
+        layout::{
+            grid::{grid_layout, GridAxisOptions},
+            solve_layout, Alignment, AxisPadding, LayoutItem, SizeHints,
+        },
+        shared_window::{ScrollToRectRequest, SetFocusRequest},
+        shortcut::standard_shortcuts,
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_font, convert_height, convert_width, Element, PseudoClass},
+            defaults::{
+                DEFAULT_MIN_HEIGHT_EM, DEFAULT_MIN_WIDTH_EM, DEFAULT_PREFERRED_HEIGHT_EM,
+                DEFAULT_PREFERRED_WIDTH_EM,
+            },
+            get_style, Style,
+        },
+        system::{send_window_request, ReportError},
+        text_editor::Text,
+        types::{LpxSuffix, PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::{Action, Role, SortDirection},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    log::warn,
+    std::{
+        cmp::{max, min, Ordering},
+        collections::{BTreeSet, HashMap},
+        marker::PhantomData,
+        ops::Range,
+        rc::Rc,
+    },
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key as KeyboardKey, ModifiersState, NamedKey},
+        window::CursorIcon,
+    },
+};
+
+/// Source of the cells displayed by a [TableView].
+///
+/// The table view only requests data for the rows that are currently visible, so the model
+/// can contain a large number of rows. When the data changes, the model must notify the table view
+/// using [TableView::handle_model_change] or the callback returned by [TableView::model_change_callback].
+pub trait TableModel {
+    fn row_count(&self) -> usize;
+    fn column_count(&self) -> usize;
+    fn data(&self, row: usize, column: usize) -> String;
+
+    fn header_data(&self, column: usize) -> String {
+        (column + 1).to_string()
+    }
+
+    /// Compares two rows by the value in `column`. Used for sorting.
+    fn compare(&self, row1: usize, row2: usize, column: usize) -> Ordering {
+        self.data(row1, column).cmp(&self.data(row2, column))
+    }
+}
+
+/// Description of a change in a [TableModel].
+///
+/// Ranges are specified in terms of model row indexes.
+/// For [RowsRemoved](Self::RowsRemoved), indexes refer to the rows before the removal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableModelChange {
+    /// Any part of the model could have changed, including the number of columns.
+    Reset,
+    RowsInserted(Range<usize>),
+    RowsRemoved(Range<usize>),
+    RowsChanged(Range<usize>),
+    HeaderChanged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Creates and updates widgets that display the cells of a [TableView] column.
+pub trait CellDelegate {
+    /// Adds the widget that displays a cell as a child of `parent` with `key`.
+    fn create_widget(&self, parent: &mut WidgetBase, key: Key);
+
+    /// Updates `widget` to display the value at `row` and `column` of the model.
+    fn update_widget(
+        &self,
+        widget: &mut dyn Widget,
+        model: &dyn TableModel,
+        row: usize,
+        column: usize,
+    );
+}
+
+/// Displays the cell as text returned by [TableModel::data]. This is the default delegate.
+pub struct TextCellDelegate;
+
+impl CellDelegate for TextCellDelegate {
+    fn create_widget(&self, parent: &mut WidgetBase, key: Key) {
+        let id = parent.id();
+        let element = parent.style_element().clone();
+        parent
+            .add_child_with_key::<Text>(key)
+            .set_host_id(id)
+            .set_host_style_element(element);
+    }
+
+    fn update_widget(
+        &self,
+        widget: &mut dyn Widget,
+        model: &dyn TableModel,
+        row: usize,
+        column: usize,
+    ) {
+        let Some(text) = widget.downcast_mut::<Text>() else {
+            warn!("TextCellDelegate: unexpected widget type");
+            return;
+        };
+        let data = model.data(row, column);
+        if text.text() != data {
+            text.set_text(data, Attrs::new());
+        }
+    }
+}
+
+struct WidgetCellDelegate<T, F> {
+    update: F,
+    _marker: PhantomData<fn(&mut T)>,
+}
+
+impl<T, F> CellDelegate for WidgetCellDelegate<T, F>
+where
+    T: Widget,
+    F: Fn(&mut T, &dyn TableModel, usize, usize),
+{
+    fn create_widget(&self, parent: &mut WidgetBase, key: Key) {
+        parent.add_child_with_key::<T>(key);
+    }
+
+    fn update_widget(
+        &self,
+        widget: &mut dyn Widget,
+        model: &dyn TableModel,
+        row: usize,
+        column: usize,
+    ) {
+        let Some(widget) = widget.downcast_mut::<T>() else {
+            warn!("cell delegate: unexpected widget type");
+            return;
+        };
+        (self.update)(widget, model, row, column);
+    }
+}
+
+const SCROLL_AREA_KEY: u64 = 0;
+
+/// A scrollable table backed by a [TableModel].
+///
+/// The table has a header that allows to resize and reorder columns and sort rows
+/// by clicking on a column. Widgets are only created for the rows that are visible,
+/// and they are reused for other rows when the table is scrolled.
+pub struct TableView {
+    base: WidgetBaseOf<Self>,
+    style: Rc<TableViewStyle>,
+}
+
+#[impl_with]
+impl TableView {
+    fn body(&self) -> &TableBody {
+        self.base
+            .get_child::<ScrollArea>(SCROLL_AREA_KEY)
+            .unwrap()
+            .content::<TableBody>()
+            .unwrap()
+    }
+
+    fn body_mut(&mut self) -> &mut TableBody {
+        self.base
+            .get_child_mut::<ScrollArea>(SCROLL_AREA_KEY)
+            .unwrap()
+            .content_mut::<TableBody>()
+            .unwrap()
+    }
+
+    pub fn set_model(&mut self, model: Rc<dyn TableModel>) -> &mut Self {
+        self.body_mut().model = Some(model);
+        self.body_mut().handle_model_change(TableModelChange::Reset);
+        self
+    }
+
+    pub fn model(&self) -> Option<&Rc<dyn TableModel>> {
+        self.body().model.as_ref()
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.body().row_count()
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.body().columns.len()
+    }
+
+    /// Updates the table view after the model has changed.
+    ///
+    /// Selected and current rows are adjusted to follow inserted and removed rows.
+    pub fn handle_model_change(&mut self, change: TableModelChange) {
+        self.body_mut().handle_model_change(change);
+    }
+
+    /// Returns a callback that calls [handle_model_change](Self::handle_model_change)
+    /// on this table view. It can be stored in the model.
+    pub fn model_change_callback(&self) -> Callback<TableModelChange> {
+        self.callback(|this, change| {
+            this.handle_model_change(change);
+            Ok(())
+        })
+    }
+
+    /// Sets the delegate that creates widgets for the cells of the model `column`.
+    pub fn set_cell_delegate(
+        &mut self,
+        column: usize,
+        delegate: Rc<dyn CellDelegate>,
+    ) -> &mut Self {
+        let body = self.body_mut();
+        body.delegates.insert(column, delegate);
+        body.remove_slots();
+        body.base.size_hint_changed();
+        self
+    }
+
+    /// Displays the cells of the model `column` using widgets of type `T`.
+    ///
+    /// `update` is called with the widget, the model, the model row and the column
+    /// each time the widget is assigned to a different row or the model changes.
+    pub fn set_cell_widget<T: Widget>(
+        &mut self,
+        column: usize,
+        update: impl Fn(&mut T, &dyn TableModel, usize, usize) + 'static,
+    ) -> &mut Self {
+        self.set_cell_delegate(
+            column,
+            Rc::new(WidgetCellDelegate {
+                update,
+                _marker: PhantomData,
+            }),
+        )
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) -> &mut Self {
+        let body = self.body_mut();
+        body.selection_mode = mode;
+        let new_selection = match mode {
+            SelectionMode::None => BTreeSet::new(),
+            SelectionMode::Single => body.selected_rows.first().copied().into_iter().collect(),
+            SelectionMode::Multi => body.selected_rows.clone(),
+        };
+        body.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.body().selection_mode
+    }
+
+    /// Sets the cell that has keyboard focus within the table and scrolls to it.
+    ///
+    /// The cell is specified as a model row and a model column. Selection is not changed.
+    pub fn set_current_cell(&mut self, cell: Option<(usize, usize)>) -> &mut Self {
+        self.body_mut().set_current_cell(cell, true);
+        self
+    }
+
+    /// Returns the model row and the model column of the current cell.
+    pub fn current_cell(&self) -> Option<(usize, usize)> {
+        self.body().current_cell
+    }
+
+    /// Returns the selected model rows.
+    pub fn selected_rows(&self) -> &BTreeSet<usize> {
+        &self.body().selected_rows
+    }
+
+    pub fn is_row_selected(&self, row: usize) -> bool {
+        self.body().selected_rows.contains(&row)
+    }
+
+    pub fn set_row_selected(&mut self, row: usize, selected: bool) -> &mut Self {
+        let body = self.body_mut();
+        if row >= body.row_count() {
+            warn!("row index out of bounds");
+            return self;
+        }
+        let mut new_selection = match body.selection_mode {
+            SelectionMode::None => {
+                warn!("cannot select rows when selection mode is None");
+                return self;
+            }
+            SelectionMode::Single => BTreeSet::new(),
+            SelectionMode::Multi => body.selected_rows.clone(),
+        };
+        if selected {
+            new_selection.insert(row);
+        } else {
+            new_selection.remove(&row);
+        }
+        body.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.body_mut().set_selection(BTreeSet::new(), true);
+        self
+    }
+
+    /// Selects all rows. Has no effect unless selection mode is [Multi](SelectionMode::Multi).
+    pub fn select_all(&mut self) -> &mut Self {
+        self.body_mut().select_all(true);
+        self
+    }
+
+    /// Sets the width of the model `column`. If `width` is `None`, the width is determined
+    /// by the header.
+    pub fn set_column_width(&mut self, column: usize, width: Option<PhysicalPixels>) -> &mut Self {
+        let body = self.body_mut();
+        let Some(info) = body.columns.iter_mut().find(|info| info.column == column) else {
+            warn!("column index out of bounds");
+            return self;
+        };
+        info.width = width;
+        body.base.size_hint_changed();
+        self
+    }
+
+    /// Returns the current width of the model `column`.
+    pub fn column_width(&self, column: usize) -> Option<PhysicalPixels> {
+        self.body()
+            .column_positions
+            .iter()
+            .find(|pos| pos.column == column)
+            .map(|pos| pos.width)
+    }
+
+    /// Sets the order in which model columns are displayed.
+    ///
+    /// `order` must contain each model column exactly once.
+    pub fn set_column_order(&mut self, order: Vec<usize>) -> &mut Self {
+        let body = self.body_mut();
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        if sorted != (0..body.columns.len()).collect::<Vec<_>>() {
+            warn!("invalid column order");
+            return self;
+        }
+        let mut new_columns = Vec::new();
+        for column in order {
+            let info = body
+                .columns
+                .iter()
+                .find(|info| info.column == column)
+                .unwrap()
+                .clone();
+            new_columns.push(info);
+        }
+        body.columns = new_columns;
+        body.base.size_hint_changed();
+        self
+    }
+
+    /// Returns model columns in the order in which they are displayed.
+    pub fn column_order(&self) -> Vec<usize> {
+        self.body().columns.iter().map(|info| info.column).collect()
+    }
+
+    /// Enables sorting by clicking on the column headers. Sorting is enabled by default.
+    pub fn set_sorting_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.body_mut().is_sorting_enabled = enabled;
+        self
+    }
+
+    /// Sorts the rows by the values in the model `column`.
+    pub fn sort_by_column(&mut self, column: usize, order: SortOrder) -> &mut Self {
+        self.body_mut().set_sort(Some((column, order)), true);
+        self
+    }
+
+    /// Displays the rows in the model order.
+    pub fn clear_sort(&mut self) -> &mut Self {
+        self.body_mut().set_sort(None, true);
+        self
+    }
+
+    /// Returns the model column used for sorting and the sort order.
+    pub fn sort_column(&self) -> Option<(usize, SortOrder)> {
+        self.body().sort
+    }
+
+    pub fn on_current_changed(&mut self, callback: Callback<Option<(usize, usize)>>) -> &mut Self {
+        self.body_mut().current_changed.add(callback);
+        self
+    }
+
+    pub fn on_selection_changed(&mut self, callback: Callback<()>) -> &mut Self {
+        self.body_mut().selection_changed.add(callback);
+        self
+    }
+
+    /// Called with the model row and the model column when a cell is double-clicked
+    /// or Enter is pressed on the current cell.
+    pub fn on_activated(&mut self, callback: Callback<(usize, usize)>) -> &mut Self {
+        self.body_mut().activated.push(callback);
+        self
+    }
+
+    pub fn on_sort_changed(&mut self, callback: Callback<Option<(usize, SortOrder)>>) -> &mut Self {
+        self.body_mut().sort_changed.add(callback);
+        self
+    }
+}
+
+impl Widget for TableView {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<ScrollArea>(SCROLL_AREA_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_content::<TableBody>();
+        Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+        }
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.style = get_style(self.base().style_element(), self.base().scale());
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_width,
+            preferred: self.style.preferred_width,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_height,
+            preferred: self.style.preferred_height,
+            is_fixed: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableViewStyle {
+    pub min_width: PhysicalPixels,
+    pub preferred_width: PhysicalPixels,
+    pub min_height: PhysicalPixels,
+    pub preferred_height: PhysicalPixels,
+}
+
+impl ComputedElementStyle for TableViewStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> TableViewStyle {
+        let element_min = element
+            .clone()
+            .with_pseudo_class(PseudoClass::Custom("min".into()));
+
+        let properties = style.find_rules(|s| element.matches(s));
+        let font = convert_font(&properties, Some(&style.root_font_style()));
+        let preferred_width = convert_width(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in table view css");
+                (font.font_size * DEFAULT_PREFERRED_WIDTH_EM).to_physical(scale)
+            });
+        let preferred_height = convert_height(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in table view css");
+                (font.font_size * DEFAULT_PREFERRED_HEIGHT_EM).to_physical(scale)
+            });
+
+        let min_properties = style.find_rules(|s| element_min.matches(s));
+        let min_width = convert_width(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in table view min css");
+                (font.font_size * DEFAULT_MIN_WIDTH_EM).to_physical(scale)
+            });
+        let min_height = convert_height(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in table view min css");
+                (font.font_size * DEFAULT_MIN_HEIGHT_EM).to_physical(scale)
+            });
+
+        Self {
+            min_width,
+            preferred_width,
+            min_height,
+            preferred_height,
+        }
+    }
+}
+
+const DEFAULT_COLUMN_WIDTH: f32 = 100.0;
+const MIN_COLUMN_WIDTH: f32 = 16.0;
+const RESIZE_HANDLE_WIDTH: f32 = 5.0;
+// Header section is not moved until the mouse is moved by this distance.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+#[derive(Debug, Clone)]
+struct ColumnInfo {
+    // Model column.
+    column: usize,
+    // Width set by the user.
+    width: Option<PhysicalPixels>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ColumnPosition {
+    // Model column.
+    column: usize,
+    x: PhysicalPixels,
+    width: PhysicalPixels,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HeaderAction {
+    Clicked(usize),
+    Dropped {
+        column: usize,
+        x_in_window: PhysicalPixels,
+    },
+    ResizeStarted(usize),
+    Resized {
+        column: usize,
+        delta: PhysicalPixels,
+    },
+}
+
+const ROWS_KEY: u64 = 0;
+const HEADER_KEY: u64 = 1;
+
+/// Content of the scroll area of a [TableView].
+///
+/// The header is always displayed at the top of the visible area. Rows are created only
+/// for the visible area of the body.
+struct TableBody {
+    base: WidgetBaseOf<Self>,
+    model: Option<Rc<dyn TableModel>>,
+    delegates: HashMap<usize, Rc<dyn CellDelegate>>,
+    // Columns in display order.
+    columns: Vec<ColumnInfo>,
+    column_positions: Vec<ColumnPosition>,
+    // View row to model row mapping. `None` if the table is not sorted.
+    model_rows: Option<Vec<usize>>,
+    // Model row to view row mapping. `None` if the table is not sorted.
+    view_rows: Option<Vec<usize>>,
+    sort: Option<(usize, SortOrder)>,
+    is_sorting_enabled: bool,
+    selection_mode: SelectionMode,
+    // Model row and model column.
+    current_cell: Option<(usize, usize)>,
+    // Model rows.
+    selected_rows: BTreeSet<usize>,
+    // Model row that is the starting point for range selection with Shift.
+    anchor_row: Option<usize>,
+    row_height: PhysicalPixels,
+    header_height: PhysicalPixels,
+    // Incremented on each model change to refresh the displayed data.
+    generation: u64,
+    resize_start_width: PhysicalPixels,
+    current_changed: Callbacks<Option<(usize, usize)>>,
+    selection_changed: Callbacks<()>,
+    sort_changed: Callbacks<Option<(usize, SortOrder)>>,
+    activated: CallbackVec<(usize, usize)>,
+}
+
+impl TableBody {
+    fn rows(&self) -> &TableRows {
+        self.base.get_child::<TableRows>(ROWS_KEY).unwrap()
+    }
+
+    fn rows_mut(&mut self) -> &mut TableRows {
+        self.base.get_child_mut::<TableRows>(ROWS_KEY).unwrap()
+    }
+
+    fn header_mut(&mut self) -> &mut TableHeader {
+        self.base.get_child_mut::<TableHeader>(HEADER_KEY).unwrap()
+    }
+
+    fn row_count(&self) -> usize {
+        self.model.as_ref().map_or(0, |model| model.row_count())
+    }
+
+    fn model_row(&self, view_row: usize) -> usize {
+        self.model_rows
+            .as_ref()
+            .map_or(view_row, |model_rows| model_rows[view_row])
+    }
+
+    fn view_row(&self, model_row: usize) -> usize {
+        self.view_rows
+            .as_ref()
+            .map_or(model_row, |view_rows| view_rows[model_row])
+    }
+
+    fn visual_column(&self, column: usize) -> Option<usize> {
+        self.columns.iter().position(|info| info.column == column)
+    }
+
+    fn delegate(&self, column: usize) -> Rc<dyn CellDelegate> {
+        self.delegates
+            .get(&column)
+            .cloned()
+            .unwrap_or_else(|| Rc::new(TextCellDelegate))
+    }
+
+    fn handle_model_change(&mut self, change: TableModelChange) {
+        let old_current = self.current_cell;
+        let old_selection = self.selected_rows.clone();
+        let row_count = self.row_count();
+        match change {
+            TableModelChange::Reset => {
+                self.current_cell = None;
+                self.anchor_row = None;
+                self.selected_rows.clear();
+                self.update_columns();
+            }
+            TableModelChange::RowsInserted(range) => {
+                let shift = |row: usize| {
+                    if row >= range.start {
+                        row + range.len()
+                    } else {
+                        row
+                    }
+                };
+                self.current_cell = self.current_cell.map(|(row, column)| (shift(row), column));
+                self.anchor_row = self.anchor_row.map(shift);
+                self.selected_rows = self.selected_rows.iter().copied().map(shift).collect();
+            }
+            TableModelChange::RowsRemoved(range) => {
+                let shift = |row: usize| {
+                    if range.contains(&row) {
+                        None
+                    } else if row >= range.end {
+                        Some(row - range.len())
+                    } else {
+                        Some(row)
+                    }
+                };
+                self.current_cell = self.current_cell.and_then(|(row, column)| {
+                    let row = shift(row).or_else(|| {
+                        // Current row was removed: move to the row that took its place.
+                        (row_count > 0).then(|| min(range.start, row_count - 1))
+                    })?;
+                    Some((row, column))
+                });
+                self.anchor_row = self.anchor_row.and_then(shift);
+                self.selected_rows = self
+                    .selected_rows
+                    .iter()
+                    .copied()
+                    .filter_map(shift)
+                    .collect();
+            }
+            TableModelChange::RowsChanged(_) => {}
+            TableModelChange::HeaderChanged => {
+                self.update_header();
+            }
+        }
+        self.generation += 1;
+        self.apply_sort();
+        if self.current_cell != old_current {
+            self.current_changed.invoke(self.current_cell, true);
+        }
+        if self.selected_rows != old_selection {
+            self.selection_changed.invoke((), true);
+        }
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    // Synchronizes the columns with the model's column count.
+    fn update_columns(&mut self) {
+        let column_count = self.model.as_ref().map_or(0, |model| model.column_count());
+        self.columns.retain(|info| info.column < column_count);
+        for column in 0..column_count {
+            if !self.columns.iter().any(|info| info.column == column) {
+                self.columns.push(ColumnInfo {
+                    column,
+                    width: None,
+                });
+            }
+        }
+        if self.sort.is_some_and(|(column, _)| column >= column_count) {
+            self.sort = None;
+        }
+        self.remove_slots();
+        let header = self.header_mut();
+        let keys: Vec<Key> = header.base.children.keys().cloned().collect();
+        for key in keys {
+            header.base.remove_child(key).or_report_err();
+        }
+        let on_action = self.callback(|this, action| {
+            this.handle_header_action(action);
+            Ok(())
+        });
+        for column in 0..column_count {
+            self.header_mut()
+                .base
+                .add_child_with_key::<HeaderSection>(column)
+                .set_column(column, on_action.clone());
+        }
+        self.update_header();
+    }
+
+    fn update_header(&mut self) {
+        let Some(model) = self.model.clone() else {
+            return;
+        };
+        let sort = self.sort;
+        let column_count = self.columns.len();
+        let positions: Vec<(usize, usize)> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, info)| (info.column, index))
+            .collect();
+        for (column, visual_index) in positions {
+            let Ok(section) = self
+                .header_mut()
+                .base
+                .get_child_mut::<HeaderSection>(column)
+            else {
+                continue;
+            };
+            let sort_order =
+                sort.and_then(|(sort_column, order)| (sort_column == column).then_some(order));
+            section.set_title(model.header_data(column), sort_order);
+            section.visual_index = visual_index;
+            section.column_count = column_count;
+        }
+    }
+
+    fn remove_slots(&mut self) {
+        let rows = self.rows_mut();
+        let keys: Vec<Key> = rows.base.children.keys().cloned().collect();
+        for key in keys {
+            rows.base.remove_child(key).or_report_err();
+        }
+    }
+
+    fn set_sort(&mut self, sort: Option<(usize, SortOrder)>, from_setter: bool) {
+        if sort.is_some_and(|(column, _)| column >= self.columns.len()) {
+            warn!("sort column out of bounds");
+            return;
+        }
+        if self.sort == sort {
+            return;
+        }
+        self.sort = sort;
+        self.apply_sort();
+        self.update_header();
+        self.sort_changed.invoke(sort, from_setter);
+        if let Some((row, column)) = self.current_cell {
+            self.scroll_to_cell(row, column);
+        }
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    fn apply_sort(&mut self) {
+        let (Some((column, order)), Some(model)) = (self.sort, self.model.clone()) else {
+            self.model_rows = None;
+            self.view_rows = None;
+            return;
+        };
+        let mut model_rows: Vec<usize> = (0..model.row_count()).collect();
+        model_rows.sort_by(|a, b| {
+            let ordering = model.compare(*a, *b, column);
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+        let mut view_rows = vec![0; model_rows.len()];
+        for (view_row, model_row) in model_rows.iter().enumerate() {
+            view_rows[*model_row] = view_row;
+        }
+        self.model_rows = Some(model_rows);
+        self.view_rows = Some(view_rows);
+        // Force refresh of the displayed rows.
+        self.generation += 1;
+    }
+
+    fn handle_header_action(&mut self, action: HeaderAction) {
+        match action {
+            HeaderAction::Clicked(column) => {
+                if !self.is_sorting_enabled {
+                    return;
+                }
+                let order = match self.sort {
+                    Some((sort_column, SortOrder::Ascending)) if sort_column == column => {
+                        SortOrder::Descending
+                    }
+                    _ => SortOrder::Ascending,
+                };
+                self.set_sort(Some((column, order)), false);
+            }
+            HeaderAction::Dropped {
+                column,
+                x_in_window,
+            } => {
+                let Some(rect_in_window) = self.base.rect_in_window() else {
+                    return;
+                };
+                let x = x_in_window - rect_in_window.left();
+                let Some(from) = self.visual_column(column) else {
+                    return;
+                };
+                let to = self
+                    .column_positions
+                    .iter()
+                    .filter(|pos| pos.column != column && pos.x + pos.width / 2 < x)
+                    .count();
+                if from != to {
+                    let info = self.columns.remove(from);
+                    self.columns.insert(to, info);
+                    self.update_header();
+                    self.base.size_hint_changed();
+                }
+            }
+            HeaderAction::ResizeStarted(column) => {
+                if let Some(pos) = self
+                    .column_positions
+                    .iter()
+                    .find(|pos| pos.column == column)
+                {
+                    self.resize_start_width = pos.width;
+                }
+            }
+            HeaderAction::Resized { column, delta } => {
+                let min_width = MIN_COLUMN_WIDTH.lpx().to_physical(self.base.scale());
+                let width = max(min_width, self.resize_start_width + delta);
+                if let Some(info) = self.columns.iter_mut().find(|info| info.column == column) {
+                    info.width = Some(width);
+                    self.base.size_hint_changed();
+                }
+            }
+        }
+    }
+
+    fn set_current_cell(&mut self, cell: Option<(usize, usize)>, from_setter: bool) {
+        if let Some((row, column)) = cell {
+            if row >= self.row_count() || column >= self.columns.len() {
+                warn!("cell index out of bounds");
+                return;
+            }
+            self.scroll_to_cell(row, column);
+        }
+        if self.current_cell == cell {
+            return;
+        }
+        self.current_cell = cell;
+        self.current_changed.invoke(cell, from_setter);
+        self.update_slots();
+    }
+
+    fn set_selection(&mut self, selection: BTreeSet<usize>, from_setter: bool) {
+        if self.selected_rows == selection {
+            return;
+        }
+        self.selected_rows = selection;
+        self.selection_changed.invoke((), from_setter);
+        self.update_slots();
+    }
+
+    fn select_all(&mut self, from_setter: bool) {
+        if self.selection_mode != SelectionMode::Multi {
+            return;
+        }
+        self.set_selection((0..self.row_count()).collect(), from_setter);
+    }
+
+    // Changes current cell and selection in response to a mouse click or a key press.
+    fn select_by_user(
+        &mut self,
+        view_row: usize,
+        column: usize,
+        modifiers: ModifiersState,
+        toggle_with_ctrl: bool,
+    ) {
+        let model_row = self.model_row(view_row);
+        let shift = modifiers.shift_key();
+        let ctrl = modifiers.control_key() || modifiers.super_key();
+        match self.selection_mode {
+            SelectionMode::None => {}
+            SelectionMode::Single => {
+                self.set_selection([model_row].into(), false);
+            }
+            SelectionMode::Multi => {
+                if shift {
+                    let anchor = self.anchor_row.unwrap_or(model_row);
+                    let anchor_view_row = self.view_row(anchor);
+                    let mut new_selection = if ctrl {
+                        self.selected_rows.clone()
+                    } else {
+                        BTreeSet::new()
+                    };
+                    new_selection.extend(
+                        (min(anchor_view_row, view_row)..=max(anchor_view_row, view_row))
+                            .map(|row| self.model_row(row)),
+                    );
+                    self.anchor_row = Some(anchor);
+                    self.set_selection(new_selection, false);
+                } else if ctrl {
+                    if toggle_with_ctrl {
+                        self.toggle_row_selected(model_row);
+                    }
+                    self.anchor_row = Some(model_row);
+                } else {
+                    self.anchor_row = Some(model_row);
+                    self.set_selection([model_row].into(), false);
+                }
+            }
+        }
+        self.set_current_cell(Some((model_row, column)), false);
+    }
+
+    fn toggle_row_selected(&mut self, model_row: usize) {
+        let mut new_selection = self.selected_rows.clone();
+        if !new_selection.remove(&model_row) {
+            new_selection.insert(model_row);
+        }
+        self.set_selection(new_selection, false);
+    }
+
+    fn cell_rect(&self, view_row: usize, column: usize) -> Option<Rect> {
+        let pos = self
+            .column_positions
+            .iter()
+            .find(|pos| pos.column == column)?;
+        Some(Rect::from_xywh(
+            pos.x,
+            self.header_height + self.row_height * view_row as i32,
+            pos.width,
+            self.row_height,
+        ))
+    }
+
+    fn scroll_to_cell(&mut self, model_row: usize, column: usize) {
+        let Some(rect) = self.cell_rect(self.view_row(model_row), column) else {
+            return;
+        };
+        let Some(window_id) = self.base.window_id() else {
+            return;
+        };
+        // Include the area covered by the header.
+        let rect = Rect::from_xywh(
+            rect.left(),
+            rect.top() - self.header_height,
+            rect.size_x(),
+            rect.size_y() + self.header_height,
+        );
+        send_window_request(
+            window_id,
+            ScrollToRectRequest {
+                widget_id: self.base.id().into(),
+                rect,
+            },
+        );
+    }
+
+    fn cell_at(&self, pos_x: PhysicalPixels, pos_y: PhysicalPixels) -> Option<(usize, usize)> {
+        if pos_y < self.header_height {
+            return None;
+        }
+        let view_row =
+            ((pos_y - self.header_height).to_i32() / max(1, self.row_height.to_i32())) as usize;
+        if view_row >= self.row_count() {
+            return None;
+        }
+        let column = self
+            .column_positions
+            .iter()
+            .find(|pos| pos_x >= pos.x && pos_x < pos.x + pos.width)?
+            .column;
+        Some((view_row, column))
+    }
+
+    fn rows_per_page(&self) -> usize {
+        let height = self
+            .base
+            .visible_rect()
+            .map_or(0, |rect| (rect.size_y() - self.header_height).to_i32());
+        max(1, height / max(1, self.row_height.to_i32())) as usize
+    }
+
+    fn focus(&mut self) -> Result<()> {
+        if !self.base.is_focused() && self.base.is_focusable() {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn column_hints(&mut self) -> Vec<SizeHints> {
+        let default_width = DEFAULT_COLUMN_WIDTH.lpx().to_physical(self.base.scale());
+        let min_width = MIN_COLUMN_WIDTH.lpx().to_physical(self.base.scale());
+        let columns = self.columns.clone();
+        let mut hints = Vec::new();
+        for info in columns {
+            let section_hints = self
+                .header_mut()
+                .base
+                .get_dyn_child_mut(info.column)
+                .map(|section| section.size_hint_x())
+                .unwrap_or(SizeHints {
+                    min: min_width,
+                    preferred: default_width,
+                    is_fixed: false,
+                });
+            hints.push(match info.width {
+                Some(width) => SizeHints {
+                    min: width,
+                    preferred: width,
+                    is_fixed: true,
+                },
+                None => SizeHints {
+                    min: max(min_width, section_hints.min),
+                    preferred: max(default_width, section_hints.preferred),
+                    is_fixed: false,
+                },
+            });
+        }
+        hints
+    }
+
+    fn update_column_positions(&mut self, width: PhysicalPixels) {
+        let items: Vec<LayoutItem> = self
+            .column_hints()
+            .into_iter()
+            .map(|size_hints| LayoutItem { size_hints })
+            .collect();
+        let options = GridAxisOptions {
+            min_padding: AxisPadding::ZERO,
+            min_spacing: 0.ppx(),
+            preferred_padding: AxisPadding::ZERO,
+            preferred_spacing: 0.ppx(),
+            border_collapse: 0.ppx(),
+            alignment: Alignment::Start,
+        };
+        let output = solve_layout(&items, width, &options);
+        let mut x = 0.ppx();
+        self.column_positions = self
+            .columns
+            .iter()
+            .zip(output.sizes)
+            .map(|(info, width)| {
+                let pos = ColumnPosition {
+                    column: info.column,
+                    x,
+                    width,
+                };
+                x += width;
+                pos
+            })
+            .collect();
+    }
+
+    // Returns the height of a row, creating the first slot if necessary.
+    fn measure_row_height(&mut self) -> PhysicalPixels {
+        if !self.rows().base.has_child(0u64) {
+            self.set_num_slots(1);
+        }
+        let positions = self.column_positions.clone();
+        let slot = self.rows_mut().slot_mut(0).unwrap();
+        slot.set_columns(&positions);
+        max(1.ppx(), slot.size_hint_y_for_cells())
+    }
+
+    fn set_num_slots(&mut self, count: usize) {
+        let column_count = self.columns.len();
+        let delegates: Vec<_> = (0..column_count)
+            .map(|column| self.delegate(column))
+            .collect();
+        let rows = self.rows_mut();
+        let num_existing = rows.base.children.len();
+        for slot in count..num_existing {
+            rows.base.remove_child(slot).or_report_err();
+        }
+        for slot in num_existing..count {
+            let row = rows.base.add_child_with_key::<TableRow>(slot);
+            for (column, delegate) in delegates.iter().enumerate() {
+                row.add_cell(column, delegate.as_ref());
+            }
+        }
+    }
+
+    fn layout_rows(&mut self, changed_size_hints: &[WidgetAddress]) -> Result<()> {
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed_size_hints);
+            }
+            return Ok(());
+        };
+        let size = geometry.size();
+        self.update_column_positions(size.x());
+        let positions = self.column_positions.clone();
+        let visible_rect = geometry.visible_rect_in_self();
+
+        let header = self.header_mut();
+        header.set_columns(&positions);
+        let header_height = header.size_hint_y(size.x()).preferred;
+        self.header_height = header_height;
+        let header_rect = Rect::from_xywh(
+            0.ppx(),
+            max(0.ppx(), visible_rect.top()),
+            size.x(),
+            header_height,
+        );
+        self.header_mut().set_geometry(
+            Some(WidgetGeometry::new(&geometry, header_rect)),
+            changed_size_hints,
+        );
+
+        self.rows_mut().set_geometry(
+            Some(WidgetGeometry::new(&geometry, geometry.rect_in_self())),
+            changed_size_hints,
+        );
+        let rows_geometry = self.rows().base.geometry.clone().unwrap();
+
+        self.row_height = self.measure_row_height();
+        let row_height = self.row_height.to_i32();
+        let row_count = self.row_count();
+
+        // Each visible row is displayed by the slot `row % num_slots`, so only the slots
+        // of the rows that became visible need to be updated when the table is scrolled.
+        let num_slots = if visible_rect.is_empty() {
+            0
+        } else {
+            min(
+                row_count,
+                (visible_rect.size_y().to_i32() / row_height) as usize + 2,
+            )
+        };
+        let first_row = (max(0, visible_rect.top().to_i32()) / row_height) as usize;
+        let visible_rows = first_row..min(row_count, first_row + num_slots);
+
+        self.set_num_slots(max(1, num_slots));
+        let mut used_slots = vec![false; max(1, num_slots)];
+        let model = self.model.clone();
+        let generation = self.generation;
+        for view_row in visible_rows {
+            let slot = view_row % num_slots;
+            used_slots[slot] = true;
+            let model_row = self.model_row(view_row);
+            let is_selected = self.selected_rows.contains(&model_row);
+            let current_column = self
+                .current_cell
+                .and_then(|(row, column)| (row == model_row).then_some(column));
+            let delegates: Vec<_> = (0..positions.len())
+                .map(|column| self.delegate(column))
+                .collect();
+            let rect = Rect::from_xywh(
+                0.ppx(),
+                self.header_height + self.row_height * view_row as i32,
+                size.x(),
+                self.row_height,
+            );
+            let table_row = self.rows_mut().slot_mut(slot)?;
+            if let Some(model) = &model {
+                table_row.set_row(
+                    view_row,
+                    model_row,
+                    row_count,
+                    generation,
+                    model.as_ref(),
+                    &delegates,
+                );
+            }
+            table_row
+                .set_selected(is_selected)
+                .set_current_column(current_column)
+                .set_columns(&positions)
+                .set_visible(true);
+            table_row.set_geometry(
+                Some(WidgetGeometry::new(&rows_geometry, rect)),
+                changed_size_hints,
+            );
+        }
+        for (slot, is_used) in used_slots.into_iter().enumerate() {
+            if !is_used {
+                let table_row = self.rows_mut().slot_mut(slot)?;
+                table_row.set_visible(false);
+                table_row.set_geometry(None, changed_size_hints);
+            }
+        }
+        Ok(())
+    }
+
+    // Updates selected and current state of the displayed rows.
+    fn update_slots(&mut self) {
+        let selected_rows = self.selected_rows.clone();
+        let current_cell = self.current_cell;
+        for slot in self.rows_mut().base.children.values_mut() {
+            let Some(table_row) = slot.downcast_mut::<TableRow>() else {
+                continue;
+            };
+            let Some(model_row) = table_row.model_row else {
+                continue;
+            };
+            let current_column =
+                current_cell.and_then(|(row, column)| (row == model_row).then_some(column));
+            table_row
+                .set_selected(selected_rows.contains(&model_row))
+                .set_current_column(current_column);
+        }
+        self.base.update();
+    }
+}
+
+impl Widget for TableBody {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.add_child_with_key::<TableRows>(ROWS_KEY);
+        base.add_child_with_key::<TableHeader>(HEADER_KEY);
+        Self {
+            base,
+            model: None,
+            delegates: HashMap::new(),
+            columns: Vec::new(),
+            column_positions: Vec::new(),
+            model_rows: None,
+            view_rows: None,
+            sort: None,
+            is_sorting_enabled: true,
+            selection_mode: SelectionMode::default(),
+            current_cell: None,
+            selected_rows: BTreeSet::new(),
+            anchor_row: None,
+            row_height: 1.ppx(),
+            header_height: 0.ppx(),
+            generation: 0,
+            resize_start_width: 0.ppx(),
+            current_changed: Callbacks::default(),
+            selection_changed: Callbacks::default(),
+            sort_changed: Callbacks::default(),
+            activated: CallbackVec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        self.layout_rows(&event.changed_size_hints)
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let hints = self.column_hints();
+        Ok(SizeHints {
+            min: hints.iter().map(|hints| hints.min).sum(),
+            preferred: hints.iter().map(|hints| hints.preferred).sum(),
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        self.update_column_positions(size_x);
+        let positions = self.column_positions.clone();
+        let header = self.header_mut();
+        header.set_columns(&positions);
+        let header_height = header.size_hint_y(size_x).preferred;
+        let row_height = self.measure_row_height();
+        let row_count: i32 = self.row_count().try_into().unwrap_or(i32::MAX);
+        let size =
+            header_height + PhysicalPixels::from_i32(row_height.to_i32().saturating_mul(row_count));
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button != MouseButton::Left || !event.state.is_pressed() {
+            return Ok(true);
+        }
+        self.focus()?;
+        let Some((view_row, column)) = self.cell_at(event.pos.x(), event.pos.y()) else {
+            return Ok(true);
+        };
+        if event.num_clicks >= 2 {
+            self.activated.invoke((self.model_row(view_row), column));
+            return Ok(true);
+        }
+        let modifiers = self.base.window_or_err()?.modifiers();
+        self.select_by_user(view_row, column, modifiers, true);
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() || !self.base.is_enabled() {
+            return Ok(false);
+        }
+        if standard_shortcuts().select_all.matches(&event) {
+            self.select_all(false);
+            return Ok(true);
+        }
+        let row_count = self.row_count();
+        if row_count == 0 || self.columns.is_empty() {
+            return Ok(false);
+        }
+        let last_row = row_count - 1;
+        let last_column = self.columns.len() - 1;
+        let (view_row, visual_column) = match self.current_cell {
+            Some((row, column)) => (
+                Some(self.view_row(row)),
+                self.visual_column(column).unwrap_or(0),
+            ),
+            None => (None, 0),
+        };
+        let ctrl = event.modifiers.control_key() || event.modifiers.super_key();
+        let (new_row, new_visual_column) = match &event.info.logical_key {
+            KeyboardKey::Named(NamedKey::ArrowUp) => (
+                view_row.map_or(0, |row| row.saturating_sub(1)),
+                visual_column,
+            ),
+            KeyboardKey::Named(NamedKey::ArrowDown) => (
+                view_row.map_or(0, |row| min(row + 1, last_row)),
+                visual_column,
+            ),
+            KeyboardKey::Named(NamedKey::ArrowLeft) => {
+                (view_row.unwrap_or(0), visual_column.saturating_sub(1))
+            }
+            KeyboardKey::Named(NamedKey::ArrowRight) => {
+                (view_row.unwrap_or(0), min(visual_column + 1, last_column))
+            }
+            KeyboardKey::Named(NamedKey::PageUp) => (
+                view_row.map_or(0, |row| row.saturating_sub(self.rows_per_page())),
+                visual_column,
+            ),
+            KeyboardKey::Named(NamedKey::PageDown) => (
+                view_row.map_or(0, |row| min(row + self.rows_per_page(), last_row)),
+                visual_column,
+            ),
+            KeyboardKey::Named(NamedKey::Home) if ctrl => (0, visual_column),
+            KeyboardKey::Named(NamedKey::End) if ctrl => (last_row, visual_column),
+            KeyboardKey::Named(NamedKey::Home) => (view_row.unwrap_or(0), 0),
+            KeyboardKey::Named(NamedKey::End) => (view_row.unwrap_or(0), last_column),
+            KeyboardKey::Named(NamedKey::Space) => {
+                if let Some((row, column)) = self.current_cell {
+                    if self.selection_mode == SelectionMode::Multi {
+                        self.anchor_row = Some(row);
+                        self.toggle_row_selected(row);
+                    } else {
+                        self.select_by_user(
+                            self.view_row(row),
+                            column,
+                            ModifiersState::empty(),
+                            false,
+                        );
+                    }
+                }
+                return Ok(true);
+            }
+            KeyboardKey::Named(NamedKey::Enter) => {
+                if let Some(cell) = self.current_cell {
+                    self.activated.invoke(cell);
+                }
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+        let column = self.columns[new_visual_column].column;
+        if view_row == Some(new_row) {
+            // Moving within a row doesn't change selection.
+            let model_row = self.model_row(new_row);
+            self.set_current_cell(Some((model_row, column)), false);
+        } else {
+            self.select_by_user(new_row, column, event.modifiers, false);
+        }
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        if event.action == Action::Focus {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    // TODO: separate reason?
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Table);
+        // Header is the first row.
+        node.set_row_count(self.row_count() + 1);
+        node.set_column_count(self.columns.len());
+        if self.selection_mode == SelectionMode::Multi {
+            node.set_multiselectable();
+        }
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+}
+
+/// Container of the row widgets. Positions of the rows are set by [TableBody].
+struct TableRows {
+    base: WidgetBaseOf<Self>,
+}
+
+impl TableRows {
+    fn slot_mut(&mut self, slot: usize) -> Result<&mut TableRow> {
+        self.base.get_child_mut::<TableRow>(slot)
+    }
+}
+
+impl Widget for TableRows {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_layout(&mut self, _event: LayoutEvent) -> Result<()> {
+        Ok(())
+    }
+}
+
+// Positions children keyed by model columns according to `positions`.
+fn layout_columns(
+    base: &mut WidgetBase,
+    positions: &[ColumnPosition],
+    changed_size_hints: &[WidgetAddress],
+) {
+    let Some(geometry) = base.geometry.clone() else {
+        for child in base.children.values_mut() {
+            child.set_geometry(None, changed_size_hints);
+        }
+        return;
+    };
+    let size_y = geometry.size_y();
+    for (key, child) in base.children.iter_mut() {
+        let position = positions.iter().find(|pos| Key::from(pos.column) == *key);
+        let child_geometry = position.map(|pos| {
+            WidgetGeometry::new(
+                &geometry,
+                Rect::from_xywh(pos.x, 0.ppx(), pos.width, size_y),
+            )
+        });
+        child.set_geometry(child_geometry, changed_size_hints);
+    }
+}
+
+// Returns the maximum preferred height of children keyed by model columns.
+fn columns_size_hint_y(base: &mut WidgetBase, positions: &[ColumnPosition]) -> PhysicalPixels {
+    let mut size = 0.ppx();
+    for pos in positions {
+        if let Ok(child) = base.get_dyn_child_mut(pos.column) {
+            size = max(size, child.size_hint_y(pos.width).preferred);
+        }
+    }
+    size
+}
+
+/// Row of header sections of a [TableView].
+struct TableHeader {
+    base: WidgetBaseOf<Self>,
+    positions: Vec<ColumnPosition>,
+}
+
+impl TableHeader {
+    fn set_columns(&mut self, positions: &[ColumnPosition]) -> &mut Self {
+        if self.positions != positions {
+            self.positions = positions.to_vec();
+            layout_columns(&mut self.base, &self.positions, &[]);
+        }
+        self
+    }
+}
+
+impl Widget for TableHeader {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self {
+            base,
+            positions: Vec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        layout_columns(&mut self.base, &self.positions, &event.changed_size_hints);
+        Ok(())
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let size = columns_size_hint_y(&mut self.base, &self.positions);
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Row);
+        node.set_row_index(0);
+        Ok(Some(node))
+    }
+}
+
+const SECTION_TITLE_KEY: u64 = 0;
+const SECTION_RESIZE_HANDLE_KEY: u64 = 1;
+
+/// Header of a column. Click sorts the table, drag moves the column.
+struct HeaderSection {
+    base: WidgetBaseOf<Self>,
+    // Model column.
+    column: usize,
+    visual_index: usize,
+    column_count: usize,
+    title: String,
+    sort_order: Option<SortOrder>,
+    on_action: Option<Callback<HeaderAction>>,
+    press_x_in_window: Option<PhysicalPixels>,
+    is_dragged: bool,
+}
+
+impl HeaderSection {
+    fn set_column(&mut self, column: usize, on_action: Callback<HeaderAction>) -> &mut Self {
+        self.column = column;
+        self.base
+            .get_child_mut::<ColumnResizeHandle>(SECTION_RESIZE_HANDLE_KEY)
+            .unwrap()
+            .set_column(column, on_action.clone());
+        self.on_action = Some(on_action);
+        self
+    }
+
+    fn set_title(&mut self, title: String, sort_order: Option<SortOrder>) -> &mut Self {
+        if self.title == title && self.sort_order == sort_order {
+            return self;
+        }
+        let text = match sort_order {
+            None => title.clone(),
+            Some(SortOrder::Ascending) => format!("{title} ▲"),
+            Some(SortOrder::Descending) => format!("{title} ▼"),
+        };
+        self.base
+            .get_child_mut::<Text>(SECTION_TITLE_KEY)
+            .unwrap()
+            .set_text(text, Attrs::new());
+        self.title = title;
+        self.sort_order = sort_order;
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+}
+
+impl Widget for HeaderSection {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Text>(SECTION_TITLE_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        base.add_child_with_key::<ColumnResizeHandle>(SECTION_RESIZE_HANDLE_KEY);
+        Self {
+            base,
+            column: 0,
+            visual_index: 0,
+            column_count: 0,
+            title: String::new(),
+            sort_order: None,
+            on_action: None,
+            press_x_in_window: None,
+            is_dragged: false,
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        let Some(geometry) = self.base.geometry.clone() else {
+            return Ok(());
+        };
+        let size = geometry.size();
+        let handle_width = RESIZE_HANDLE_WIDTH.lpx().to_physical(self.base.scale());
+        self.base
+            .get_dyn_child_mut(SECTION_RESIZE_HANDLE_KEY)
+            .unwrap()
+            .set_geometry(
+                Some(WidgetGeometry::new(
+                    &geometry,
+                    Rect::from_xywh(size.x() - handle_width, 0.ppx(), handle_width, size.y()),
+                )),
+                &event.changed_size_hints,
+            );
+        Ok(())
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if event.button != MouseButton::Left {
+            return Ok(true);
+        }
+        if event.state.is_pressed() {
+            self.press_x_in_window = Some(event.pos_in_window.x());
+            self.is_dragged = false;
+        } else if self.press_x_in_window.take().is_some() {
+            let action = if self.is_dragged {
+                HeaderAction::Dropped {
+                    column: self.column,
+                    x_in_window: event.pos_in_window.x(),
+                }
+            } else {
+                HeaderAction::Clicked(self.column)
+            };
+            self.is_dragged = false;
+            self.set_pseudo_class(PseudoClass::Custom("dragged".into()), false);
+            if let Some(on_action) = &self.on_action {
+                on_action.invoke(action);
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if let Some(press_x) = self.press_x_in_window {
+            let threshold = DRAG_THRESHOLD.lpx().to_physical(self.base.scale());
+            let delta = event.pos_in_window.x() - press_x;
+            if !self.is_dragged && max(delta, -delta) > threshold {
+                self.is_dragged = true;
+                self.set_pseudo_class(PseudoClass::Custom("dragged".into()), true);
+            }
+        }
+        Ok(true)
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::ColumnHeader);
+        node.set_label(self.title.clone());
+        node.set_row_index(0);
+        node.set_column_index(self.visual_index);
+        node.set_size_of_set(self.column_count);
+        match self.sort_order {
+            Some(SortOrder::Ascending) => node.set_sort_direction(SortDirection::Ascending),
+            Some(SortOrder::Descending) => node.set_sort_direction(SortDirection::Descending),
+            None => {}
+        }
+        node.add_action(Action::Click);
+        Ok(Some(node))
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        if event.action == Action::Click {
+            if let Some(on_action) = &self.on_action {
+                on_action.invoke(HeaderAction::Clicked(self.column));
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.base
+            .get_child_mut::<Text>(SECTION_TITLE_KEY)
+            .unwrap()
+            .set_host_style_element(element);
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+/// Area at the right edge of a header section that allows to resize the column.
+struct ColumnResizeHandle {
+    base: WidgetBaseOf<Self>,
+    column: usize,
+    on_action: Option<Callback<HeaderAction>>,
+    press_x_in_window: Option<PhysicalPixels>,
+}
+
+impl ColumnResizeHandle {
+    fn set_column(&mut self, column: usize, on_action: Callback<HeaderAction>) {
+        self.column = column;
+        self.on_action = Some(on_action);
+    }
+}
+
+impl Widget for ColumnResizeHandle {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_cursor_icon(CursorIcon::ColResize);
+        Self {
+            base,
+            column: 0,
+            on_action: None,
+            press_x_in_window: None,
+        }
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if event.button != MouseButton::Left {
+            return Ok(true);
+        }
+        if event.state.is_pressed() {
+            self.press_x_in_window = Some(event.pos_in_window.x());
+            if let Some(on_action) = &self.on_action {
+                on_action.invoke(HeaderAction::ResizeStarted(self.column));
+            }
+        } else {
+            self.press_x_in_window = None;
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_move(&mut self, event: MouseMoveEvent) -> Result<bool> {
+        if let (Some(press_x), Some(on_action)) = (self.press_x_in_window, &self.on_action) {
+            on_action.invoke(HeaderAction::Resized {
+                column: self.column,
+                delta: event.pos_in_window.x() - press_x,
+            });
+        }
+        Ok(true)
+    }
+}
+
+/// Widget that displays one row of a [TableView]. It's reused for different rows on scroll.
+struct TableRow {
+    base: WidgetBaseOf<Self>,
+    view_row: Option<usize>,
+    model_row: Option<usize>,
+    row_count: usize,
+    generation: u64,
+    is_selected: bool,
+    positions: Vec<ColumnPosition>,
+}
+
+impl TableRow {
+    fn cell_mut(&mut self, column: usize) -> Option<&mut TableCell> {
+        self.base.get_child_mut::<TableCell>(column).ok()
+    }
+
+    fn add_cell(&mut self, column: usize, delegate: &dyn CellDelegate) {
+        let cell = self.base.add_child_with_key::<TableCell>(column);
+        delegate.create_widget(&mut cell.base, CELL_CONTENT_KEY.into());
+        match cell.base.get_dyn_child_mut(CELL_CONTENT_KEY) {
+            Ok(content) => {
+                content.set_column(0).set_row(0);
+            }
+            Err(_) => warn!("cell delegate didn't create a widget"),
+        }
+    }
+
+    fn set_row(
+        &mut self,
+        view_row: usize,
+        model_row: usize,
+        row_count: usize,
+        generation: u64,
+        model: &dyn TableModel,
+        delegates: &[Rc<dyn CellDelegate>],
+    ) -> &mut Self {
+        if self.model_row != Some(model_row) || self.generation != generation {
+            for (column, delegate) in delegates.iter().enumerate() {
+                if let Some(cell) = self.cell_mut(column) {
+                    cell.set_data(model, model_row, column, delegate.as_ref());
+                }
+            }
+            self.model_row = Some(model_row);
+            self.generation = generation;
+        }
+        if self.view_row != Some(view_row) || self.row_count != row_count {
+            self.view_row = Some(view_row);
+            self.row_count = row_count;
+            for child in self.base.children.values_mut() {
+                if let Some(cell) = child.downcast_mut::<TableCell>() {
+                    cell.view_row = view_row;
+                    cell.base.update();
+                }
+            }
+            self.base.update();
+        }
+        self
+    }
+
+    fn set_columns(&mut self, positions: &[ColumnPosition]) -> &mut Self {
+        if self.positions != positions {
+            self.positions = positions.to_vec();
+            for (visual_index, pos) in positions.iter().enumerate() {
+                if let Some(cell) = self.cell_mut(pos.column) {
+                    cell.visual_index = visual_index;
+                }
+            }
+            layout_columns(&mut self.base, &self.positions, &[]);
+        }
+        self
+    }
+
+    fn set_selected(&mut self, value: bool) -> &mut Self {
+        if self.is_selected != value {
+            self.is_selected = value;
+            self.base.update();
+        }
+        self.set_pseudo_class(PseudoClass::Custom("selected".into()), value);
+        for child in self.base.children.values_mut() {
+            child.set_pseudo_class(PseudoClass::Custom("selected".into()), value);
+        }
+        self
+    }
+
+    fn set_current_column(&mut self, current_column: Option<usize>) -> &mut Self {
+        for (key, child) in self.base.children.iter_mut() {
+            let is_current = current_column.is_some_and(|column| Key::from(column) == *key);
+            child.set_pseudo_class(PseudoClass::Custom("current".into()), is_current);
+        }
+        self
+    }
+
+    fn size_hint_y_for_cells(&mut self) -> PhysicalPixels {
+        columns_size_hint_y(&mut self.base, &self.positions)
+    }
+}
+
+impl Widget for TableRow {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self {
+            base,
+            view_row: None,
+            model_row: None,
+            row_count: 0,
+            generation: 0,
+            is_selected: false,
+            positions: Vec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        layout_columns(&mut self.base, &self.positions, &event.changed_size_hints);
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let Some(view_row) = self.view_row else {
+            return Ok(None);
+        };
+        let mut node = accesskit::Node::new(Role::Row);
+        // Header is the first row.
+        node.set_row_index(view_row + 1);
+        node.set_selected(self.is_selected);
+        Ok(Some(node))
+    }
+}
+
+const CELL_CONTENT_KEY: u64 = 0;
+
+/// Container of the widget created by a [CellDelegate].
+struct TableCell {
+    base: WidgetBaseOf<Self>,
+    view_row: usize,
+    visual_index: usize,
+    label: String,
+}
+
+impl TableCell {
+    fn set_data(
+        &mut self,
+        model: &dyn TableModel,
+        model_row: usize,
+        column: usize,
+        delegate: &dyn CellDelegate,
+    ) {
+        if let Ok(content) = self.base.get_dyn_child_mut(CELL_CONTENT_KEY) {
+            delegate.update_widget(content, model, model_row, column);
+        }
+        self.label = model.data(model_row, column);
+        self.base.update();
+    }
+}
+
+impl Widget for TableCell {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self {
+            base,
+            view_row: 0,
+            visual_index: 0,
+            label: String::new(),
+        }
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Cell);
+        node.set_label(self.label.clone());
+        // Header is the first row.
+        node.set_row_index(self.view_row + 1);
+        node.set_column_index(self.visual_index);
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        if let Ok(text) = self.base.get_child_mut::<Text>(CELL_CONTENT_KEY) {
+            text.set_host_style_element(element);
+        }
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
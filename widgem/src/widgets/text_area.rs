@@ -0,0 +1,278 @@
+use {
+    super::{scroll_area::ScrollArea, Widget, WidgetBaseOf, WidgetExt},
+    crate::{
+        callback::{Callback, CallbackVec},
+        event::{
+            FocusInEvent, FocusOutEvent, InputMethodEvent, KeyboardInputEvent, LayoutEvent,
+            StyleChangeEvent,
+        },
+        impl_widget_base,
+        layout::{grid::grid_layout, SizeHints},
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_font, convert_height, convert_width, Element, PseudoClass},
+            defaults::{
+                DEFAULT_MIN_HEIGHT_EM, DEFAULT_MIN_WIDTH_EM, DEFAULT_PREFERRED_HEIGHT_EM,
+                DEFAULT_PREFERRED_WIDTH_EM,
+            },
+            get_style, Style,
+        },
+        system::ReportError,
+        text_editor::Text,
+        types::PhysicalPixels,
+    },
+    anyhow::Result,
+    cosmic_text::{Attrs, Wrap},
+    log::warn,
+    std::{fmt::Display, rc::Rc},
+    widgem_macros::impl_with,
+    winit::window::CursorIcon,
+};
+
+/// A multiline text editor with scroll bars.
+pub struct TextArea {
+    base: WidgetBaseOf<Self>,
+    style: Rc<TextAreaStyle>,
+    is_word_wrap_enabled: bool,
+    on_text_edited: CallbackVec<String>,
+}
+
+#[impl_with]
+impl TextArea {
+    fn scroll_area(&self) -> &ScrollArea {
+        self.base.get_child::<ScrollArea>(0).unwrap()
+    }
+
+    fn scroll_area_mut(&mut self) -> &mut ScrollArea {
+        self.base.get_child_mut::<ScrollArea>(0).unwrap()
+    }
+
+    fn text_widget(&self) -> &Text {
+        self.scroll_area().content::<Text>().unwrap()
+    }
+
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.scroll_area_mut().content_mut::<Text>().unwrap()
+    }
+
+    /// Replaces the text. This also clears the undo history.
+    pub fn set_text(&mut self, text: impl Display) {
+        self.text_widget_mut().set_text(text, Attrs::new());
+    }
+
+    pub fn text(&self) -> String {
+        self.text_widget().text()
+    }
+
+    /// Enables or disables wrapping long lines at word boundaries.
+    ///
+    /// If wrapping is disabled, long lines can be viewed by scrolling horizontally. Enabled by default.
+    pub fn set_word_wrap(&mut self, enabled: bool) {
+        self.is_word_wrap_enabled = enabled;
+        self.text_widget_mut().set_wrap(if enabled {
+            Wrap::WordOrGlyph
+        } else {
+            Wrap::None
+        });
+        self.update_wrap_width();
+    }
+
+    pub fn is_word_wrap_enabled(&self) -> bool {
+        self.is_word_wrap_enabled
+    }
+
+    /// Allows or disallows editing the text by the user. The text is editable by default.
+    pub fn set_editable(&mut self, editable: bool) {
+        self.text_widget_mut().set_editable(editable);
+        self.set_pseudo_class(PseudoClass::ReadOnly, !editable);
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.text_widget().is_editable()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.text_widget().can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.text_widget().can_redo()
+    }
+
+    /// Reverts the last edit made by the user.
+    pub fn undo(&mut self) {
+        let old_text = self.text();
+        self.text_widget_mut().undo();
+        self.notify_if_edited(old_text);
+    }
+
+    /// Reapplies the last edit reverted by [undo](Self::undo).
+    pub fn redo(&mut self) {
+        let old_text = self.text();
+        self.text_widget_mut().redo();
+        self.notify_if_edited(old_text);
+    }
+
+    /// Forgets all edits, so that they can't be undone.
+    pub fn clear_history(&mut self) {
+        self.text_widget_mut().clear_history();
+    }
+
+    /// The callback is invoked when the text is changed by the user.
+    pub fn on_text_edited(&mut self, callback: Callback<String>) {
+        self.on_text_edited.push(callback);
+    }
+
+    fn notify_if_edited(&mut self, old_text: String) {
+        let text = self.text();
+        if text != old_text {
+            self.on_text_edited.invoke(text);
+        }
+    }
+
+    fn update_wrap_width(&mut self) {
+        let width = if self.is_word_wrap_enabled {
+            self.scroll_area().viewport_size().map(|size| size.x())
+        } else {
+            None
+        };
+        self.text_widget_mut().set_wrap_width(width);
+    }
+}
+
+impl Widget for TextArea {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        base.set_cursor_icon(CursorIcon::Text);
+        let host_id = base.id();
+        let element = base.style_element().clone();
+        let scroll_area = base
+            .add_child_with_key::<ScrollArea>(0)
+            .set_column(0)
+            .set_row(0);
+        let editor = scroll_area
+            .set_content::<Text>()
+            .set_multiline(true)
+            .set_editable(true)
+            .set_host_id(host_id.into())
+            .set_host_style_element(element);
+        editor.set_wrap(Wrap::WordOrGlyph);
+        editor.base_mut().set_receives_all_mouse_events(true);
+        Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+            is_word_wrap_enabled: true,
+            on_text_edited: CallbackVec::new(),
+        }
+    }
+
+    fn handle_focus_in(&mut self, event: FocusInEvent) -> Result<()> {
+        self.text_widget_mut().handle_host_focus_in(event.reason)
+    }
+
+    fn handle_focus_out(&mut self, _event: FocusOutEvent) -> Result<()> {
+        self.text_widget_mut().handle_host_focus_out()
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        self.update_wrap_width();
+        Ok(())
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.style = get_style(self.base().style_element(), self.base().scale());
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_width,
+            preferred: self.style.preferred_width,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_height,
+            preferred: self.style.preferred_height,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        let old_text = self.text();
+        let accepted = self.text_widget_mut().handle_host_keyboard_input(event)?;
+        self.notify_if_edited(old_text);
+        Ok(accepted)
+    }
+
+    fn handle_input_method(&mut self, event: InputMethodEvent) -> Result<bool> {
+        let old_text = self.text();
+        let accepted = self.text_widget_mut().handle_host_ime(event)?;
+        self.notify_if_edited(old_text);
+        Ok(accepted)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextAreaStyle {
+    pub min_width: PhysicalPixels,
+    pub preferred_width: PhysicalPixels,
+    pub min_height: PhysicalPixels,
+    pub preferred_height: PhysicalPixels,
+}
+
+impl ComputedElementStyle for TextAreaStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> TextAreaStyle {
+        let element_min = element
+            .clone()
+            .with_pseudo_class(PseudoClass::Custom("min".into()));
+
+        let properties = style.find_rules(|s| element.matches(s));
+        let font = convert_font(&properties, Some(&style.root_font_style()));
+        let preferred_width = convert_width(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in text area css");
+                (font.font_size * DEFAULT_PREFERRED_WIDTH_EM).to_physical(scale)
+            });
+        let preferred_height = convert_height(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in text area css");
+                (font.font_size * DEFAULT_PREFERRED_HEIGHT_EM).to_physical(scale)
+            });
+
+        let min_properties = style.find_rules(|s| element_min.matches(s));
+        let min_width = convert_width(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in text area min css");
+                (font.font_size * DEFAULT_MIN_WIDTH_EM).to_physical(scale)
+            });
+        let min_height = convert_height(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in text area min css");
+                (font.font_size * DEFAULT_MIN_HEIGHT_EM).to_physical(scale)
+            });
+
+        Self {
+            min_width,
+            preferred_width,
+            min_height,
+            preferred_height,
+        }
+    }
+}
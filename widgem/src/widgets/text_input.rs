@@ -1,6 +1,7 @@
 use {
     super::{Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry},
     crate::{
+        callback::{Callback, CallbackVec},
         event::{
             FocusInEvent, FocusOutEvent, InputMethodEvent, KeyboardInputEvent, LayoutEvent,
             ScrollToRectRequest, StyleChangeEvent,
@@ -18,9 +19,9 @@ use {
         types::{PhysicalPixels, Point, PpxSuffix, Rect},
     },
     anyhow::Result,
-    cosmic_text::Attrs,
+    cosmic_text::{Attrs, Cursor},
     log::warn,
-    std::{cmp::max, fmt::Display, rc::Rc},
+    std::{cmp::max, fmt::Display, ops::Range, rc::Rc},
     winit::window::CursorIcon,
 };
 
@@ -57,6 +58,7 @@ impl Widget for Viewport {
 pub struct TextInput {
     base: WidgetBaseOf<Self>,
     style: Rc<TextInputStyle>,
+    on_text_edited: CallbackVec<String>,
 }
 
 impl TextInput {
@@ -78,10 +80,78 @@ impl TextInput {
             .unwrap()
     }
 
+    /// Replaces the text. This also clears the undo history.
     pub fn set_text(&mut self, text: impl Display) {
         self.text_widget_mut().set_text(text, Attrs::new());
     }
 
+    pub fn text(&self) -> String {
+        self.text_widget().text()
+    }
+
+    /// Inserts `text` at the cursor, replacing the selected text.
+    ///
+    /// Unlike [set_text](Self::set_text), the change can be undone by the user.
+    pub fn insert_text(&mut self, text: &str) {
+        self.text_widget_mut().insert_text(text);
+    }
+
+    /// Allows or disallows editing the text by the user. The text is editable by default.
+    pub fn set_editable(&mut self, editable: bool) {
+        self.text_widget_mut().set_editable(editable);
+        self.set_pseudo_class(PseudoClass::ReadOnly, !editable);
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.text_widget().is_editable()
+    }
+
+    /// Selects the text within the specified byte range and moves the cursor to the end of the range.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        let text = self.text_widget_mut();
+        text.set_select_opt(Some(Cursor::new(0, range.start)));
+        text.set_cursor(Cursor::new(0, range.end));
+    }
+
+    /// The callback is invoked when the text is changed by the user.
+    pub fn on_text_edited(&mut self, callback: Callback<String>) {
+        self.on_text_edited.push(callback);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.text_widget().can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.text_widget().can_redo()
+    }
+
+    /// Reverts the last edit made by the user.
+    pub fn undo(&mut self) {
+        let old_text = self.text();
+        self.text_widget_mut().undo();
+        self.notify_if_edited(old_text);
+    }
+
+    /// Reapplies the last edit reverted by [undo](Self::undo).
+    pub fn redo(&mut self) {
+        let old_text = self.text();
+        self.text_widget_mut().redo();
+        self.notify_if_edited(old_text);
+    }
+
+    /// Forgets all edits, so that they can't be undone.
+    pub fn clear_history(&mut self) {
+        self.text_widget_mut().clear_history();
+    }
+
+    fn notify_if_edited(&mut self, old_text: String) {
+        let text = self.text();
+        if text != old_text {
+            self.on_text_edited.invoke(text);
+        }
+    }
+
     fn adjust_scroll(&mut self, changed_size_hints: &[WidgetAddress]) {
         let Some(editor_viewport_rect) = self
             .base
@@ -173,6 +243,7 @@ impl Widget for TextInput {
         Self {
             style: get_style(base.style_element(), base.scale()),
             base,
+            on_text_edited: CallbackVec::new(),
         }
     }
 
@@ -192,6 +263,9 @@ impl Widget for TextInput {
 
     fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
         self.style = get_style(self.base().style_element(), self.base().scale());
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.base.size_hint_changed();
         Ok(())
     }
 
@@ -204,11 +278,17 @@ impl Widget for TextInput {
     }
 
     fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
-        self.text_widget_mut().handle_host_keyboard_input(event)
+        let old_text = self.text();
+        let accepted = self.text_widget_mut().handle_host_keyboard_input(event)?;
+        self.notify_if_edited(old_text);
+        Ok(accepted)
     }
 
     fn handle_input_method(&mut self, event: InputMethodEvent) -> Result<bool> {
-        self.text_widget_mut().handle_host_ime(event)
+        let old_text = self.text();
+        let accepted = self.text_widget_mut().handle_host_ime(event)?;
+        self.notify_if_edited(old_text);
+        Ok(accepted)
     }
 
     fn handle_scroll_to_rect_request(&mut self, event: ScrollToRectRequest) -> Result<bool> {
@@ -0,0 +1,361 @@
+use {
+    super::{button::Button, menu::Menu, Widget, WidgetBaseOf, WidgetExt, WidgetGeometry},
+    crate::{
+        draw::DrawEvent,
+        event::LayoutEvent,
+        impl_widget_base,
+        key::Key,
+        layout::SizeHints,
+        shortcut::KeyCombinations,
+        types::{LpxSuffix, PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::Role,
+    anyhow::Result,
+    std::cmp::max,
+};
+
+const OVERFLOW_BUTTON_KEY: &str = "overflow_button";
+const OVERFLOW_MENU_KEY: &str = "overflow_menu";
+
+/// A horizontal row of buttons, usually displayed at the top of a main window.
+///
+/// Tool buttons are regular [Button]s with the `tool_button` class. Use
+/// [Button::set_checkable] for toggle buttons and [Button::set_shortcut] to assign
+/// a shortcut to the action of a button.
+///
+/// If the tool bar is too narrow to fit all items, the items that don't fit are hidden and
+/// can be accessed from the overflow menu displayed at the end of the tool bar.
+pub struct ToolBar {
+    base: WidgetBaseOf<Self>,
+    num_items: usize,
+    // Items starting from this index are in the overflow menu.
+    num_visible_items: usize,
+}
+
+impl ToolBar {
+    pub fn add_button(&mut self) -> &mut Button {
+        let index = self.num_items;
+        self.num_items += 1;
+        self.base
+            .add_child_with_key::<Button>(index)
+            .add_class("tool_button".into())
+            .set_focusable(false)
+            // The column is not used for layout, but it determines the order
+            // of the items for `:first-child` and similar selectors.
+            .set_column(index as i32)
+            .set_row(0)
+    }
+
+    pub fn add_separator(&mut self) -> &mut ToolBarSeparator {
+        let index = self.num_items;
+        self.num_items += 1;
+        self.base
+            .add_child_with_key::<ToolBarSeparator>(index)
+            .set_column(index as i32)
+            .set_row(0)
+    }
+
+    /// Returns the button at `index`, or `None` if there is no button at `index`.
+    ///
+    /// Buttons and separators share the same index space.
+    pub fn button(&self, index: usize) -> Option<&Button> {
+        self.base.get_child::<Button>(index).ok()
+    }
+
+    pub fn button_mut(&mut self, index: usize) -> Option<&mut Button> {
+        self.base.get_child_mut::<Button>(index).ok()
+    }
+
+    /// Number of buttons and separators in the tool bar.
+    pub fn len(&self) -> usize {
+        self.num_items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
+    /// Removes all buttons and separators.
+    pub fn clear(&mut self) {
+        for index in 0..self.num_items {
+            let _ = self.base.remove_child(index);
+        }
+        self.num_items = 0;
+    }
+
+    fn overflow_menu_mut(&mut self) -> &mut Menu {
+        self.base.get_child_mut::<Menu>(OVERFLOW_MENU_KEY).unwrap()
+    }
+
+    fn is_item_visible(&self, index: usize) -> bool {
+        self.base
+            .get_dyn_child(index)
+            .is_ok_and(|item| item.base().is_self_visible())
+    }
+
+    fn open_overflow_menu(&mut self) -> Result<()> {
+        let Some(rect) = self
+            .base
+            .get_dyn_child(OVERFLOW_BUTTON_KEY)?
+            .base()
+            .rect_in_window()
+        else {
+            return Ok(());
+        };
+        let mut entries = Vec::new();
+        for index in self.num_visible_items..self.num_items {
+            if !self.is_item_visible(index) {
+                continue;
+            }
+            let entry = self.button(index).map(|button| OverflowEntry {
+                index,
+                text: button.text().to_string(),
+                is_enabled: button.base().is_self_enabled(),
+                is_checkable: button.is_checkable(),
+                is_checked: button.is_checked(),
+                shortcut: button.shortcut().cloned(),
+            });
+            entries.push(entry);
+        }
+        self.overflow_menu_mut().clear();
+        for entry in entries {
+            let Some(entry) = entry else {
+                self.overflow_menu_mut().add_separator();
+                continue;
+            };
+            let index = entry.index;
+            let callback = self.callback(move |this, _| {
+                if let Some(button) = this.button_mut(index) {
+                    button.trigger();
+                }
+                Ok(())
+            });
+            self.overflow_menu_mut()
+                .add_item()
+                .set_text(entry.text)
+                .set_checkable(entry.is_checkable)
+                .set_checked(entry.is_checked)
+                .set_shortcut_hint(entry.shortcut)
+                .on_triggered(callback)
+                .set_enabled(entry.is_enabled);
+        }
+        let menu = self.overflow_menu_mut();
+        menu.set_anchor_rect(rect);
+        menu.popup();
+        Ok(())
+    }
+}
+
+// A button hidden in the overflow menu.
+struct OverflowEntry {
+    index: usize,
+    text: String,
+    is_enabled: bool,
+    is_checkable: bool,
+    is_checked: bool,
+    shortcut: Option<KeyCombinations>,
+}
+
+impl Widget for ToolBar {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let open_overflow_menu = base.callback(|this, _| this.open_overflow_menu());
+        base.add_child_with_key::<Button>(OVERFLOW_BUTTON_KEY)
+            .set_text("»")
+            .add_class("tool_button".into())
+            .add_class("tool_bar_overflow".into())
+            .set_focusable(false)
+            .set_accessibility_node_enabled(false)
+            .on_triggered(open_overflow_menu);
+        base.add_child_with_key::<Menu>(OVERFLOW_MENU_KEY).close();
+        Self {
+            base,
+            num_items: 0,
+            num_visible_items: 0,
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let changed_size_hints = &event.changed_size_hints;
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed_size_hints);
+            }
+            return Ok(());
+        };
+        let grid = self.base.common_style.grid.clone();
+        let padding_x = grid.x.preferred_padding;
+        let padding_y = grid.y.preferred_padding;
+        let spacing = grid.x.preferred_spacing;
+        let size_y = max(0.ppx(), geometry.size_y() - padding_y.sum());
+
+        let mut widths = Vec::new();
+        for index in 0..self.num_items {
+            let width = if self.is_item_visible(index) {
+                Some(self.base.get_dyn_child_mut(index)?.size_hint_x().preferred)
+            } else {
+                None
+            };
+            widths.push(width);
+        }
+        let visible_widths = widths.iter().flatten().copied();
+        let total_size_x: PhysicalPixels = visible_widths.clone().sum::<PhysicalPixels>()
+            + spacing * (visible_widths.count().saturating_sub(1) as i32)
+            + padding_x.sum();
+
+        let overflow_button = self.base.get_dyn_child_mut(OVERFLOW_BUTTON_KEY)?;
+        let overflow_size_x = overflow_button.size_hint_x().preferred;
+        let mut num_visible_items = self.num_items;
+        if total_size_x > geometry.size_x() {
+            let available = geometry.size_x() - padding_x.sum() - overflow_size_x - spacing;
+            let mut pos = 0.ppx();
+            for (index, width) in widths.iter().enumerate() {
+                let Some(width) = width else {
+                    continue;
+                };
+                if pos + *width > available {
+                    num_visible_items = index;
+                    break;
+                }
+                pos += *width + spacing;
+            }
+        }
+        let overflow_rect = (num_visible_items < self.num_items).then(|| {
+            Rect::from_xywh(
+                geometry.size_x() - padding_x.end - overflow_size_x,
+                padding_y.start,
+                overflow_size_x,
+                size_y,
+            )
+        });
+        overflow_button.set_geometry(
+            overflow_rect.map(|rect| WidgetGeometry::new(&geometry, rect)),
+            changed_size_hints,
+        );
+
+        let mut pos = padding_x.start;
+        for (index, width) in widths.into_iter().enumerate() {
+            let rect = width.filter(|_| index < num_visible_items).map(|width| {
+                let rect = Rect::from_xywh(pos, padding_y.start, width, size_y);
+                pos += width + spacing;
+                rect
+            });
+            self.base.get_dyn_child_mut(index)?.set_geometry(
+                rect.map(|rect| WidgetGeometry::new(&geometry, rect)),
+                changed_size_hints,
+            );
+        }
+        self.num_visible_items = num_visible_items;
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.x;
+        let (min_padding, preferred_padding) = (grid.min_padding, grid.preferred_padding);
+        let spacing = grid.preferred_spacing;
+        let mut preferred = 0.ppx();
+        let mut num_visible = 0;
+        for index in 0..self.num_items {
+            if self.is_item_visible(index) {
+                preferred += self.base.get_dyn_child_mut(index)?.size_hint_x().preferred;
+                num_visible += 1;
+            }
+        }
+        preferred += spacing * (max(num_visible, 1) - 1) + preferred_padding.sum();
+        let overflow_size_x = self
+            .base
+            .get_dyn_child_mut(OVERFLOW_BUTTON_KEY)?
+            .size_hint_x()
+            .min;
+        Ok(SizeHints {
+            min: overflow_size_x + min_padding.sum(),
+            preferred,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.y;
+        let (min_padding, preferred_padding) = (grid.min_padding, grid.preferred_padding);
+        let mut min_size = 0.ppx();
+        let mut preferred_size = 0.ppx();
+        let keys = (0..self.num_items)
+            .filter(|index| self.is_item_visible(*index))
+            .map(Into::into)
+            .chain([OVERFLOW_BUTTON_KEY.into()])
+            .collect::<Vec<Key>>();
+        for key in keys {
+            let item = self.base.get_dyn_child_mut(key)?;
+            let size_x = item.size_hint_x().preferred;
+            let hints = item.size_hint_y(size_x);
+            min_size = max(min_size, hints.min);
+            preferred_size = max(preferred_size, hints.preferred);
+        }
+        Ok(SizeHints {
+            min: min_size + min_padding.sum(),
+            preferred: preferred_size + preferred_padding.sum(),
+            is_fixed: true,
+        })
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::Toolbar)))
+    }
+}
+
+/// A vertical line that separates groups of buttons in a [ToolBar].
+///
+/// Create separators with [ToolBar::add_separator].
+pub struct ToolBarSeparator {
+    base: WidgetBaseOf<Self>,
+}
+
+impl ToolBarSeparator {
+    fn line_width(&self) -> PhysicalPixels {
+        1.0.lpx().to_physical(self.base.scale())
+    }
+}
+
+impl Widget for ToolBarSeparator {
+    impl_widget_base!();
+
+    fn new(base: WidgetBaseOf<Self>) -> Self {
+        Self { base }
+    }
+
+    fn handle_draw(&mut self, event: DrawEvent) -> Result<()> {
+        let size = self.base.size_or_err()?;
+        let padding_y = self.base.common_style.grid.y.preferred_padding;
+        let line_width = self.line_width();
+        event.fill_rect(
+            Rect::from_xywh(
+                (size.x() - line_width) / 2,
+                padding_y.start,
+                line_width,
+                size.y() - padding_y.sum(),
+            ),
+            self.base.common_style.text_color,
+        );
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.x;
+        let line_width = self.line_width();
+        Ok(SizeHints {
+            min: grid.min_padding.sum() + line_width,
+            preferred: grid.preferred_padding.sum() + line_width,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        let grid = &self.base.common_style.grid.y;
+        Ok(SizeHints {
+            min: grid.min_padding.sum(),
+            preferred: grid.preferred_padding.sum(),
+            is_fixed: false,
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use {
+    super::{menu::popup_position, Widget, WidgetBaseOf, WidgetExt},
+    crate::{
+        event::LayoutEvent,
+        impl_widget_base,
+        layout::grid::grid_layout,
+        shared_window::{SharedWindow, X11WindowType},
+        text_editor::Text,
+        types::{Axis, LpxSuffix, PpxSuffix, Rect, Size},
+    },
+    accesskit::Role,
+    anyhow::Result,
+    cosmic_text::Attrs,
+    std::fmt::Display,
+    winit::window::WindowLevel,
+};
+
+// Vertical distance between the mouse pointer and the tooltip.
+const CURSOR_OFFSET: f32 = 20.0;
+
+/// A small popup window that displays a hint about the widget under the mouse pointer.
+///
+/// Tooltips are created automatically for widgets that have a tooltip text
+/// (see [WidgetBase::set_tooltip](super::WidgetBase::set_tooltip)). The popup is placed
+/// below the mouse pointer, or above it if there is not enough space on the screen.
+pub struct Tooltip {
+    base: WidgetBaseOf<Self>,
+    anchor_window: Option<SharedWindow>,
+    cursor_anchor: Option<Rect>,
+}
+
+impl Tooltip {
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(0).unwrap()
+    }
+
+    pub fn set_text(&mut self, text: impl Display) -> &mut Self {
+        self.text_widget_mut().set_text(text, Attrs::new());
+        self.base.size_hint_changed();
+        self.base.update();
+        self
+    }
+
+    fn capture_cursor(&mut self) {
+        let offset = CURSOR_OFFSET.lpx().to_physical(self.base.scale());
+        self.cursor_anchor = self
+            .anchor_window
+            .as_ref()
+            .and_then(|window| window.cursor_position())
+            .map(|pos| Rect::from_pos_size(pos, Size::new(0.ppx(), offset)));
+    }
+
+    fn update_position(&mut self) {
+        let (Some(anchor), Some(anchor_window)) = (self.cursor_anchor, &self.anchor_window) else {
+            return;
+        };
+        let Some(offset) = anchor_window.inner_position() else {
+            return;
+        };
+        let Some(size) = self.base.size() else {
+            return;
+        };
+        let position = popup_position(
+            anchor.translate(offset),
+            size,
+            anchor_window.monitor_rect(),
+            Axis::Y,
+        );
+        self.base
+            .window
+            .as_ref()
+            .unwrap()
+            .set_outer_position(position);
+    }
+}
+
+impl Widget for Tooltip {
+    impl_widget_base!();
+
+    fn is_window_root_type() -> bool {
+        true
+    }
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        let window = base.window.clone().unwrap();
+        window.set_decorations(false);
+        window.set_window_level(WindowLevel::AlwaysOnTop);
+        window.set_x11_window_type(vec![X11WindowType::Tooltip]);
+        window.set_skip_windows_taskbar(true);
+        window.set_active(false);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child::<Text>()
+            .set_column(0)
+            .set_row(0)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        let mut this = Self {
+            anchor_window: base.parent_window(),
+            base,
+            cursor_anchor: None,
+        };
+        this.capture_cursor();
+        this
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        grid_layout(self, &event.changed_size_hints);
+        self.update_position();
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        Ok(Some(accesskit::Node::new(Role::Tooltip)))
+    }
+}
+
+impl Drop for Tooltip {
+    fn drop(&mut self) {
+        self.base.window.as_ref().unwrap().deregister();
+    }
+}
@@ -0,0 +1,1194 @@
+use {
+    super::{
+        check_box::ComputedIndicatorStyle, image::Image, list_view::SelectionMode,
+        scroll_area::ScrollArea, Widget, WidgetAddress, WidgetBaseOf, WidgetExt, WidgetGeometry,
+    },
+    crate::{
+        callback::{Callback, CallbackVec, Callbacks},
+        event::{
+            AccessibilityActionEvent, FocusReason, KeyboardInputEvent, LayoutEvent,
+            MouseInputEvent, StyleChangeEvent,
+        },
+        impl_widget_base,
+        layout::SizeHints,
+        shared_window::{ScrollToRectRequest, SetFocusRequest},
+        shortcut::standard_shortcuts,
+        style::{
+            common::ComputedElementStyle,
+            css::{convert_font, convert_height, convert_width, Element, PseudoClass},
+            defaults::{
+                DEFAULT_MIN_HEIGHT_EM, DEFAULT_MIN_WIDTH_EM, DEFAULT_PREFERRED_HEIGHT_EM,
+                DEFAULT_PREFERRED_WIDTH_EM,
+            },
+            get_style, Style,
+        },
+        system::{send_window_request, ReportError},
+        text_editor::Text,
+        types::{PhysicalPixels, PpxSuffix, Rect},
+    },
+    accesskit::{Action, Role},
+    anyhow::Result,
+    cosmic_text::Attrs,
+    log::warn,
+    std::{
+        cmp::{max, min},
+        collections::BTreeSet,
+        rc::Rc,
+    },
+    widgem_macros::impl_with,
+    winit::{
+        event::MouseButton,
+        keyboard::{Key as KeyboardKey, ModifiersState, NamedKey},
+    },
+};
+
+/// Source of the nodes displayed by a [TreeView].
+///
+/// Nodes are identified by paths: indexes of the node and its ancestors among their siblings,
+/// starting from the top level. The empty path refers to the invisible root node.
+///
+/// The tree view only requests children of expanded nodes, so the model can load them lazily
+/// when [child_count](Self::child_count) is called for the first time.
+/// When the data changes, the model must notify the tree view using
+/// [TreeView::handle_model_change] or the callback returned by [TreeView::model_change_callback].
+pub trait TreeModel {
+    fn child_count(&self, path: &[usize]) -> usize;
+    fn data(&self, path: &[usize]) -> String;
+
+    /// Returns `true` if the node has or may have children.
+    ///
+    /// It's used to display the branch indicator of collapsed nodes. Implement it if
+    /// calculating [child_count](Self::child_count) is expensive.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.child_count(path) > 0
+    }
+}
+
+/// Description of a change in a [TreeModel].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeModelChange {
+    /// Any part of the model could have changed.
+    Reset,
+    /// Children of the node at the path were added, removed or reordered.
+    ///
+    /// Expanded and selected state of the descendants of the node is discarded.
+    ChildrenChanged(Vec<usize>),
+    /// Data of the node at the path has changed.
+    DataChanged(Vec<usize>),
+}
+
+const SCROLL_AREA_KEY: u64 = 0;
+
+/// A scrollable tree of expandable text nodes backed by a [TreeModel].
+///
+/// Widgets are only created for the nodes that are visible, and they are reused
+/// for other nodes when the tree is scrolled or expanded.
+pub struct TreeView {
+    base: WidgetBaseOf<Self>,
+    style: Rc<TreeViewStyle>,
+}
+
+#[impl_with]
+impl TreeView {
+    fn body(&self) -> &TreeBody {
+        self.base
+            .get_child::<ScrollArea>(SCROLL_AREA_KEY)
+            .unwrap()
+            .content::<TreeBody>()
+            .unwrap()
+    }
+
+    fn body_mut(&mut self) -> &mut TreeBody {
+        self.base
+            .get_child_mut::<ScrollArea>(SCROLL_AREA_KEY)
+            .unwrap()
+            .content_mut::<TreeBody>()
+            .unwrap()
+    }
+
+    pub fn set_model(&mut self, model: Rc<dyn TreeModel>) -> &mut Self {
+        self.body_mut().model = Some(model);
+        self.body_mut().handle_model_change(TreeModelChange::Reset);
+        self
+    }
+
+    pub fn model(&self) -> Option<&Rc<dyn TreeModel>> {
+        self.body().model.as_ref()
+    }
+
+    /// Updates the tree view after the model has changed.
+    pub fn handle_model_change(&mut self, change: TreeModelChange) {
+        self.body_mut().handle_model_change(change);
+    }
+
+    /// Returns a callback that calls [handle_model_change](Self::handle_model_change)
+    /// on this tree view. It can be stored in the model.
+    pub fn model_change_callback(&self) -> Callback<TreeModelChange> {
+        self.callback(|this, change| {
+            this.handle_model_change(change);
+            Ok(())
+        })
+    }
+
+    /// Shows or hides children of the node at `path`.
+    pub fn set_expanded(&mut self, path: &[usize], expanded: bool) -> &mut Self {
+        self.body_mut().set_expanded(path, expanded, true);
+        self
+    }
+
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.body().expanded.contains(path)
+    }
+
+    pub fn expand(&mut self, path: &[usize]) -> &mut Self {
+        self.set_expanded(path, true)
+    }
+
+    pub fn collapse(&mut self, path: &[usize]) -> &mut Self {
+        self.set_expanded(path, false)
+    }
+
+    /// Returns the number of nodes that are currently displayed, i.e. top level nodes
+    /// and descendants of expanded nodes.
+    pub fn visible_node_count(&self) -> usize {
+        self.body().nodes.len()
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) -> &mut Self {
+        let body = self.body_mut();
+        body.selection_mode = mode;
+        let new_selection = match mode {
+            SelectionMode::None => BTreeSet::new(),
+            SelectionMode::Single => body.selected.first().cloned().into_iter().collect(),
+            SelectionMode::Multi => body.selected.clone(),
+        };
+        body.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.body().selection_mode
+    }
+
+    /// Sets the node that has keyboard focus within the tree and scrolls to it.
+    ///
+    /// Ancestors of the node are expanded. Selection is not changed.
+    pub fn set_current(&mut self, path: Option<&[usize]>) -> &mut Self {
+        let body = self.body_mut();
+        if let Some(path) = path {
+            for len in 1..path.len() {
+                body.set_expanded(&path[..len], true, true);
+            }
+        }
+        body.set_current(path.map(|path| path.to_vec()), true);
+        self
+    }
+
+    pub fn current(&self) -> Option<&[usize]> {
+        self.body().current.as_deref()
+    }
+
+    pub fn selected(&self) -> &BTreeSet<Vec<usize>> {
+        &self.body().selected
+    }
+
+    pub fn is_selected(&self, path: &[usize]) -> bool {
+        self.body().selected.contains(path)
+    }
+
+    pub fn set_selected(&mut self, path: &[usize], selected: bool) -> &mut Self {
+        let body = self.body_mut();
+        let mut new_selection = match body.selection_mode {
+            SelectionMode::None => {
+                warn!("cannot select nodes when selection mode is None");
+                return self;
+            }
+            SelectionMode::Single => BTreeSet::new(),
+            SelectionMode::Multi => body.selected.clone(),
+        };
+        if selected {
+            new_selection.insert(path.to_vec());
+        } else {
+            new_selection.remove(path);
+        }
+        body.set_selection(new_selection, true);
+        self
+    }
+
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.body_mut().set_selection(BTreeSet::new(), true);
+        self
+    }
+
+    /// Selects all visible nodes. Has no effect unless selection mode is
+    /// [Multi](SelectionMode::Multi).
+    pub fn select_all(&mut self) -> &mut Self {
+        self.body_mut().select_all(true);
+        self
+    }
+
+    pub fn on_current_changed(&mut self, callback: Callback<Option<Vec<usize>>>) -> &mut Self {
+        self.body_mut().current_changed.add(callback);
+        self
+    }
+
+    pub fn on_selection_changed(&mut self, callback: Callback<()>) -> &mut Self {
+        self.body_mut().selection_changed.add(callback);
+        self
+    }
+
+    /// Called with the path of the node and the new state when a node is expanded or collapsed.
+    pub fn on_expanded_changed(&mut self, callback: Callback<(Vec<usize>, bool)>) -> &mut Self {
+        self.body_mut().expanded_changed.add(callback);
+        self
+    }
+
+    /// Called when a node is double-clicked or Enter is pressed on the current node.
+    pub fn on_activated(&mut self, callback: Callback<Vec<usize>>) -> &mut Self {
+        self.body_mut().activated.push(callback);
+        self
+    }
+}
+
+impl Widget for TreeView {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<ScrollArea>(SCROLL_AREA_KEY)
+            .set_column(0)
+            .set_row(0)
+            .set_content::<TreeBody>();
+        Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+        }
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.style = get_style(self.base().style_element(), self.base().scale());
+        self.base.size_hint_changed();
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_width,
+            preferred: self.style.preferred_width,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.style.min_height,
+            preferred: self.style.preferred_height,
+            is_fixed: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TreeViewStyle {
+    pub min_width: PhysicalPixels,
+    pub preferred_width: PhysicalPixels,
+    pub min_height: PhysicalPixels,
+    pub preferred_height: PhysicalPixels,
+}
+
+impl ComputedElementStyle for TreeViewStyle {
+    fn new(style: &Style, element: &Element, scale: f32) -> TreeViewStyle {
+        let element_min = element
+            .clone()
+            .with_pseudo_class(PseudoClass::Custom("min".into()));
+
+        let properties = style.find_rules(|s| element.matches(s));
+        let font = convert_font(&properties, Some(&style.root_font_style()));
+        let preferred_width = convert_width(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in tree view css");
+                (font.font_size * DEFAULT_PREFERRED_WIDTH_EM).to_physical(scale)
+            });
+        let preferred_height = convert_height(&properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in tree view css");
+                (font.font_size * DEFAULT_PREFERRED_HEIGHT_EM).to_physical(scale)
+            });
+
+        let min_properties = style.find_rules(|s| element_min.matches(s));
+        let min_width = convert_width(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing width in tree view min css");
+                (font.font_size * DEFAULT_MIN_WIDTH_EM).to_physical(scale)
+            });
+        let min_height = convert_height(&min_properties, scale, font.font_size)
+            .or_report_err()
+            .flatten()
+            .unwrap_or_else(|| {
+                warn!("missing height in tree view min css");
+                (font.font_size * DEFAULT_MIN_HEIGHT_EM).to_physical(scale)
+            });
+
+        Self {
+            min_width,
+            preferred_width,
+            min_height,
+            preferred_height,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VisibleNode {
+    path: Vec<usize>,
+    has_children: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeAction {
+    Select,
+    Expand,
+    Collapse,
+}
+
+/// Content of the scroll area of a [TreeView].
+///
+/// Rows are created only for the visible area of the body.
+struct TreeBody {
+    base: WidgetBaseOf<Self>,
+    model: Option<Rc<dyn TreeModel>>,
+    expanded: BTreeSet<Vec<usize>>,
+    // Displayed nodes in depth-first order. This is also the lexicographic order of the paths.
+    nodes: Vec<VisibleNode>,
+    selection_mode: SelectionMode,
+    current: Option<Vec<usize>>,
+    selected: BTreeSet<Vec<usize>>,
+    // Starting node for range selection with Shift.
+    anchor: Option<Vec<usize>>,
+    row_height: PhysicalPixels,
+    // Width of the branch indicator. Each level of the tree is indented by this amount.
+    indent: PhysicalPixels,
+    // Maximal preferred width of the displayed rows.
+    content_width: PhysicalPixels,
+    // Incremented on each model change to refresh the displayed data.
+    generation: u64,
+    current_changed: Callbacks<Option<Vec<usize>>>,
+    selection_changed: Callbacks<()>,
+    expanded_changed: Callbacks<(Vec<usize>, bool)>,
+    activated: CallbackVec<Vec<usize>>,
+}
+
+impl TreeBody {
+    fn slot_mut(&mut self, slot: usize) -> Result<&mut TreeRow> {
+        self.base.get_child_mut::<TreeRow>(slot)
+    }
+
+    fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.nodes
+            .binary_search_by(|node| node.path.as_slice().cmp(path))
+            .ok()
+    }
+
+    fn handle_model_change(&mut self, change: TreeModelChange) {
+        let old_current = self.current.clone();
+        let old_selection = self.selected.clone();
+        match change {
+            TreeModelChange::Reset => {
+                self.expanded.clear();
+                self.current = None;
+                self.anchor = None;
+                self.selected.clear();
+                self.content_width = 0.ppx();
+            }
+            TreeModelChange::ChildrenChanged(path) => {
+                let is_descendant =
+                    |other: &Vec<usize>| other.len() > path.len() && other.starts_with(&path);
+                self.expanded.retain(|other| !is_descendant(other));
+                self.selected.retain(|other| !is_descendant(other));
+                if self.current.as_ref().is_some_and(is_descendant) {
+                    self.current = (!path.is_empty()).then(|| path.clone());
+                }
+                if self.anchor.as_ref().is_some_and(is_descendant) {
+                    self.anchor = None;
+                }
+            }
+            TreeModelChange::DataChanged(_) => {}
+        }
+        self.generation += 1;
+        self.rebuild_nodes();
+        if self.current != old_current {
+            self.current_changed.invoke(self.current.clone(), true);
+        }
+        if self.selected != old_selection {
+            self.selection_changed.invoke((), true);
+        }
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    fn rebuild_nodes(&mut self) {
+        self.nodes.clear();
+        let Some(model) = self.model.clone() else {
+            return;
+        };
+        let mut nodes = Vec::new();
+        append_visible_children(model.as_ref(), &self.expanded, &mut Vec::new(), &mut nodes);
+        self.nodes = nodes;
+    }
+
+    fn set_expanded(&mut self, path: &[usize], expanded: bool, from_setter: bool) {
+        if path.is_empty() {
+            warn!("cannot change expanded state of the root node");
+            return;
+        }
+        if self.expanded.contains(path) == expanded {
+            return;
+        }
+        if expanded {
+            self.expanded.insert(path.to_vec());
+        } else {
+            self.expanded.remove(path);
+            // Descendants of a collapsed node can't be current or selected.
+            let is_descendant =
+                |other: &Vec<usize>| other.len() > path.len() && other.starts_with(path);
+            if self.current.as_ref().is_some_and(is_descendant) {
+                self.set_current(Some(path.to_vec()), from_setter);
+            }
+            if self.selected.iter().any(is_descendant) {
+                let mut new_selection = self.selected.clone();
+                new_selection.retain(|other| !is_descendant(other));
+                self.set_selection(new_selection, from_setter);
+            }
+        }
+        self.rebuild_nodes();
+        self.expanded_changed
+            .invoke((path.to_vec(), expanded), from_setter);
+        self.base.size_hint_changed();
+        self.base.update();
+    }
+
+    fn toggle_expanded(&mut self, row: usize) {
+        let node = &self.nodes[row];
+        if !node.has_children {
+            return;
+        }
+        let path = node.path.clone();
+        let expanded = !self.expanded.contains(&path);
+        self.set_expanded(&path, expanded, false);
+    }
+
+    fn set_current(&mut self, path: Option<Vec<usize>>, from_setter: bool) {
+        if let Some(path) = &path {
+            let Some(row) = self.row_of(path) else {
+                warn!("node is not visible or doesn't exist");
+                return;
+            };
+            self.scroll_to_row(row);
+        }
+        if self.current == path {
+            return;
+        }
+        self.current = path.clone();
+        self.current_changed.invoke(path, from_setter);
+        self.update_slots();
+    }
+
+    fn set_selection(&mut self, selection: BTreeSet<Vec<usize>>, from_setter: bool) {
+        if self.selected == selection {
+            return;
+        }
+        self.selected = selection;
+        self.selection_changed.invoke((), from_setter);
+        self.update_slots();
+    }
+
+    fn select_all(&mut self, from_setter: bool) {
+        if self.selection_mode != SelectionMode::Multi {
+            return;
+        }
+        let selection = self.nodes.iter().map(|node| node.path.clone()).collect();
+        self.set_selection(selection, from_setter);
+    }
+
+    // Changes current node and selection in response to a mouse click or a key press.
+    fn select_by_user(&mut self, row: usize, modifiers: ModifiersState, toggle_with_ctrl: bool) {
+        let path = self.nodes[row].path.clone();
+        let shift = modifiers.shift_key();
+        let ctrl = modifiers.control_key() || modifiers.super_key();
+        match self.selection_mode {
+            SelectionMode::None => {}
+            SelectionMode::Single => {
+                self.set_selection([path.clone()].into(), false);
+            }
+            SelectionMode::Multi => {
+                if shift {
+                    let anchor_row = self
+                        .anchor
+                        .as_ref()
+                        .and_then(|anchor| self.row_of(anchor))
+                        .unwrap_or(row);
+                    let mut new_selection = if ctrl {
+                        self.selected.clone()
+                    } else {
+                        BTreeSet::new()
+                    };
+                    new_selection.extend(
+                        self.nodes[min(anchor_row, row)..=max(anchor_row, row)]
+                            .iter()
+                            .map(|node| node.path.clone()),
+                    );
+                    self.anchor = Some(self.nodes[anchor_row].path.clone());
+                    self.set_selection(new_selection, false);
+                } else if ctrl {
+                    if toggle_with_ctrl {
+                        self.toggle_selected(&path);
+                    }
+                    self.anchor = Some(path.clone());
+                } else {
+                    self.anchor = Some(path.clone());
+                    self.set_selection([path.clone()].into(), false);
+                }
+            }
+        }
+        self.set_current(Some(path), false);
+    }
+
+    fn toggle_selected(&mut self, path: &[usize]) {
+        let mut new_selection = self.selected.clone();
+        if !new_selection.remove(path) {
+            new_selection.insert(path.to_vec());
+        }
+        self.set_selection(new_selection, false);
+    }
+
+    fn handle_node_action(&mut self, (path, action): (Vec<usize>, NodeAction)) {
+        let Some(row) = self.row_of(&path) else {
+            return;
+        };
+        match action {
+            NodeAction::Select => self.select_by_user(row, ModifiersState::empty(), false),
+            NodeAction::Expand => self.set_expanded(&path, true, false),
+            NodeAction::Collapse => self.set_expanded(&path, false, false),
+        }
+    }
+
+    fn scroll_to_row(&mut self, row: usize) {
+        let Some(window_id) = self.base.window_id() else {
+            return;
+        };
+        let rect = Rect::from_xywh(
+            0.ppx(),
+            self.row_height * row as i32,
+            max(1.ppx(), self.base.size().map_or(0.ppx(), |size| size.x())),
+            self.row_height,
+        );
+        send_window_request(
+            window_id,
+            ScrollToRectRequest {
+                widget_id: self.base.id().into(),
+                rect,
+            },
+        );
+    }
+
+    fn row_at(&self, y: PhysicalPixels) -> Option<usize> {
+        let row = (max(0, y.to_i32()) / max(1, self.row_height.to_i32())) as usize;
+        (row < self.nodes.len()).then_some(row)
+    }
+
+    fn rows_per_page(&self) -> usize {
+        let height = self
+            .base
+            .visible_rect()
+            .map_or(0, |rect| rect.size_y().to_i32());
+        max(1, height / max(1, self.row_height.to_i32())) as usize
+    }
+
+    fn focus(&mut self) -> Result<()> {
+        if !self.base.is_focused() && self.base.is_focusable() {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    // Measures row height and indentation, creating the first slot if necessary.
+    fn measure_rows(&mut self) {
+        if !self.base.has_child(0u64) {
+            self.set_num_slots(1);
+        }
+        let slot = self.slot_mut(0).unwrap();
+        let indent = slot.branch_width();
+        let size_x = slot.size_hint_x().preferred;
+        let row_height = max(1.ppx(), slot.size_hint_y(size_x).preferred);
+        self.indent = indent;
+        self.row_height = row_height;
+    }
+
+    fn set_num_slots(&mut self, count: usize) {
+        let on_action = self.callback(|this, action| {
+            this.handle_node_action(action);
+            Ok(())
+        });
+        let num_existing = self.base.children.len();
+        for slot in count..num_existing {
+            self.base.remove_child(slot).or_report_err();
+        }
+        for slot in num_existing..count {
+            self.base
+                .add_child_with_key::<TreeRow>(slot)
+                .on_action
+                .push(on_action.clone());
+        }
+    }
+
+    fn layout_rows(&mut self, changed_size_hints: &[WidgetAddress]) -> Result<()> {
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed_size_hints);
+            }
+            return Ok(());
+        };
+        let size = geometry.size();
+        let visible_rect = geometry.visible_rect_in_self();
+        self.measure_rows();
+        let row_height = self.row_height.to_i32();
+        let row_count = self.nodes.len();
+
+        // Each visible row is displayed by the slot `row % num_slots`, so only the slots
+        // of the rows that became visible need to be updated when the tree is scrolled.
+        let num_slots = if visible_rect.is_empty() {
+            0
+        } else {
+            min(
+                row_count,
+                (visible_rect.size_y().to_i32() / row_height) as usize + 2,
+            )
+        };
+        let first_row = (max(0, visible_rect.top().to_i32()) / row_height) as usize;
+        let visible_rows = first_row..min(row_count, first_row + num_slots);
+
+        self.set_num_slots(max(1, num_slots));
+        let mut used_slots = vec![false; max(1, num_slots)];
+        let model = self.model.clone();
+        let generation = self.generation;
+        let mut content_width = self.content_width;
+        for row in visible_rows {
+            let slot = row % num_slots;
+            used_slots[slot] = true;
+            let node = self.nodes[row].clone();
+            let is_expanded = self.expanded.contains(&node.path);
+            let is_selected = self.selected.contains(&node.path);
+            let is_current = self.current.as_ref() == Some(&node.path);
+            let rect = Rect::from_xywh(
+                0.ppx(),
+                self.row_height * row as i32,
+                size.x(),
+                self.row_height,
+            );
+            let tree_row = self.slot_mut(slot)?;
+            if let Some(model) = &model {
+                tree_row.set_node(&node, is_expanded, generation, model.as_ref());
+            }
+            tree_row
+                .set_selected(is_selected)
+                .set_current(is_current)
+                .set_visible(true);
+            content_width = max(content_width, tree_row.size_hint_x().preferred);
+            tree_row.set_geometry(
+                Some(WidgetGeometry::new(&geometry, rect)),
+                changed_size_hints,
+            );
+        }
+        for (slot, is_used) in used_slots.into_iter().enumerate() {
+            if !is_used {
+                let tree_row = self.slot_mut(slot)?;
+                tree_row.set_visible(false);
+                tree_row.set_geometry(None, changed_size_hints);
+            }
+        }
+        if content_width != self.content_width {
+            self.content_width = content_width;
+            self.base.size_hint_changed();
+        }
+        Ok(())
+    }
+
+    // Updates selected and current state of the displayed rows.
+    fn update_slots(&mut self) {
+        let selected = self.selected.clone();
+        let current = self.current.clone();
+        for slot in self.base.children.values_mut() {
+            let Some(tree_row) = slot.downcast_mut::<TreeRow>() else {
+                continue;
+            };
+            let Some(path) = tree_row.path.clone() else {
+                continue;
+            };
+            tree_row
+                .set_selected(selected.contains(&path))
+                .set_current(current.as_ref() == Some(&path));
+        }
+        self.base.update();
+    }
+}
+
+fn append_visible_children(
+    model: &dyn TreeModel,
+    expanded: &BTreeSet<Vec<usize>>,
+    path: &mut Vec<usize>,
+    nodes: &mut Vec<VisibleNode>,
+) {
+    for index in 0..model.child_count(path) {
+        path.push(index);
+        let has_children = model.has_children(path);
+        nodes.push(VisibleNode {
+            path: path.clone(),
+            has_children,
+        });
+        if has_children && expanded.contains(path) {
+            append_visible_children(model, expanded, path, nodes);
+        }
+        path.pop();
+    }
+}
+
+impl Widget for TreeBody {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.set_supports_focus(true);
+        Self {
+            base,
+            model: None,
+            expanded: BTreeSet::new(),
+            nodes: Vec::new(),
+            selection_mode: SelectionMode::default(),
+            current: None,
+            selected: BTreeSet::new(),
+            anchor: None,
+            row_height: 1.ppx(),
+            indent: 0.ppx(),
+            content_width: 0.ppx(),
+            generation: 0,
+            current_changed: Callbacks::default(),
+            selection_changed: Callbacks::default(),
+            expanded_changed: Callbacks::default(),
+            activated: CallbackVec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        self.layout_rows(&event.changed_size_hints)
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        Ok(SizeHints {
+            min: self.content_width,
+            preferred: self.content_width,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, _size_x: PhysicalPixels) -> Result<SizeHints> {
+        self.measure_rows();
+        let row_count: i32 = self.nodes.len().try_into().unwrap_or(i32::MAX);
+        let size = PhysicalPixels::from_i32(self.row_height.to_i32().saturating_mul(row_count));
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_mouse_input(&mut self, event: MouseInputEvent) -> Result<bool> {
+        if !self.base.is_enabled() {
+            return Ok(true);
+        }
+        if event.button != MouseButton::Left || !event.state.is_pressed() {
+            return Ok(true);
+        }
+        self.focus()?;
+        let Some(row) = self.row_at(event.pos.y()) else {
+            return Ok(true);
+        };
+        let branch_start = self.indent * (self.nodes[row].path.len() as i32 - 1);
+        if event.pos.x() >= branch_start && event.pos.x() < branch_start + self.indent {
+            self.toggle_expanded(row);
+            return Ok(true);
+        }
+        if event.num_clicks >= 2 {
+            self.toggle_expanded(row);
+            self.activated.invoke(self.nodes[row].path.clone());
+            return Ok(true);
+        }
+        let modifiers = self.base.window_or_err()?.modifiers();
+        self.select_by_user(row, modifiers, true);
+        Ok(true)
+    }
+
+    fn handle_keyboard_input(&mut self, event: KeyboardInputEvent) -> Result<bool> {
+        if !event.info.state.is_pressed() || !self.base.is_enabled() {
+            return Ok(false);
+        }
+        if standard_shortcuts().select_all.matches(&event) {
+            self.select_all(false);
+            return Ok(true);
+        }
+        let row_count = self.nodes.len();
+        if row_count == 0 {
+            return Ok(false);
+        }
+        let current = self.current.as_ref().and_then(|path| self.row_of(path));
+        let last = row_count - 1;
+        let new_row = match &event.info.logical_key {
+            KeyboardKey::Named(NamedKey::ArrowUp) => current.map_or(0, |row| row.saturating_sub(1)),
+            KeyboardKey::Named(NamedKey::ArrowDown) => current.map_or(0, |row| min(row + 1, last)),
+            KeyboardKey::Named(NamedKey::PageUp) => {
+                current.map_or(0, |row| row.saturating_sub(self.rows_per_page()))
+            }
+            KeyboardKey::Named(NamedKey::PageDown) => {
+                current.map_or(0, |row| min(row + self.rows_per_page(), last))
+            }
+            KeyboardKey::Named(NamedKey::Home) => 0,
+            KeyboardKey::Named(NamedKey::End) => last,
+            KeyboardKey::Named(NamedKey::ArrowRight) => {
+                let Some(row) = current else {
+                    return Ok(true);
+                };
+                let node = self.nodes[row].clone();
+                if !node.has_children {
+                    return Ok(true);
+                }
+                if !self.expanded.contains(&node.path) {
+                    self.set_expanded(&node.path, true, false);
+                    return Ok(true);
+                }
+                // Move to the first child.
+                if row + 1 < row_count && self.nodes[row + 1].path.starts_with(&node.path) {
+                    row + 1
+                } else {
+                    return Ok(true);
+                }
+            }
+            KeyboardKey::Named(NamedKey::ArrowLeft) => {
+                let Some(row) = current else {
+                    return Ok(true);
+                };
+                let node = self.nodes[row].clone();
+                if self.expanded.contains(&node.path) {
+                    self.set_expanded(&node.path, false, false);
+                    return Ok(true);
+                }
+                // Move to the parent.
+                match self.row_of(&node.path[..node.path.len() - 1]) {
+                    Some(parent_row) => parent_row,
+                    None => return Ok(true),
+                }
+            }
+            KeyboardKey::Named(NamedKey::Space) => {
+                if let Some(row) = current {
+                    if self.selection_mode == SelectionMode::Multi {
+                        let path = self.nodes[row].path.clone();
+                        self.toggle_selected(&path);
+                        self.anchor = Some(path);
+                    } else {
+                        self.select_by_user(row, ModifiersState::empty(), false);
+                    }
+                }
+                return Ok(true);
+            }
+            KeyboardKey::Named(NamedKey::Enter) => {
+                if let Some(path) = self.current.clone() {
+                    self.activated.invoke(path);
+                }
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+        self.select_by_user(new_row, event.modifiers, false);
+        Ok(true)
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        if event.action == Action::Focus {
+            send_window_request(
+                self.base.window_or_err()?.id(),
+                SetFocusRequest {
+                    widget_id: self.base.id().into(),
+                    // TODO: separate reason?
+                    reason: FocusReason::Mouse,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let mut node = accesskit::Node::new(Role::Tree);
+        if self.selection_mode == SelectionMode::Multi {
+            node.set_multiselectable();
+        }
+        if !self.base.is_enabled() {
+            node.set_disabled();
+        }
+        node.add_action(Action::Focus);
+        Ok(Some(node))
+    }
+}
+
+const BRANCH_KEY: u64 = 0;
+const TEXT_KEY: u64 = 1;
+
+/// Widget that displays one node of a [TreeView]. It's reused for different nodes on scroll.
+struct TreeRow {
+    base: WidgetBaseOf<Self>,
+    path: Option<Vec<usize>>,
+    sibling_count: usize,
+    has_children: bool,
+    is_expanded: bool,
+    is_selected: bool,
+    generation: u64,
+    data: String,
+    on_action: CallbackVec<(Vec<usize>, NodeAction)>,
+}
+
+impl TreeRow {
+    fn branch_mut(&mut self) -> &mut TreeBranch {
+        self.base.get_child_mut::<TreeBranch>(BRANCH_KEY).unwrap()
+    }
+
+    fn text_widget_mut(&mut self) -> &mut Text {
+        self.base.get_child_mut::<Text>(TEXT_KEY).unwrap()
+    }
+
+    fn branch_width(&mut self) -> PhysicalPixels {
+        self.branch_mut().size_hint_x().preferred
+    }
+
+    fn level(&self) -> usize {
+        self.path.as_ref().map_or(1, |path| path.len())
+    }
+
+    fn set_node(
+        &mut self,
+        node: &VisibleNode,
+        is_expanded: bool,
+        generation: u64,
+        model: &dyn TreeModel,
+    ) -> &mut Self {
+        if self.path.as_ref() == Some(&node.path)
+            && self.generation == generation
+            && self.is_expanded == is_expanded
+        {
+            return self;
+        }
+        let data = model.data(&node.path);
+        if self.data != data {
+            self.text_widget_mut().set_text(&data, Attrs::new());
+            self.data = data;
+        }
+        let parent = &node.path[..node.path.len() - 1];
+        self.sibling_count = model.child_count(parent);
+        if self.level() != node.path.len() {
+            self.base.size_hint_changed();
+        }
+        self.path = Some(node.path.clone());
+        self.has_children = node.has_children;
+        self.is_expanded = is_expanded;
+        self.generation = generation;
+        self.branch_mut()
+            .set_pseudo_class(PseudoClass::Custom("leaf".into()), !node.has_children)
+            .set_pseudo_class(PseudoClass::Custom("expanded".into()), is_expanded);
+        self.set_pseudo_class(PseudoClass::Custom("expanded".into()), is_expanded);
+        self.base.update();
+        self
+    }
+
+    fn set_selected(&mut self, value: bool) -> &mut Self {
+        if self.is_selected != value {
+            self.is_selected = value;
+            self.base.update();
+        }
+        self.set_pseudo_class(PseudoClass::Custom("selected".into()), value);
+        self
+    }
+
+    fn set_current(&mut self, value: bool) -> &mut Self {
+        self.set_pseudo_class(PseudoClass::Custom("current".into()), value);
+        self
+    }
+}
+
+impl Widget for TreeRow {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<TreeBranch>(BRANCH_KEY);
+        let id = base.id().raw();
+        let element = base.style_element().clone();
+        base.add_child_with_key::<Text>(TEXT_KEY)
+            .set_host_id(id)
+            .set_host_style_element(element);
+        Self {
+            base,
+            path: None,
+            sibling_count: 0,
+            has_children: false,
+            is_expanded: false,
+            is_selected: false,
+            generation: 0,
+            data: String::new(),
+            on_action: CallbackVec::new(),
+        }
+    }
+
+    fn handle_layout(&mut self, event: LayoutEvent) -> Result<()> {
+        let changed = &event.changed_size_hints;
+        let Some(geometry) = self.base.geometry.clone() else {
+            for child in self.base.children.values_mut() {
+                child.set_geometry(None, changed);
+            }
+            return Ok(());
+        };
+        let size = geometry.size();
+        let indent = self.branch_width();
+        let branch_x = indent * (self.level() as i32 - 1);
+        let text_x = branch_x + indent;
+        self.branch_mut().set_geometry(
+            Some(WidgetGeometry::new(
+                &geometry,
+                Rect::from_xywh(branch_x, 0.ppx(), indent, size.y()),
+            )),
+            changed,
+        );
+        self.text_widget_mut().set_geometry(
+            Some(WidgetGeometry::new(
+                &geometry,
+                Rect::from_xywh(text_x, 0.ppx(), max(0.ppx(), size.x() - text_x), size.y()),
+            )),
+            changed,
+        );
+        Ok(())
+    }
+
+    fn handle_size_hint_x_request(&mut self) -> Result<SizeHints> {
+        let indent = self.branch_width();
+        let text_hints = self.text_widget_mut().size_hint_x();
+        let size = indent * self.level() as i32 + text_hints.preferred;
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: false,
+        })
+    }
+
+    fn handle_size_hint_y_request(&mut self, size_x: PhysicalPixels) -> Result<SizeHints> {
+        let branch = self.branch_mut();
+        let branch_width = branch.size_hint_x().preferred;
+        let branch_height = branch.size_hint_y(branch_width).preferred;
+        let text_height = self.text_widget_mut().size_hint_y(size_x).preferred;
+        let size = max(branch_height, text_height);
+        Ok(SizeHints {
+            min: size,
+            preferred: size,
+            is_fixed: true,
+        })
+    }
+
+    fn handle_accessibility_action(&mut self, event: AccessibilityActionEvent) -> Result<()> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        let action = match event.action {
+            Action::Click => NodeAction::Select,
+            Action::Expand => NodeAction::Expand,
+            Action::Collapse => NodeAction::Collapse,
+            _ => return Ok(()),
+        };
+        self.on_action.invoke((path, action));
+        Ok(())
+    }
+
+    fn handle_accessibility_node_request(&mut self) -> Result<Option<accesskit::Node>> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        let mut node = accesskit::Node::new(Role::TreeItem);
+        node.set_label(self.data.clone());
+        node.set_level(path.len());
+        node.set_position_in_set(path.last().copied().unwrap_or_default() + 1);
+        node.set_size_of_set(self.sibling_count);
+        node.set_selected(self.is_selected);
+        node.add_action(Action::Click);
+        if self.has_children {
+            node.set_expanded(self.is_expanded);
+            node.add_action(if self.is_expanded {
+                Action::Collapse
+            } else {
+                Action::Expand
+            });
+        }
+        Ok(Some(node))
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        let element = self.base.style_element().clone();
+        self.text_widget_mut().set_host_style_element(element);
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
+
+/// Indicator of the expanded state of a tree node. The image is specified by the `content`
+/// property in CSS.
+struct TreeBranch {
+    base: WidgetBaseOf<Self>,
+    style: Rc<ComputedIndicatorStyle>,
+}
+
+impl TreeBranch {
+    fn refresh_style(&mut self) {
+        self.style = get_style(self.base.style_element(), self.base.scale());
+        let icon = self.style.icon.clone();
+        let image = self.base.get_child_mut::<Image>(0).unwrap();
+        image.set_visible(icon.is_some());
+        image.set_prescaled(true);
+        image.set_pixmap(icon);
+    }
+}
+
+impl Widget for TreeBranch {
+    impl_widget_base!();
+
+    fn new(mut base: WidgetBaseOf<Self>) -> Self {
+        base.add_child_with_key::<Image>(0)
+            .set_column(0)
+            .set_row(0)
+            .set_visible(false);
+        let mut this = Self {
+            style: get_style(base.style_element(), base.scale()),
+            base,
+        };
+        this.refresh_style();
+        this
+    }
+
+    fn handle_style_change(&mut self, _event: StyleChangeEvent) -> Result<()> {
+        self.refresh_style();
+        self.base.size_hint_changed();
+        self.base.update();
+        Ok(())
+    }
+}
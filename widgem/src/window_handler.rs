@@ -47,6 +47,10 @@ impl<'a> WindowHandler<'a> {
 
     pub fn handle_event(&mut self, event: WindowEvent) {
         self.window.pass_event_to_accesskit(&event);
+        if self.handle_blocked_input(&event) {
+            self.after_widget_activity();
+            return;
+        }
 
         match event {
             WindowEvent::RedrawRequested => {
@@ -74,6 +78,7 @@ impl<'a> WindowHandler<'a> {
             WindowEvent::CursorLeft { .. } => {
                 self.window.cursor_left();
                 self.dispatch_mouse_leave();
+                self.set_tooltip_widget(None);
             }
             WindowEvent::CursorMoved {
                 position,
@@ -121,9 +126,24 @@ impl<'a> WindowHandler<'a> {
                 if state.is_some_and(|state| !state.is_accepted()) {
                     self.window.set_cursor(CursorIcon::Default);
                 }
+                if self.window.mouse_grabber_widget().is_none() {
+                    let hovered = match state {
+                        Some(MouseEventState::AcceptedBy(id)) => Some(id),
+                        _ => None,
+                    };
+                    let owner = self.tooltip_owner(hovered);
+                    self.set_tooltip_widget(owner);
+                }
             }
             WindowEvent::ModifiersChanged(modifiers) => {
+                let was_alt_pressed = self.window.modifiers().alt_key();
                 self.window.set_modifiers(modifiers.state());
+                if was_alt_pressed != modifiers.state().alt_key()
+                    && with_system(|system| system.config.mnemonics_on_alt)
+                {
+                    // Show or hide mnemonic underlines.
+                    self.window.request_redraw();
+                }
             }
             WindowEvent::MouseInput {
                 device_id,
@@ -131,6 +151,7 @@ impl<'a> WindowHandler<'a> {
                 button,
                 ..
             } => {
+                self.hide_tooltip();
                 self.window.mouse_input(state, button);
                 if let Some(pos_in_window) = self.window.cursor_position() {
                     self.window.init_mouse_event_state().or_report_err();
@@ -193,6 +214,7 @@ impl<'a> WindowHandler<'a> {
                 delta,
                 phase,
             } => {
+                self.hide_tooltip();
                 if let Some(pos_in_window) = self.window.cursor_position() {
                     self.window.init_mouse_event_state().or_report_err();
                     if let Some(mouse_grabber_widget_id) = self.window.mouse_grabber_widget() {
@@ -245,6 +267,9 @@ impl<'a> WindowHandler<'a> {
                     is_synthetic,
                     modifiers: self.window.modifiers(),
                 };
+                if event.info.state == ElementState::Pressed {
+                    self.hide_tooltip();
+                }
                 if let Some(focused_widget) = self.window.focused_widget() {
                     if let Ok(widget) = get_widget_by_id_mut(self.root_widget, focused_widget) {
                         widget.dispatch(event.clone().into());
@@ -252,7 +277,16 @@ impl<'a> WindowHandler<'a> {
                 }
 
                 // TODO: only if event is not accepted by a widget
-                if event.info.state == ElementState::Pressed {
+                let mut triggered_callbacks = self.window.triggered_shortcuts(&event);
+                with_system(|system| {
+                    for shortcut in &system.application_shortcuts {
+                        if shortcut.key_combinations.matches(&event) {
+                            triggered_callbacks.push(shortcut.callback.clone());
+                        }
+                    }
+                });
+                // Shortcuts take priority over focus navigation (e.g. Ctrl+Tab).
+                if triggered_callbacks.is_empty() && event.info.state == ElementState::Pressed {
                     let logical_key = &event.info.logical_key;
                     if logical_key == &Key::Named(NamedKey::Tab) {
                         if self.window.modifiers().shift_key() {
@@ -262,16 +296,6 @@ impl<'a> WindowHandler<'a> {
                         }
                     }
                 }
-
-                // TODO: only if event is not accepted above
-                let mut triggered_callbacks = Vec::new();
-                with_system(|system| {
-                    for shortcut in &system.application_shortcuts {
-                        if shortcut.key_combinations.matches(&event) {
-                            triggered_callbacks.push(shortcut.callback.clone());
-                        }
-                    }
-                });
                 for callback in triggered_callbacks {
                     callback.invoke(());
                 }
@@ -305,6 +329,85 @@ impl<'a> WindowHandler<'a> {
         self.after_widget_activity();
     }
 
+    // Discards user input while a modal window blocks this window.
+    // Returns `true` if the event shouldn't be processed further.
+    fn handle_blocked_input(&mut self, event: &WindowEvent) -> bool {
+        let Some(blocker_id) = self.window.modal_blocker() else {
+            return false;
+        };
+        match event {
+            WindowEvent::MouseInput { state, button, .. } => {
+                self.window.mouse_input(*state, *button);
+                if !self.window.any_mouse_buttons_pressed() {
+                    self.window.set_mouse_grabber_widget(None);
+                }
+                if *state == ElementState::Pressed {
+                    let blocker = with_system(|system| {
+                        system
+                            .windows
+                            .get(&blocker_id)
+                            .map(|info| info.shared_window.clone())
+                    });
+                    if let Some(blocker) = blocker {
+                        blocker.focus_window();
+                    }
+                }
+                true
+            }
+            WindowEvent::CursorMoved { .. } => {
+                self.dispatch_mouse_leave();
+                self.set_tooltip_widget(None);
+                true
+            }
+            WindowEvent::MouseWheel { .. }
+            | WindowEvent::KeyboardInput { .. }
+            | WindowEvent::Ime(_)
+            | WindowEvent::CloseRequested => true,
+            _ => false,
+        }
+    }
+
+    // Returns the closest widget that has a tooltip, starting from `id` and going up
+    // to the root of the window.
+    fn tooltip_owner(&mut self, id: Option<RawWidgetId>) -> Option<RawWidgetId> {
+        let mut id = id?;
+        loop {
+            let widget = get_widget_by_id_mut(self.root_widget, id).ok()?;
+            if widget.base().tooltip().is_some() {
+                return Some(id);
+            }
+            if id == self.window.root_widget_id() {
+                return None;
+            }
+            id = widget.base().parent_id()?;
+        }
+    }
+
+    // Hides the tooltip of the previous widget under the mouse pointer
+    // and schedules the tooltip of the new one.
+    fn set_tooltip_widget(&mut self, id: Option<RawWidgetId>) {
+        if self.window.tooltip_widget() == id {
+            return;
+        }
+        self.hide_tooltip();
+        self.window.set_tooltip_widget(id);
+        if let Some(id) = id {
+            if let Ok(widget) = get_widget_by_id_mut(self.root_widget, id) {
+                widget.base_mut().start_tooltip_timer();
+            }
+        }
+    }
+
+    // Hides the current tooltip. It's not shown again until the mouse pointer
+    // moves to another widget.
+    fn hide_tooltip(&mut self) {
+        if let Some(id) = self.window.tooltip_widget() {
+            if let Ok(widget) = get_widget_by_id_mut(self.root_widget, id) {
+                widget.base_mut().hide_tooltip();
+            }
+        }
+    }
+
     pub fn handle_accesskit_event(&mut self, event: accesskit_winit::Event) {
         match event.window_event {
             accesskit_winit::WindowEvent::InitialTreeRequested => {